@@ -3,28 +3,19 @@
 //! It uses parallel processing for speed up
 
 use colored::Colorize;
+use missile_sim::export::{RunRecord, summary_table};
 use missile_sim::prelude::*;
 use rand::prelude::*;
 use rayon::iter::ParallelIterator;
 use rayon::prelude::IntoParallelIterator;
 use std::collections::HashMap;
 use std::f64::consts::TAU;
-use std::fs::File;
-use std::io::Write;
 use std::path::PathBuf;
 use std::sync::atomic::AtomicUsize;
 use std::sync::{Arc, LazyLock, Mutex};
 use std::time::Instant;
 
-struct Record {
-    scenario_name: Arc<String>,
-    guidance_law: &'static str,
-    hit: i8,
-    miss_distance: f64,
-    time_to_impact: f64,
-}
-
-static GLOBAL_RECORD: LazyLock<Arc<Mutex<Vec<Record>>>> =
+static GLOBAL_RECORD: LazyLock<Arc<Mutex<Vec<RunRecord>>>> =
     LazyLock::new(|| Arc::new(Mutex::new(Vec::new())));
 
 static COUNTER: LazyLock<AtomicUsize> = LazyLock::new(|| AtomicUsize::new(0));
@@ -39,13 +30,6 @@ fn main() -> anyhow::Result<()> {
         });
 
     let start_time = Instant::now();
-    let laws: Vec<(&str, Box<dyn GuidanceLaw>)> = vec![
-        ("PPN", Box::new(PureProportionalNavigation)),
-        ("TPN", Box::new(TrueProportionalNavigation)),
-        ("APN", Box::new(AugmentedProportionalNavigation::new(1.256))),
-        ("PP", Box::new(PurePursuit)),
-        ("LP", Box::new(LeadPursuit::new(1.256))),
-    ];
 
     let random_scene: Vec<Scenario> = (0..run_count)
         .map(|i| {
@@ -54,17 +38,29 @@ fn main() -> anyhow::Result<()> {
         })
         .collect();
 
+    let (apn_time_constant, lp_lead_time) = tune_gains(&random_scene);
+    println!(
+        "Tuned gains: APN time_constant = {:.3}, LP lead_time = {:.3}",
+        apn_time_constant, lp_lead_time
+    );
+
+    let registry = GuidanceRegistry::builtin();
+    let laws = [
+        GuidanceSpec::new("PPN"),
+        GuidanceSpec::new("TPN"),
+        GuidanceSpec::new("APN").with_param("time_constant", apn_time_constant),
+        GuidanceSpec::new("PP"),
+        GuidanceSpec::new("LP").with_param("lead_time", lp_lead_time),
+    ]
+    .iter()
+    .map(|spec| registry.build(spec))
+    .collect::<anyhow::Result<Vec<_>>>()?;
+
     random_scene.into_par_iter().for_each(|scenario| {
-        let shared_scenario_name = Arc::new(scenario.name.clone());
-        for (law_name, law) in &laws {
+        for law in &laws {
+            let law_name = law.name();
             let metrics = scenario.simulate(law.as_ref());
-            let record = Record {
-                scenario_name: Arc::clone(&shared_scenario_name),
-                guidance_law: law_name,
-                hit: if metrics.hit { 1 } else { 0 },
-                miss_distance: metrics.miss_distance,
-                time_to_impact: *metrics.time_history.last().unwrap_or(&0.0),
-            };
+            let record = RunRecord::new(&scenario, law.as_ref(), &metrics);
 
             {
                 GLOBAL_RECORD
@@ -92,24 +88,8 @@ fn main() -> anyhow::Result<()> {
     let file_name = format!("Metrics_{}.csv", run_count);
     let file_path = PathBuf::from(file_name);
 
-    let mut file = File::create(&file_path)?;
-    writeln!(
-        file,
-        "scenario_name,guidance_law,hit,miss_distance,time_to_impact"
-    )?;
-
     let all_records = GLOBAL_RECORD.lock().expect("Failed to acquire lock");
-    for record in all_records.iter() {
-        writeln!(
-            file,
-            "{},{},{},{:.2},{:.2}",
-            record.scenario_name,
-            record.guidance_law,
-            record.hit,
-            record.miss_distance,
-            record.time_to_impact
-        )?;
-    }
+    summary_table(&all_records).save(&file_path)?;
 
     println!(
         "CSV saved to {}, Time elapsed: {:?}",
@@ -123,25 +103,22 @@ fn main() -> anyhow::Result<()> {
 
     // aggregate results
     for record in all_records.iter() {
-        if record.hit == 1 {
-            *hit_summary.entry(record.guidance_law).or_insert(0) += 1;
+        // records carry the full spec, e.g. `APN{time_constant=1.2}`, group by law name
+        let law = record.guidance.split('{').next().unwrap_or_default();
+        if record.hit {
+            *hit_summary.entry(law).or_insert(0) += 1;
         }
 
-        *miss_dist_summary.entry(record.guidance_law).or_insert(0.0) += record.miss_distance;
-        *impact_time_summary
-            .entry(record.guidance_law)
-            .or_insert(0.0) += record.time_to_impact;
+        *miss_dist_summary.entry(law).or_insert(0.0) += record.miss_distance;
+        *impact_time_summary.entry(law).or_insert(0.0) += record.summary.duration;
     }
 
     // average them
-    for law in ["PPN", "TPN", "APN", "PP", "LP"] {
-        if let Some(v) = miss_dist_summary.get_mut(law) {
-            *v /= run_count as f64;
-        }
-
-        if let Some(v) = impact_time_summary.get_mut(law) {
-            *v /= run_count as f64;
-        }
+    for v in miss_dist_summary
+        .values_mut()
+        .chain(impact_time_summary.values_mut())
+    {
+        *v /= run_count as f64;
     }
 
     println!();
@@ -332,6 +309,31 @@ fn plot_all_metrics(
     Ok(())
 }
 
+/// Tunes the APN time constant and LP lead time on the first few scenarios, flown at the
+/// same timestep as the bench so the gains fit the runs they are used in.
+fn tune_gains(scenes: &[Scenario]) -> (f64, f64) {
+    const TUNING_SET: usize = 8;
+
+    let tuning_scene = &scenes[..scenes.len().min(TUNING_SET)];
+
+    let optimizer = Optimizer::NelderMead {
+        max_iterations: 40,
+        tolerance: 1e-2,
+    };
+
+    let apn = TuningProblem::apn(tuning_scene)
+        .optimize(&optimizer)
+        .expect("APN tunes its time constant");
+    let lp = TuningProblem::lead_pursuit(tuning_scene)
+        .optimize(&optimizer)
+        .expect("LP tunes its lead time");
+
+    (
+        apn.value("time_constant").unwrap_or(0.5),
+        lp.value("lead_time").unwrap_or(1.0),
+    )
+}
+
 #[inline(always)]
 fn generate_random_scenario(seed: u64, rng: &mut StdRng) -> anyhow::Result<Scenario> {
     let m_pos = Vector3::new(
@@ -397,5 +399,6 @@ fn generate_random_scenario(seed: u64, rng: &mut StdRng) -> anyhow::Result<Scena
         .dt(0.0001)
         .total_time(60.0)
         .hit_threshold(10.0)
+        .recording(RecordingPolicy::None) // only the summary is used
         .build()
 }
@@ -81,14 +81,17 @@ fn spawn_missile(tx: f32, ty: f32, color: Color) -> TrackedMissile {
 
 #[macroquad::main("Missile Guidance Debug")]
 async fn main() {
-    let laws: [&dyn GuidanceLaw; 5] = [
-        &PureProportionalNavigation,
-        &TrueProportionalNavigation,
-        &AugmentedProportionalNavigation::new(1.115),
-        &PurePursuit,
-        &LeadPursuit::new(1.155),
-    ];
-    let law_names = ["PPN", "TPN", "APN", "PP", "LP"];
+    let registry = GuidanceRegistry::builtin();
+    let laws: Vec<Box<dyn GuidanceLaw>> = [
+        "PPN",
+        "TPN",
+        "APN{time_constant=1.115}",
+        "PP",
+        "LP{lead_time=1.155}",
+    ]
+    .iter()
+    .map(|spec| registry.build_str(spec).expect("valid guidance spec"))
+    .collect();
 
     let mut law_idx = 0usize;
     let mut target = vec2(screen_width() / 2.0, screen_height() / 2.0);
@@ -166,7 +169,7 @@ async fn main() {
             acceleration: Vector3::zeros(),
         };
 
-        let law = laws[law_idx];
+        let law = laws[law_idx].as_ref();
         let sf = sw as f64;
         let shf = sh as f64;
 
@@ -325,7 +328,7 @@ async fn main() {
 
         // Top-left: stats
         draw_text(
-            format!("Law [TAB/1-6]: {}", law_names[law_idx]),
+            format!("Law [TAB/1-6]: {}", laws[law_idx].name()),
             10.0,
             gap,
             fs,
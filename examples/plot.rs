@@ -1,151 +1,15 @@
 use anyhow::Result;
 use colored::Colorize;
+use missile_sim::export::{AcmiExport, GeoOrigin};
 use missile_sim::prelude::*;
+use missile_sim::simulation::load_scenario_dir;
 use rayon::iter::ParallelIterator;
 use rayon::prelude::IntoParallelRefIterator;
 use std::path::{Path, PathBuf};
 
 fn main() -> Result<()> {
-    let bvr_intercept = Scenario::builder("BVR Fighter Intercept")
-        .missile(Missile {
-            state: State3D {
-                position: Vector3::new(0.0, 0.0, 10000.0),
-                velocity: Vector3::new(900.0, 0.0, 0.0), // Mach ~2.6
-            },
-            max_acceleration: 350.0, // ~35g
-            navigation_constant: 4.0,
-            max_closing_speed: 1800.0,
-        })
-        .target(Target {
-            state: State3D {
-                position: Vector3::new(25000.0, 3000.0, 10500.0),
-                velocity: Vector3::new(-320.0, 20.0, 0.0), // fighter cruise
-            },
-            acceleration: Vector3::new(0.0, 25.0, 0.0),
-        })
-        .dt(0.0001)
-        .total_time(35.0)
-        .hit_threshold(10.0)
-        .build()?;
-
-    let sea_skimmer = Scenario::builder("Sea Skimming Cruise Missile Defense")
-        .missile(Missile {
-            state: State3D {
-                position: Vector3::new(0.0, 0.0, 15.0),
-                velocity: Vector3::new(1000.0, 0.0, 0.0),
-            },
-            max_acceleration: 400.0,
-            navigation_constant: 5.0,
-            max_closing_speed: 2000.0,
-        })
-        .target(Target {
-            state: State3D {
-                position: Vector3::new(18000.0, 100.0, 8.0),
-                velocity: Vector3::new(-270.0, 0.0, 0.0),
-            },
-            acceleration: Vector3::zeros(),
-        })
-        .dt(0.0001)
-        .total_time(20.0)
-        .hit_threshold(3.0)
-        .build()?;
-
-    let sam_defense = Scenario::builder("SAM Against Diving Aircraft")
-        .missile(Missile {
-            state: State3D {
-                position: Vector3::new(0.0, 0.0, 0.0),
-                velocity: Vector3::new(1400.0, 0.0, 300.0),
-            },
-            max_acceleration: 300.0,
-            navigation_constant: 4.5,
-            max_closing_speed: 2200.0,
-        })
-        .target(Target {
-            state: State3D {
-                position: Vector3::new(35000.0, 5000.0, 12000.0),
-                velocity: Vector3::new(-250.0, 0.0, -150.0),
-            },
-            acceleration: Vector3::new(0.0, 10.0, -5.0),
-        })
-        .dt(0.0001)
-        .total_time(45.0)
-        .hit_threshold(8.0)
-        .build()?;
-
-    let terminal_defense = Scenario::builder("Ballistic Terminal Intercept")
-        .missile(Missile {
-            state: State3D {
-                position: Vector3::new(0.0, 0.0, 0.0),
-                velocity: Vector3::new(1600.0, 0.0, 1800.0),
-            },
-            max_acceleration: 500.0,
-            navigation_constant: 6.0,
-            max_closing_speed: 3500.0,
-        })
-        .target(Target {
-            state: State3D {
-                position: Vector3::new(40000.0, 0.0, 35000.0),
-                velocity: Vector3::new(-400.0, 0.0, -2200.0),
-            },
-            acceleration: Vector3::zeros(),
-        })
-        .dt(0.0001)
-        .total_time(30.0)
-        .hit_threshold(5.0)
-        .build()?;
-
-    let drone_intercept = Scenario::builder("Rotorcraft Anti-Drone Engagement")
-        .missile(Missile {
-            state: State3D {
-                position: Vector3::new(0.0, 0.0, 300.0),
-                velocity: Vector3::new(450.0, 0.0, 0.0),
-            },
-            max_acceleration: 80.0,
-            navigation_constant: 4.0,
-            max_closing_speed: 700.0,
-        })
-        .target(Target {
-            state: State3D {
-                position: Vector3::new(6000.0, 800.0, 500.0),
-                velocity: Vector3::new(-60.0, 15.0, 0.0),
-            },
-            acceleration: Vector3::new(0.0, 2.0, 0.0),
-        })
-        .dt(0.0001)
-        .total_time(18.0)
-        .hit_threshold(2.0)
-        .build()?;
-
-    let hypersonic = Scenario::builder("Hypersonic Glide Vehicle Intercept")
-        .missile(Missile {
-            state: State3D {
-                position: Vector3::new(0.0, 0.0, 15000.0),
-                velocity: Vector3::new(2200.0, 0.0, 400.0),
-            },
-            max_acceleration: 450.0,
-            navigation_constant: 5.0,
-            max_closing_speed: 4500.0,
-        })
-        .target(Target {
-            state: State3D {
-                position: Vector3::new(60000.0, 7000.0, 30000.0),
-                velocity: Vector3::new(-1800.0, -150.0, -250.0),
-            },
-            acceleration: Vector3::new(0.0, 30.0, 10.0),
-        })
-        .dt(0.0001)
-        .total_time(40.0)
-        .hit_threshold(15.0)
-        .build()?;
-
-    let scene = [
-        bvr_intercept,
-        sea_skimmer,
-        sam_defense,
-        terminal_defense,
-        drone_intercept,
-        hypersonic,
-    ];
+    // scenario library lives in ./scenarios as plain TOML files
+    let scene = load_scenario_dir("./scenarios")?;
 
     let mut title = vec![];
     let mut metrics = vec![];
@@ -169,6 +33,7 @@ fn main() -> Result<()> {
     }
 
     plot_projection(&metrics, PathBuf::from("./assets/Plot_PP.png"), &title)?;
+    save_acmi(&metrics, "./assets/acmi", "PP", &title)?;
 
     title.clear();
     metrics.clear();
@@ -192,6 +57,7 @@ fn main() -> Result<()> {
     }
 
     plot_projection(&metrics, PathBuf::from("./assets/Plot_PPN.png"), &title)?;
+    save_acmi(&metrics, "./assets/acmi", "PPN", &title)?;
 
     title.clear();
     metrics.clear();
@@ -215,7 +81,25 @@ fn main() -> Result<()> {
     }
 
     plot_projection(&metrics, PathBuf::from("./assets/Plot_TPN.png"), &title)?;
+    save_acmi(&metrics, "./assets/acmi", "TPN", &title)?;
+
+    Ok(())
+}
 
+/// Writes each run as a Tacview recording, for 3D replay of the same engagements.
+pub fn save_acmi(
+    metrics: &[SimulationMetrics],
+    dir: &str,
+    law: &str,
+    titles: &[&str],
+) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for (idx, (metric, title)) in metrics.iter().zip(titles).enumerate() {
+        AcmiExport::new(GeoOrigin::default())
+            .title(&format!("{title} ({law})"))
+            .add_run(law, metric)
+            .save(format!("{dir}/{law}_{idx}.acmi"))?;
+    }
     Ok(())
 }
 
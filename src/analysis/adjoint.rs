@@ -0,0 +1,220 @@
+use crate::core::calculate_closing_speed;
+use crate::simulation::Scenario;
+use serde::{Deserialize, Serialize};
+
+/// Linearized planar homing loop for miss-distance analysis (Zarchan, *Tactical and
+/// Strategic Missile Guidance*).
+///
+/// States are the relative lateral separation `y`, its rate `ẏ` and the achieved missile
+/// acceleration `n_L`, with a first-order autopilot lag:
+///
+/// - `ÿ = n_T - n_L`
+/// - `n_c = N' * V_c * λ̇ = N' * (y + ẏ * t_go) / t_go²`
+/// - `ṅ_L = (n_c - n_L) / T`
+///
+/// [`LinearEngagement::forward`] flies one engagement of a given flight time, while
+/// [`LinearEngagement::adjoint`] gets the miss for every flight time from a single
+/// backward run of the adjoint system.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LinearEngagement {
+    /// Effective navigation ratio `N'`.
+    pub navigation_ratio: f64,
+    /// Closing speed `V_c` (m/s). Informational: the loop is written in `y` and `t_go`, so
+    /// `V_c` cancels out of it, [`LinearEngagement::from_scenario`] uses it to set
+    /// `flight_time`.
+    pub closing_speed: f64,
+    /// Missile speed `V_m` (m/s), converts heading error into an initial `ẏ`.
+    pub missile_speed: f64,
+    /// Autopilot time constant `T` (s). Must be positive.
+    pub time_constant: f64,
+    /// Longest flight time evaluated (s).
+    pub flight_time: f64,
+    /// Integration step (s).
+    pub dt: f64,
+}
+
+/// Error sources driving the miss distance.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ErrorSources {
+    /// Step target maneuver `n_T` (m/s²).
+    pub target_maneuver: f64,
+    /// Heading error at launch (rad).
+    pub heading_error: f64,
+    /// White acceleration-command noise spectral density (`(m/s²)²/Hz`).
+    pub acceleration_noise: f64,
+}
+
+/// Miss distance as a function of flight time, per error source.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MissCurves {
+    /// Flight times (s).
+    pub flight_time: Vec<f64>,
+    /// Miss due to the step target maneuver (m).
+    pub target_maneuver: Vec<f64>,
+    /// Miss due to heading error (m).
+    pub heading_error: Vec<f64>,
+    /// RMS miss due to acceleration noise (m).
+    pub acceleration_noise: Vec<f64>,
+}
+
+/// Breakdown of the miss distance at one flight time.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ErrorBudget {
+    /// Flight time (s).
+    pub flight_time: f64,
+    /// Miss due to the step target maneuver (m).
+    pub target_maneuver: f64,
+    /// Miss due to heading error (m).
+    pub heading_error: f64,
+    /// RMS miss due to acceleration noise (m).
+    pub acceleration_noise: f64,
+    /// Root-sum-square of all contributions (m).
+    pub total_rss: f64,
+}
+
+impl MissCurves {
+    /// Returns the error budget at the sampled flight time closest to `flight_time`.
+    pub fn budget_at(&self, flight_time: f64) -> Option<ErrorBudget> {
+        let idx = self
+            .flight_time
+            .iter()
+            .enumerate()
+            .min_by(|a, b| {
+                (a.1 - flight_time)
+                    .abs()
+                    .total_cmp(&(b.1 - flight_time).abs())
+            })?
+            .0;
+
+        let tm = self.target_maneuver[idx];
+        let he = self.heading_error[idx];
+        let noise = self.acceleration_noise[idx];
+
+        Some(ErrorBudget {
+            flight_time: self.flight_time[idx],
+            target_maneuver: tm,
+            heading_error: he,
+            acceleration_noise: noise,
+            total_rss: (tm * tm + he * he + noise * noise).sqrt(),
+        })
+    }
+}
+
+impl LinearEngagement {
+    /// Linearizes a scenario about its initial geometry.
+    ///
+    /// The engine has no autopilot lag, so a small `time_constant` approximates it.
+    pub fn from_scenario(scenario: &Scenario, time_constant: f64) -> Self {
+        let m = &scenario.missile.state;
+        let t = &scenario.target.state;
+        let closing_speed =
+            calculate_closing_speed(&m.position, &m.velocity, &t.position, &t.velocity).max(1.0);
+        let range = (t.position - m.position).norm();
+
+        Self {
+            navigation_ratio: scenario.missile.navigation_constant,
+            closing_speed,
+            missile_speed: m.speed(),
+            time_constant,
+            flight_time: range / closing_speed,
+            dt: (time_constant / 20.0).min(scenario.dt.max(1e-4)),
+        }
+    }
+
+    /// Flies a single linear engagement of length `flight_time` and returns the miss `y(t_f)`.
+    ///
+    /// Noise is stochastic and is not included here, use [`LinearEngagement::adjoint`] for it.
+    pub fn forward(&self, flight_time: f64, sources: &ErrorSources) -> f64 {
+        let n_p = self.navigation_ratio;
+        let tau = self.time_constant;
+
+        let derivative = |t: f64, x: [f64; 3]| -> [f64; 3] {
+            let t_go = (flight_time - t).max(1e-6);
+            let n_c = n_p * (x[0] + x[1] * t_go) / (t_go * t_go);
+            [x[1], sources.target_maneuver - x[2], (n_c - x[2]) / tau]
+        };
+
+        let mut x = [0.0, -self.missile_speed * sources.heading_error, 0.0];
+        let mut t = 0.0;
+        let h = self.dt;
+
+        // stop half a step short of t_go = 0, where the PN command is singular
+        while flight_time - t > 0.5 * h {
+            let step = h.min(flight_time - t);
+            x = rk4(&derivative, t, x, step);
+            t += step;
+        }
+
+        x[0]
+    }
+
+    /// Runs the adjoint system once and returns the miss for every flight time up to
+    /// `flight_time`, sampled every `dt`.
+    ///
+    /// With adjoint time `τ` (= time to go), `dλ/dτ = Aᵀ(τ) λ` from `λ(0) = [1, 0, 0]`:
+    /// - step target maneuver: `n_T * ∫ λ₂ dτ`
+    /// - heading error: `-V_m * HE * λ₂(t_f)`
+    /// - acceleration noise: `sqrt(Φ * ∫ (λ₃ / T)² dτ)`
+    pub fn adjoint(&self, sources: &ErrorSources) -> MissCurves {
+        let n_p = self.navigation_ratio;
+        let tau = self.time_constant;
+
+        // state: [λ1, λ2, λ3, ∫λ2, ∫(λ3/T)²]
+        let derivative = |t_go: f64, x: [f64; 5]| -> [f64; 5] {
+            let t_go = t_go.max(1e-9);
+            let (l1, l2, l3) = (x[0], x[1], x[2]);
+            [
+                n_p * l3 / (tau * t_go * t_go),
+                l1 + n_p * l3 / (tau * t_go),
+                -l2 - l3 / tau,
+                l2,
+                (l3 / tau) * (l3 / tau),
+            ]
+        };
+
+        let steps = (self.flight_time / self.dt).ceil() as usize;
+        let mut curves = MissCurves {
+            flight_time: Vec::with_capacity(steps),
+            target_maneuver: Vec::with_capacity(steps),
+            heading_error: Vec::with_capacity(steps),
+            acceleration_noise: Vec::with_capacity(steps),
+        };
+
+        let mut x = [1.0, 0.0, 0.0, 0.0, 0.0];
+        let mut t_go = 0.0;
+        for _ in 0..steps {
+            x = rk4(&derivative, t_go, x, self.dt);
+            t_go += self.dt;
+
+            curves.flight_time.push(t_go);
+            curves.target_maneuver.push(sources.target_maneuver * x[3]);
+            curves
+                .heading_error
+                .push(-self.missile_speed * sources.heading_error * x[1]);
+            curves
+                .acceleration_noise
+                .push((sources.acceleration_noise * x[4]).sqrt());
+        }
+
+        curves
+    }
+}
+
+/// One classic fourth-order Runge-Kutta step.
+#[inline]
+fn rk4<const N: usize>(
+    f: &impl Fn(f64, [f64; N]) -> [f64; N],
+    t: f64,
+    x: [f64; N],
+    h: f64,
+) -> [f64; N] {
+    let add =
+        |a: [f64; N], b: [f64; N], s: f64| -> [f64; N] { std::array::from_fn(|i| a[i] + b[i] * s) };
+
+    let k1 = f(t, x);
+    let k2 = f(t + 0.5 * h, add(x, k1, 0.5 * h));
+    let k3 = f(t + 0.5 * h, add(x, k2, 0.5 * h));
+    let k4 = f(t + h, add(x, k3, h));
+
+    std::array::from_fn(|i| x[i] + h / 6.0 * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]))
+}
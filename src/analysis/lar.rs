@@ -0,0 +1,389 @@
+use crate::core::State3D;
+use crate::entity::{Missile, Target};
+use crate::guidance::GuidanceLaw;
+use crate::simulation::{RecordingPolicy, Scenario, SimulationMetrics};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for a launch acceptability region (LAR) sweep.
+///
+/// The shooter sits at the origin at `launch_altitude` and the target is placed
+/// down-range along `+x` at each candidate launch range. The missile is always
+/// launched boresighted at the target's initial position.
+///
+/// Aspect angle is measured at the target, between its velocity and the line
+/// back to the shooter: `0` is a hot (head-on) target, `π` is a target flying away.
+///
+/// Every run flies `base` with only the geometry replaced, so its environment, terrain,
+/// frame, seed, 6-DOF airframe, launcher and datalink all shape the envelope.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EnvelopeConfig {
+    /// Scenario each run starts from, a plain kinematic one when `None`. Its missile,
+    /// target, timing and recording are replaced per run, a launcher is moved to the
+    /// launch point.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<Scenario>,
+    /// Missile template. Only its speed (velocity magnitude) and guidance
+    /// parameters are used, position and heading are set per run.
+    pub missile: Missile,
+    /// Shooter altitude (z) in meters.
+    pub launch_altitude: f64,
+    /// Target speed in m/s.
+    pub target_speed: f64,
+    /// Target acceleration in its heading frame (`x` along velocity, `y` left, `z` up).
+    pub target_acceleration: Vector3<f64>,
+    /// Lateral acceleration (m/s²) the target uses to turn tail for the no-escape range.
+    pub escape_acceleration: f64,
+    /// Aspect angles to evaluate, in radians.
+    pub aspect_angles: Vec<f64>,
+    /// Target altitudes to evaluate, in meters.
+    pub target_altitudes: Vec<f64>,
+    /// Shortest launch range considered (m).
+    pub min_range: f64,
+    /// Longest launch range considered (m).
+    pub max_range: f64,
+    /// Number of coarse range samples used to bracket the hit region before bisecting.
+    pub scan_steps: usize,
+    /// Bisection stops once the bracket is narrower than this (m).
+    pub tolerance: f64,
+    /// Simulation timestep for each run.
+    pub dt: f64,
+    /// Maximum time of flight for each run.
+    pub total_time: f64,
+    /// Hit threshold for each run.
+    pub hit_threshold: f64,
+    /// Fuze arming time (s). Hits before this time of flight don't count, which is what bounds Rmin.
+    pub arming_time: f64,
+}
+
+impl Default for EnvelopeConfig {
+    fn default() -> Self {
+        Self {
+            base: None,
+            missile: Missile {
+                state: State3D::init(0.0, 0.0, 0.0, 1000.0, 0.0, 0.0),
+                max_acceleration: 300.0,
+                navigation_constant: 4.0,
+                max_closing_speed: 3000.0,
+            },
+            launch_altitude: 5000.0,
+            target_speed: 300.0,
+            target_acceleration: Vector3::zeros(),
+            escape_acceleration: 60.0,
+            aspect_angles: (0..=12)
+                .map(|i| i as f64 * std::f64::consts::PI / 12.0)
+                .collect(),
+            target_altitudes: vec![5000.0],
+            min_range: 500.0,
+            max_range: 40000.0,
+            scan_steps: 16,
+            tolerance: 50.0,
+            dt: 0.01,
+            total_time: 60.0,
+            hit_threshold: 10.0,
+            arming_time: 1.5,
+        }
+    }
+}
+
+/// Launch ranges for a single aspect angle and target altitude.
+///
+/// A range is `None` when no launch inside `[min_range, max_range]` produced a hit.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EnvelopePoint {
+    /// Aspect angle in radians.
+    pub aspect_angle: f64,
+    /// Target altitude in meters.
+    pub target_altitude: f64,
+    /// Minimum launch range (Rmin).
+    pub r_min: Option<f64>,
+    /// Maximum launch range against a non-reacting target (Rmax).
+    pub r_max: Option<f64>,
+    /// No-escape range (Rne), the longest range at which a tail-turning target is still hit.
+    pub r_ne: Option<f64>,
+}
+
+/// Envelope ranges for one target altitude, ordered by aspect angle.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PolarTable {
+    /// Target altitude in meters.
+    pub target_altitude: f64,
+    /// One row per aspect angle.
+    pub rows: Vec<EnvelopePoint>,
+}
+
+/// Envelope ranges as dense `altitude × aspect` grids, convenient for plotting.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EnvelopeGrid {
+    /// Aspect angles (columns), in radians.
+    pub aspect_angles: Vec<f64>,
+    /// Target altitudes (rows), in meters.
+    pub target_altitudes: Vec<f64>,
+    /// `r_min[altitude][aspect]`.
+    pub r_min: Vec<Vec<Option<f64>>>,
+    /// `r_max[altitude][aspect]`.
+    pub r_max: Vec<Vec<Option<f64>>>,
+    /// `r_ne[altitude][aspect]`.
+    pub r_ne: Vec<Vec<Option<f64>>>,
+}
+
+/// The computed launch envelope of a missile/guidance pair.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LaunchEnvelope {
+    /// Name of the guidance law used.
+    pub guidance: String,
+    /// Evaluated points, altitude-major (all aspects of the first altitude first).
+    pub points: Vec<EnvelopePoint>,
+}
+
+impl LaunchEnvelope {
+    /// Computes the envelope by bisecting launch range with repeated simulations.
+    ///
+    /// For every `(altitude, aspect)` pair the range interval is first scanned with
+    /// `scan_steps` samples to bracket the hit region, then each edge is refined by
+    /// bisection. This assumes the hit region is a single interval along range.
+    pub fn compute(config: &EnvelopeConfig, guidance: &dyn GuidanceLaw) -> Self {
+        let mut points =
+            Vec::with_capacity(config.target_altitudes.len() * config.aspect_angles.len());
+
+        for &altitude in &config.target_altitudes {
+            for &aspect in &config.aspect_angles {
+                let hit = |range: f64| {
+                    let metrics =
+                        launch_scenario(config, range, aspect, altitude).simulate(guidance);
+                    is_armed_hit(config, &metrics)
+                };
+                let escape_hit = |range: f64| {
+                    let metrics = escape_run(config, range, aspect, altitude, guidance);
+                    is_armed_hit(config, &metrics)
+                };
+
+                let (r_min, r_max) = hit_interval(config, hit);
+                let (_, r_ne) = hit_interval(config, escape_hit);
+
+                points.push(EnvelopePoint {
+                    aspect_angle: aspect,
+                    target_altitude: altitude,
+                    r_min,
+                    r_max,
+                    r_ne,
+                });
+            }
+        }
+
+        Self {
+            guidance: guidance.name().to_string(),
+            points,
+        }
+    }
+
+    /// Splits the envelope into one polar table per target altitude.
+    pub fn polar_tables(&self) -> Vec<PolarTable> {
+        let mut tables: Vec<PolarTable> = Vec::new();
+        for point in &self.points {
+            match tables
+                .iter_mut()
+                .find(|t| t.target_altitude == point.target_altitude)
+            {
+                Some(table) => table.rows.push(point.clone()),
+                None => tables.push(PolarTable {
+                    target_altitude: point.target_altitude,
+                    rows: vec![point.clone()],
+                }),
+            }
+        }
+
+        for table in &mut tables {
+            table
+                .rows
+                .sort_by(|a, b| a.aspect_angle.total_cmp(&b.aspect_angle));
+        }
+        tables
+    }
+
+    /// Returns the envelope as dense grids indexed `[altitude][aspect]`.
+    pub fn grid(&self) -> EnvelopeGrid {
+        let tables = self.polar_tables();
+        let aspect_angles = tables
+            .first()
+            .map(|t| t.rows.iter().map(|p| p.aspect_angle).collect())
+            .unwrap_or_default();
+
+        let column = |f: fn(&EnvelopePoint) -> Option<f64>| -> Vec<Vec<Option<f64>>> {
+            tables
+                .iter()
+                .map(|t| t.rows.iter().map(f).collect())
+                .collect()
+        };
+
+        EnvelopeGrid {
+            aspect_angles,
+            target_altitudes: tables.iter().map(|t| t.target_altitude).collect(),
+            r_min: column(|p| p.r_min),
+            r_max: column(|p| p.r_max),
+            r_ne: column(|p| p.r_ne),
+        }
+    }
+}
+
+/// A hit only counts once the fuze has armed.
+fn is_armed_hit(config: &EnvelopeConfig, metrics: &SimulationMetrics) -> bool {
+    metrics.hit && metrics.summary.time_of_flight >= config.arming_time
+}
+
+/// Builds the scenario for one launch range, aspect and target altitude.
+fn launch_scenario(config: &EnvelopeConfig, range: f64, aspect: f64, altitude: f64) -> Scenario {
+    let missile_pos = Vector3::new(0.0, 0.0, config.launch_altitude);
+    let target_pos = Vector3::new(range, 0.0, altitude);
+
+    let mut missile = config.missile.clone();
+    let boresight = (target_pos - missile_pos).normalize();
+    missile.state = State3D {
+        position: missile_pos,
+        velocity: boresight * config.missile.state.speed(),
+    };
+
+    // heading frame of the target: x along velocity, y to the left, z up
+    let heading = Vector3::new(-aspect.cos(), aspect.sin(), 0.0);
+    let left = Vector3::z().cross(&heading);
+    let acceleration = heading * config.target_acceleration.x
+        + left * config.target_acceleration.y
+        + Vector3::z() * config.target_acceleration.z;
+
+    let target = Target {
+        state: State3D {
+            position: target_pos,
+            velocity: heading * config.target_speed,
+        },
+        acceleration,
+    };
+    let name = format!("lar_{:.0}_{:.3}_{:.0}", range, aspect, altitude);
+    let Some(base) = &config.base else {
+        return Scenario::builder(&name)
+            .missile(missile)
+            .target(target)
+            .dt(config.dt)
+            .total_time(config.total_time)
+            .hit_threshold(config.hit_threshold)
+            .recording(RecordingPolicy::None)
+            .build()
+            .expect("missile and target are always set");
+    };
+
+    let mut scenario = base.clone();
+    scenario.name = name;
+    scenario.missile = missile;
+    scenario.target = target;
+    scenario.dt = config.dt;
+    scenario.total_time = config.total_time;
+    scenario.hit_threshold = config.hit_threshold;
+    scenario.record_vectors = false;
+    scenario.recording = RecordingPolicy::None;
+    if let Some(launcher) = &mut scenario.launcher {
+        launcher.state.position = missile_pos;
+    }
+    scenario
+}
+
+/// Flies one no-escape run: at launch the target turns tail at `escape_acceleration`.
+fn escape_run(
+    config: &EnvelopeConfig,
+    range: f64,
+    aspect: f64,
+    altitude: f64,
+    guidance: &dyn GuidanceLaw,
+) -> SimulationMetrics {
+    let mut engine = launch_scenario(config, range, aspect, altitude).engine();
+
+    let mut metrics = SimulationMetrics::init(0).with_recording(RecordingPolicy::None);
+    engine.record_metrics(&mut metrics, Vector3::zeros(), Vector3::zeros());
+
+    while !engine.should_terminate() {
+        let away = engine.target.state.position - engine.missile.state.position;
+        engine.target.acceleration = turn_toward(
+            &engine.target.state.velocity,
+            &away,
+            config.escape_acceleration,
+        );
+        engine.step(guidance, &mut metrics);
+    }
+
+    metrics.finalize(config.hit_threshold);
+    metrics
+}
+
+/// Lateral acceleration of magnitude `accel` turning `velocity` toward `direction`.
+/// Returns zero once the velocity is aligned.
+fn turn_toward(velocity: &Vector3<f64>, direction: &Vector3<f64>, accel: f64) -> Vector3<f64> {
+    let speed = velocity.norm();
+    let dir_norm = direction.norm();
+    if speed < 1e-6 || dir_norm < 1e-6 {
+        return Vector3::zeros();
+    }
+
+    let v_hat = velocity / speed;
+    let d_hat = direction / dir_norm;
+    let lateral = d_hat - v_hat * v_hat.dot(&d_hat);
+    let lateral_norm = lateral.norm();
+
+    if v_hat.dot(&d_hat) > 0.999 {
+        Vector3::zeros()
+    } else if lateral_norm < 1e-9 {
+        // exactly opposite, pick any horizontal turn direction
+        let side = Vector3::z().cross(&v_hat);
+        if side.norm() < 1e-9 {
+            Vector3::zeros()
+        } else {
+            side.normalize() * accel
+        }
+    } else {
+        lateral / lateral_norm * accel
+    }
+}
+
+/// Finds the shortest and longest range with a hit inside `[min_range, max_range]`.
+fn hit_interval(config: &EnvelopeConfig, hit: impl Fn(f64) -> bool) -> (Option<f64>, Option<f64>) {
+    let samples = config.scan_steps.max(2);
+    let span = config.max_range - config.min_range;
+    let ranges: Vec<f64> = (0..samples)
+        .map(|i| config.min_range + span * i as f64 / (samples - 1) as f64)
+        .collect();
+    let hits: Vec<bool> = ranges.iter().map(|&r| hit(r)).collect();
+
+    let (Some(first), Some(last)) = (hits.iter().position(|&h| h), hits.iter().rposition(|&h| h))
+    else {
+        return (None, None);
+    };
+
+    let r_min = if first == 0 {
+        ranges[0]
+    } else {
+        bisect(ranges[first], ranges[first - 1], config.tolerance, &hit)
+    };
+
+    let r_max = if last == samples - 1 {
+        ranges[last]
+    } else {
+        bisect(ranges[last], ranges[last + 1], config.tolerance, &hit)
+    };
+
+    (Some(r_min), Some(r_max))
+}
+
+/// Bisects between a hitting range and a missing range, returning the last known hit.
+fn bisect(
+    mut hit_range: f64,
+    mut miss_range: f64,
+    tolerance: f64,
+    hit: &impl Fn(f64) -> bool,
+) -> f64 {
+    while (hit_range - miss_range).abs() > tolerance {
+        let mid = 0.5 * (hit_range + miss_range);
+        if hit(mid) {
+            hit_range = mid;
+        } else {
+            miss_range = mid;
+        }
+    }
+    hit_range
+}
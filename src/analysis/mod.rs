@@ -0,0 +1,7 @@
+mod adjoint;
+mod lar;
+mod tuning;
+
+pub use adjoint::{ErrorBudget, ErrorSources, LinearEngagement, MissCurves};
+pub use lar::{EnvelopeConfig, EnvelopeGrid, EnvelopePoint, LaunchEnvelope, PolarTable};
+pub use tuning::{ObjectiveWeights, Optimizer, TuningParameter, TuningProblem, TuningResult};
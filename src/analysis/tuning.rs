@@ -0,0 +1,423 @@
+use crate::core::{STANDARD_GRAVITY, SimRng};
+use crate::guidance::{AugmentedProportionalNavigation, GuidanceLaw, LeadPursuit};
+use crate::simulation::{RecordingPolicy, Scenario};
+use serde::{Deserialize, Serialize};
+
+/// A bounded guidance parameter to be tuned.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TuningParameter {
+    /// Parameter name, used in results (e.g. `"time_constant"`).
+    pub name: String,
+    /// Lower bound.
+    pub min: f64,
+    /// Upper bound.
+    pub max: f64,
+    /// Starting value (Nelder-Mead simplex origin).
+    pub initial: f64,
+}
+
+impl TuningParameter {
+    /// Creates a parameter with bounds and a starting value.
+    pub fn new(name: &str, min: f64, max: f64, initial: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            min,
+            max,
+            initial: initial.clamp(min, max),
+        }
+    }
+}
+
+/// Weights of the tuning objective.
+///
+/// `objective = miss_distance * mean(miss) + acceleration * mean(peak accel) + time_of_flight * mean(tof)`
+///
+/// Peak acceleration and time of flight come from [`crate::simulation::EngagementSummary`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ObjectiveWeights {
+    /// Weight of the mean miss distance (m).
+    pub miss_distance: f64,
+    /// Weight of the mean peak achieved acceleration (m/s²).
+    pub acceleration: f64,
+    /// Weight of the mean time of flight (s).
+    pub time_of_flight: f64,
+}
+
+impl Default for ObjectiveWeights {
+    /// Miss distance only.
+    fn default() -> Self {
+        Self {
+            miss_distance: 1.0,
+            acceleration: 0.0,
+            time_of_flight: 0.0,
+        }
+    }
+}
+
+/// Optimization algorithm used by [`TuningProblem::optimize`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum Optimizer {
+    /// Nelder-Mead downhill simplex, started from the parameters' initial values.
+    NelderMead {
+        /// Maximum number of simplex iterations.
+        max_iterations: usize,
+        /// Stops once the objective spread across the simplex drops below this.
+        tolerance: f64,
+    },
+    /// Real-coded genetic algorithm with tournament selection, blend crossover,
+    /// gaussian mutation and single-individual elitism.
+    Genetic {
+        /// Individuals per generation.
+        population: usize,
+        /// Number of generations.
+        generations: usize,
+        /// Mutation standard deviation as a fraction of each parameter's range.
+        mutation: f64,
+        /// Seed for the optimizer's random generator.
+        seed: u64,
+    },
+}
+
+impl Default for Optimizer {
+    fn default() -> Self {
+        Optimizer::NelderMead {
+            max_iterations: 100,
+            tolerance: 1e-3,
+        }
+    }
+}
+
+/// Outcome of a tuning run.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TuningResult {
+    /// Best parameter values as `(name, value)` pairs.
+    pub parameters: Vec<(String, f64)>,
+    /// Objective value at the best parameters.
+    pub objective: f64,
+    /// Number of objective evaluations (each one simulates every scenario).
+    pub evaluations: usize,
+    /// Best objective after each iteration/generation.
+    pub history: Vec<f64>,
+}
+
+impl TuningResult {
+    /// Returns the tuned value of the named parameter.
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.parameters
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| *v)
+    }
+}
+
+type BuildFn<'a> = dyn Fn(&[f64], &mut Scenario) -> Box<dyn GuidanceLaw> + Sync + 'a;
+
+/// Tunes guidance parameters over a fixed set of scenarios.
+///
+/// Each candidate parameter vector is handed to a build closure that returns the
+/// guidance law to fly and may also adjust the scenario (e.g. the missile's
+/// `navigation_constant`). Every scenario is then simulated and the weighted
+/// objective averaged. Use seeded scenarios so the objective is deterministic.
+///
+/// ```no_run
+/// use missile_sim::analysis::{Optimizer, TuningProblem};
+/// # let scenarios: Vec<missile_sim::prelude::Scenario> = vec![];
+///
+/// let result = TuningProblem::apn(&scenarios).optimize(&Optimizer::default()).unwrap();
+/// println!("APN time constant: {:?}", result.value("time_constant"));
+/// ```
+pub struct TuningProblem<'a> {
+    scenarios: &'a [Scenario],
+    parameters: Vec<TuningParameter>,
+    weights: ObjectiveWeights,
+    build: Box<BuildFn<'a>>,
+}
+
+impl<'a> TuningProblem<'a> {
+    /// Creates a tuning problem from scenarios, parameters and a build closure.
+    pub fn new(
+        scenarios: &'a [Scenario],
+        parameters: Vec<TuningParameter>,
+        build: impl Fn(&[f64], &mut Scenario) -> Box<dyn GuidanceLaw> + Sync + 'a,
+    ) -> Self {
+        Self {
+            scenarios,
+            parameters,
+            weights: ObjectiveWeights::default(),
+            build: Box::new(build),
+        }
+    }
+
+    /// Tunes the missile's `navigation_constant` for a fixed guidance law.
+    pub fn navigation_constant(
+        scenarios: &'a [Scenario],
+        law: impl Fn() -> Box<dyn GuidanceLaw> + Sync + 'a,
+    ) -> Self {
+        Self::new(
+            scenarios,
+            vec![TuningParameter::new("navigation_constant", 2.0, 8.0, 4.0)],
+            move |p, scenario| {
+                scenario.missile.navigation_constant = p[0];
+                law()
+            },
+        )
+    }
+
+    /// Tunes the APN `time_constant`.
+    pub fn apn(scenarios: &'a [Scenario]) -> Self {
+        Self::new(
+            scenarios,
+            vec![TuningParameter::new("time_constant", 0.1, 5.0, 0.5)],
+            |p, _| Box::new(AugmentedProportionalNavigation::new(p[0])),
+        )
+    }
+
+    /// Tunes the LP `lead_time`.
+    pub fn lead_pursuit(scenarios: &'a [Scenario]) -> Self {
+        Self::new(
+            scenarios,
+            vec![TuningParameter::new("lead_time", 0.0, 5.0, 1.0)],
+            |p, _| Box::new(LeadPursuit::new(p[0])),
+        )
+    }
+
+    /// Sets the objective weights.
+    pub fn weights(mut self, weights: ObjectiveWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Evaluates the objective for one parameter vector.
+    pub fn evaluate(&self, values: &[f64]) -> f64 {
+        if self.scenarios.is_empty() {
+            return 0.0;
+        }
+
+        let mut miss = 0.0;
+        let mut accel = 0.0;
+        let mut tof = 0.0;
+
+        for scenario in self.scenarios {
+            let mut scenario = scenario.clone();
+            scenario.recording = RecordingPolicy::None;
+            let law = (self.build)(values, &mut scenario);
+            let metrics = scenario.simulate(law.as_ref());
+
+            miss += metrics.miss_distance;
+            accel += metrics.summary.peak_acceleration_g * STANDARD_GRAVITY;
+            tof += metrics.summary.time_of_flight;
+        }
+
+        let n = self.scenarios.len() as f64;
+        (self.weights.miss_distance * miss
+            + self.weights.acceleration * accel
+            + self.weights.time_of_flight * tof)
+            / n
+    }
+
+    /// Runs the optimizer and returns the best parameters found.
+    ///
+    /// Returns an error if the problem has no parameters to tune.
+    pub fn optimize(&self, optimizer: &Optimizer) -> anyhow::Result<TuningResult> {
+        if self.parameters.is_empty() {
+            anyhow::bail!("tuning problem has no parameters");
+        }
+        let (best, objective, evaluations, history) = match *optimizer {
+            Optimizer::NelderMead {
+                max_iterations,
+                tolerance,
+            } => self.nelder_mead(max_iterations, tolerance),
+            Optimizer::Genetic {
+                population,
+                generations,
+                mutation,
+                seed,
+            } => self.genetic(population, generations, mutation, seed),
+        };
+
+        Ok(TuningResult {
+            parameters: self
+                .parameters
+                .iter()
+                .zip(best)
+                .map(|(p, v)| (p.name.clone(), v))
+                .collect(),
+            objective,
+            evaluations,
+            history,
+        })
+    }
+
+    #[inline]
+    fn clamp(&self, values: &mut [f64]) {
+        for (v, p) in values.iter_mut().zip(&self.parameters) {
+            *v = v.clamp(p.min, p.max);
+        }
+    }
+
+    fn nelder_mead(
+        &self,
+        max_iterations: usize,
+        tolerance: f64,
+    ) -> (Vec<f64>, f64, usize, Vec<f64>) {
+        let n = self.parameters.len();
+        let mut evaluations = 0;
+        let mut eval = |x: &mut Vec<f64>| {
+            self.clamp(x);
+            evaluations += 1;
+            self.evaluate(x)
+        };
+
+        // initial simplex: origin plus a 10%-of-range step along each axis
+        let origin: Vec<f64> = self.parameters.iter().map(|p| p.initial).collect();
+        let mut simplex: Vec<(Vec<f64>, f64)> = Vec::with_capacity(n + 1);
+        let mut x0 = origin.clone();
+        let f0 = eval(&mut x0);
+        simplex.push((x0, f0));
+        for (i, p) in self.parameters.iter().enumerate() {
+            let mut x = origin.clone();
+            let step = 0.1 * (p.max - p.min);
+            x[i] = if x[i] + step <= p.max {
+                x[i] + step
+            } else {
+                x[i] - step
+            };
+            let f = eval(&mut x);
+            simplex.push((x, f));
+        }
+
+        let mut history = Vec::with_capacity(max_iterations);
+        for _ in 0..max_iterations {
+            simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+            history.push(simplex[0].1);
+
+            if (simplex[n].1 - simplex[0].1).abs() < tolerance {
+                break;
+            }
+
+            let centroid: Vec<f64> = (0..n)
+                .map(|i| simplex[..n].iter().map(|(x, _)| x[i]).sum::<f64>() / n as f64)
+                .collect();
+            let towards = |t: f64, x: &[f64]| -> Vec<f64> {
+                centroid
+                    .iter()
+                    .zip(x)
+                    .map(|(c, xi)| c + t * (xi - c))
+                    .collect()
+            };
+
+            let worst = simplex[n].clone();
+            let mut reflected = towards(-1.0, &worst.0);
+            let fr = eval(&mut reflected);
+
+            if fr < simplex[0].1 {
+                let mut expanded = towards(-2.0, &worst.0);
+                let fe = eval(&mut expanded);
+                simplex[n] = if fe < fr {
+                    (expanded, fe)
+                } else {
+                    (reflected, fr)
+                };
+            } else if fr < simplex[n - 1].1 {
+                simplex[n] = (reflected, fr);
+            } else {
+                let mut contracted = towards(0.5, &worst.0);
+                let fc = eval(&mut contracted);
+                if fc < worst.1 {
+                    simplex[n] = (contracted, fc);
+                } else {
+                    // shrink everything toward the best vertex
+                    let best = simplex[0].0.clone();
+                    for vertex in simplex.iter_mut().skip(1) {
+                        let mut x: Vec<f64> = best
+                            .iter()
+                            .zip(&vertex.0)
+                            .map(|(b, xi)| b + 0.5 * (xi - b))
+                            .collect();
+                        let f = eval(&mut x);
+                        *vertex = (x, f);
+                    }
+                }
+            }
+        }
+
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let (best, objective) = simplex.swap_remove(0);
+        (best, objective, evaluations, history)
+    }
+
+    fn genetic(
+        &self,
+        population: usize,
+        generations: usize,
+        mutation: f64,
+        seed: u64,
+    ) -> (Vec<f64>, f64, usize, Vec<f64>) {
+        let population = population.max(2);
+        let mut rng = SimRng::seed_from_u64(seed);
+        let mut evaluations = 0;
+
+        let mut individuals: Vec<(Vec<f64>, f64)> = (0..population)
+            .map(|i| {
+                // keep the user's starting point in the first generation
+                let x: Vec<f64> = if i == 0 {
+                    self.parameters.iter().map(|p| p.initial).collect()
+                } else {
+                    self.parameters
+                        .iter()
+                        .map(|p| rng.range(p.min, p.max))
+                        .collect()
+                };
+                evaluations += 1;
+                let f = self.evaluate(&x);
+                (x, f)
+            })
+            .collect();
+
+        let mut history = Vec::with_capacity(generations);
+        for _ in 0..generations {
+            individuals.sort_by(|a, b| a.1.total_cmp(&b.1));
+            history.push(individuals[0].1);
+
+            let mut next = Vec::with_capacity(population);
+            next.push(individuals[0].clone()); // elitism
+
+            while next.len() < population {
+                let a = tournament(&individuals, &mut rng);
+                let b = tournament(&individuals, &mut rng);
+
+                // BLX-0.5 crossover followed by gaussian mutation
+                let mut child: Vec<f64> = a
+                    .iter()
+                    .zip(b)
+                    .zip(&self.parameters)
+                    .map(|((xa, xb), p)| {
+                        let (lo, hi) = (xa.min(*xb), xa.max(*xb));
+                        let spread = 0.5 * (hi - lo);
+                        rng.range(lo - spread, hi + spread)
+                            + rng.normal() * mutation * (p.max - p.min)
+                    })
+                    .collect();
+                self.clamp(&mut child);
+
+                evaluations += 1;
+                let f = self.evaluate(&child);
+                next.push((child, f));
+            }
+
+            individuals = next;
+        }
+
+        individuals.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let (best, objective) = individuals.swap_remove(0);
+        history.push(objective);
+        (best, objective, evaluations, history)
+    }
+}
+
+/// Binary tournament selection.
+fn tournament<'p>(individuals: &'p [(Vec<f64>, f64)], rng: &mut SimRng) -> &'p [f64] {
+    let a = &individuals[rng.index(individuals.len())];
+    let b = &individuals[rng.index(individuals.len())];
+    if a.1 <= b.1 { &a.0 } else { &b.0 }
+}
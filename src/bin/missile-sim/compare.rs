@@ -0,0 +1,64 @@
+use crate::{is_csv, write_output};
+use missile_sim::export::{RunRecord, summary_table};
+use missile_sim::prelude::*;
+use rayon::prelude::*;
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Scenario file (JSON, TOML or YAML).
+    scenario: PathBuf,
+    /// Guidance law specs to compare, repeatable. Defaults to every built-in law.
+    #[arg(long)]
+    law: Vec<String>,
+    /// Write the results to this file (`.csv` or `.json`).
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+pub fn execute(args: Args) -> anyhow::Result<()> {
+    let mut scenario = Scenario::load(&args.scenario)?;
+    scenario.recording = RecordingPolicy::None;
+
+    let registry = GuidanceRegistry::builtin();
+    let specs: Vec<String> = if args.law.is_empty() {
+        registry.names().map(str::to_string).collect()
+    } else {
+        args.law
+    };
+    let laws = specs
+        .iter()
+        .map(|spec| registry.build_str(spec))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let records: Vec<RunRecord> = laws
+        .par_iter()
+        .map(|law| RunRecord::new(&scenario, law.as_ref(), &scenario.simulate(law.as_ref())))
+        .collect();
+
+    println!("Scenario: {}", scenario.name);
+    println!(
+        "{:<28} {:>4} {:>12} {:>10} {:>10}",
+        "Guidance", "Hit", "Miss (m)", "TOF (s)", "Peak (g)"
+    );
+    for record in &records {
+        println!(
+            "{:<28} {:>4} {:>12.3} {:>10.3} {:>10.2}",
+            record.guidance,
+            if record.hit { "YES" } else { "NO" },
+            record.miss_distance,
+            record.summary.time_of_flight,
+            record.summary.peak_acceleration_g
+        );
+    }
+
+    if let Some(path) = &args.output {
+        if is_csv(Some(path)) {
+            summary_table(&records).save(path)?;
+        } else {
+            write_output(Some(path), &serde_json::to_string_pretty(&records)?)?;
+        }
+    }
+
+    Ok(())
+}
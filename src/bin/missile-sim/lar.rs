@@ -0,0 +1,86 @@
+use crate::{resolve_law, write_output};
+use missile_sim::prelude::*;
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Scenario file, every launch flies it with the missile and target moved into place.
+    scenario: PathBuf,
+    /// Guidance law spec. Defaults to the scenario's own.
+    #[arg(long)]
+    law: Option<String>,
+    /// Target speed (m/s).
+    #[arg(long, default_value_t = 300.0)]
+    target_speed: f64,
+    /// Target altitudes (m), repeatable. Defaults to the launch altitude.
+    #[arg(long = "altitude")]
+    altitudes: Vec<f64>,
+    /// Number of aspect angles between 0 and 180 deg.
+    #[arg(long, default_value_t = 13)]
+    aspects: usize,
+    /// Longest launch range considered (m).
+    #[arg(long, default_value_t = 40000.0)]
+    max_range: f64,
+    /// Fuze arming time (s).
+    #[arg(long, default_value_t = 1.5)]
+    arming_time: f64,
+    /// Write the envelope as JSON to this file.
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+pub fn execute(args: Args) -> anyhow::Result<()> {
+    let scenario = Scenario::load(&args.scenario)?;
+    let law = resolve_law(&GuidanceRegistry::builtin(), &scenario, args.law.as_deref())?;
+    anyhow::ensure!(args.aspects >= 2, "--aspects needs at least 2 angles");
+
+    let launch_altitude = scenario.missile.state.position.z;
+    let config = EnvelopeConfig {
+        base: Some(scenario.clone()),
+        missile: scenario.missile.clone(),
+        launch_altitude,
+        target_speed: args.target_speed,
+        aspect_angles: (0..args.aspects)
+            .map(|i| i as f64 * std::f64::consts::PI / (args.aspects - 1) as f64)
+            .collect(),
+        target_altitudes: if args.altitudes.is_empty() {
+            vec![launch_altitude]
+        } else {
+            args.altitudes.clone()
+        },
+        max_range: args.max_range,
+        dt: scenario.dt.max(0.001),
+        total_time: scenario.total_time,
+        hit_threshold: scenario.hit_threshold,
+        arming_time: args.arming_time,
+        ..Default::default()
+    };
+
+    let envelope = LaunchEnvelope::compute(&config, law.as_ref());
+
+    println!("Scenario: {} | Guidance: {}", scenario.name, law.spec());
+    for table in envelope.polar_tables() {
+        println!();
+        println!("Target altitude {:.0} m", table.target_altitude);
+        println!(
+            "{:>8} {:>10} {:>10} {:>10}",
+            "Aspect", "Rmin (m)", "Rmax (m)", "Rne (m)"
+        );
+        let fmt = |r: Option<f64>| r.map_or("-".to_string(), |r| format!("{r:.0}"));
+        for row in &table.rows {
+            println!(
+                "{:>8.1} {:>10} {:>10} {:>10}",
+                row.aspect_angle.to_degrees(),
+                fmt(row.r_min),
+                fmt(row.r_max),
+                fmt(row.r_ne)
+            );
+        }
+    }
+
+    if let Some(path) = &args.output {
+        write_output(Some(path), &serde_json::to_string_pretty(&envelope)?)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+//! `missile-sim` command-line application, built with `--features cli`.
+//!
+//! ```text
+//! missile-sim run scenarios/01_bvr_intercept.toml --law "APN{time_constant=1.2}"
+//! missile-sim compare scenarios/02_sea_skimmer.toml
+//! missile-sim monte-carlo scenarios/03_sam_defense.toml -n 1000 --output mc.json
+//! missile-sim sweep scenarios/05_drone_intercept.toml --law LP --param lead_time=0.2:2.0:10
+//! missile-sim lar scenarios/01_bvr_intercept.toml --law PPN --output lar.json
+//! ```
+
+mod compare;
+mod lar;
+mod monte_carlo;
+mod run;
+mod sweep;
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use missile_sim::prelude::*;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+#[command(
+    name = "missile-sim",
+    version,
+    about = "Run and batch missile engagement scenarios"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Simulate a scenario file and print or write its metrics.
+    Run(run::Args),
+    /// Run several guidance laws on one scenario.
+    Compare(compare::Args),
+    /// Run seeded dispersions of a scenario and summarize them.
+    MonteCarlo(monte_carlo::Args),
+    /// Sweep guidance and missile parameters over a grid.
+    Sweep(sweep::Args),
+    /// Compute launch acceptability regions for the scenario's missile.
+    Lar(lar::Args),
+}
+
+fn main() -> anyhow::Result<()> {
+    match Cli::parse().command {
+        Command::Run(args) => run::execute(args),
+        Command::Compare(args) => compare::execute(args),
+        Command::MonteCarlo(args) => monte_carlo::execute(args),
+        Command::Sweep(args) => sweep::execute(args),
+        Command::Lar(args) => lar::execute(args),
+    }
+}
+
+/// Guidance law from `--law`, falling back to the scenario's own.
+fn resolve_law(
+    registry: &GuidanceRegistry,
+    scenario: &Scenario,
+    law: Option<&str>,
+) -> anyhow::Result<Box<dyn GuidanceLaw>> {
+    match (law, &scenario.guidance) {
+        (Some(spec), _) => registry.build_str(spec),
+        (None, Some(spec)) => registry.build(spec),
+        (None, None) => anyhow::bail!(
+            "scenario `{}` has no guidance law, pass one with --law",
+            scenario.name
+        ),
+    }
+}
+
+/// One line of a results table, shared by `compare` and `sweep`.
+#[derive(Serialize)]
+struct ResultRow {
+    guidance: String,
+    hit: bool,
+    miss_distance: f64,
+    time_of_flight: f64,
+    peak_acceleration_g: f64,
+}
+
+impl ResultRow {
+    fn new(law: &dyn GuidanceLaw, metrics: &SimulationMetrics) -> Self {
+        Self {
+            guidance: law.spec().to_string(),
+            hit: metrics.hit,
+            miss_distance: metrics.miss_distance,
+            time_of_flight: metrics.summary.time_of_flight,
+            peak_acceleration_g: metrics.summary.peak_acceleration_g,
+        }
+    }
+
+    const CSV_HEADER: &str = "guidance,hit,miss_distance,time_of_flight,peak_acceleration_g";
+
+    fn csv(&self) -> String {
+        format!(
+            "\"{}\",{},{:.4},{:.4},{:.4}",
+            self.guidance,
+            self.hit,
+            self.miss_distance,
+            self.time_of_flight,
+            self.peak_acceleration_g
+        )
+    }
+}
+
+/// Writes `contents` to `path`, or to stdout without a path.
+fn write_output(path: Option<&Path>, contents: &str) -> anyhow::Result<()> {
+    match path {
+        Some(path) => {
+            std::fs::write(path, contents).with_context(|| format!("writing {}", path.display()))
+        }
+        None => {
+            println!("{contents}");
+            Ok(())
+        }
+    }
+}
+
+/// Whether an output path asks for CSV rather than JSON.
+fn is_csv(path: Option<&PathBuf>) -> bool {
+    path.and_then(|p| p.extension())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+}
@@ -0,0 +1,185 @@
+use crate::{is_csv, resolve_law, write_output};
+use missile_sim::prelude::*;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Scenario file (JSON, TOML or YAML).
+    scenario: PathBuf,
+    /// Guidance law spec. Defaults to the scenario's own.
+    #[arg(long)]
+    law: Option<String>,
+    /// Number of runs.
+    #[arg(long, short = 'n', default_value_t = 1000)]
+    runs: u64,
+    /// Base seed, run `i` uses `seed + i`.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// 1-sigma target position error per axis (m).
+    #[arg(long, default_value_t = 100.0)]
+    position_sigma: f64,
+    /// 1-sigma target velocity error per axis (m/s).
+    #[arg(long, default_value_t = 10.0)]
+    velocity_sigma: f64,
+    /// 1-sigma target maneuver error per axis (m/s²).
+    #[arg(long, default_value_t = 5.0)]
+    maneuver_sigma: f64,
+    /// 1-sigma missile launch heading error (deg).
+    #[arg(long, default_value_t = 2.0)]
+    heading_sigma: f64,
+    /// Write the summary and runs to this file (`.json`), or only the runs (`.csv`).
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct Run {
+    index: u64,
+    seed: u64,
+    hit: bool,
+    miss_distance: f64,
+    time_of_flight: f64,
+    peak_acceleration_g: f64,
+}
+
+#[derive(Serialize)]
+struct Summary {
+    scenario: String,
+    guidance: String,
+    runs: usize,
+    hits: usize,
+    hit_rate: f64,
+    miss_mean: f64,
+    miss_std: f64,
+    miss_p50: f64,
+    miss_p90: f64,
+    miss_max: f64,
+    time_of_flight_mean: f64,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    summary: &'a Summary,
+    runs: &'a [Run],
+}
+
+pub fn execute(args: Args) -> anyhow::Result<()> {
+    let mut base = Scenario::load(&args.scenario)?;
+    base.recording = RecordingPolicy::None;
+    let law = resolve_law(&GuidanceRegistry::builtin(), &base, args.law.as_deref())?;
+
+    let runs: Vec<Run> = (0..args.runs)
+        .into_par_iter()
+        .map(|index| {
+            let seed = args.seed.wrapping_add(index);
+            let scenario = disperse(&base, &args, seed);
+            let metrics = scenario.simulate(law.as_ref());
+            Run {
+                index,
+                seed,
+                hit: metrics.hit,
+                miss_distance: metrics.miss_distance,
+                time_of_flight: metrics.summary.time_of_flight,
+                peak_acceleration_g: metrics.summary.peak_acceleration_g,
+            }
+        })
+        .collect();
+
+    let summary = summarize(&base, law.as_ref(), &runs);
+    println!("Scenario: {}", summary.scenario);
+    println!("Guidance: {}", summary.guidance);
+    println!(
+        "Hits:     {} / {} ({:.1}%)",
+        summary.hits,
+        summary.runs,
+        summary.hit_rate * 100.0
+    );
+    println!(
+        "Miss:     mean {:.2} m, std {:.2} m, p50 {:.2} m, p90 {:.2} m, max {:.2} m",
+        summary.miss_mean, summary.miss_std, summary.miss_p50, summary.miss_p90, summary.miss_max
+    );
+    println!("TOF:      mean {:.3} s", summary.time_of_flight_mean);
+
+    if let Some(path) = &args.output {
+        let contents = if is_csv(Some(path)) {
+            std::iter::once(
+                "index,seed,hit,miss_distance,time_of_flight,peak_acceleration_g".to_string(),
+            )
+            .chain(runs.iter().map(|r| {
+                format!(
+                    "{},{},{},{:.4},{:.4},{:.4}",
+                    r.index,
+                    r.seed,
+                    r.hit,
+                    r.miss_distance,
+                    r.time_of_flight,
+                    r.peak_acceleration_g
+                )
+            }))
+            .collect::<Vec<_>>()
+            .join("\n")
+        } else {
+            serde_json::to_string_pretty(&Report {
+                summary: &summary,
+                runs: &runs,
+            })?
+        };
+        write_output(Some(path), &contents)?;
+    }
+
+    Ok(())
+}
+
+/// Applies the random dispersions for one run.
+fn disperse(base: &Scenario, args: &Args, seed: u64) -> Scenario {
+    let mut rng = SimRng::seed_from_u64(seed);
+    let mut gauss = |sigma: f64| Vector3::new(rng.normal(), rng.normal(), rng.normal()) * sigma;
+
+    let mut scenario = base.clone();
+    scenario.seed = seed;
+    scenario.target.state.position += gauss(args.position_sigma);
+    scenario.target.state.velocity += gauss(args.velocity_sigma);
+    scenario.target.acceleration += gauss(args.maneuver_sigma);
+
+    // small heading error: perturb the direction, keep the speed
+    let velocity = scenario.missile.state.velocity;
+    let speed = velocity.norm();
+    if speed > 1e-6 {
+        let direction = velocity / speed + gauss(args.heading_sigma.to_radians());
+        scenario.missile.state.velocity = direction.normalize() * speed;
+    }
+
+    scenario
+}
+
+fn summarize(scenario: &Scenario, law: &dyn GuidanceLaw, runs: &[Run]) -> Summary {
+    let n = runs.len().max(1) as f64;
+    let hits = runs.iter().filter(|r| r.hit).count();
+
+    let mut misses: Vec<f64> = runs.iter().map(|r| r.miss_distance).collect();
+    misses.sort_by(f64::total_cmp);
+    let mean = misses.iter().sum::<f64>() / n;
+    let variance = misses.iter().map(|m| (m - mean).powi(2)).sum::<f64>() / n;
+    let percentile = |p: f64| -> f64 {
+        if misses.is_empty() {
+            return 0.0;
+        }
+        misses[((misses.len() - 1) as f64 * p).round() as usize]
+    };
+
+    Summary {
+        scenario: scenario.name.clone(),
+        guidance: law.spec().to_string(),
+        runs: runs.len(),
+        hits,
+        hit_rate: hits as f64 / n,
+        miss_mean: mean,
+        miss_std: variance.sqrt(),
+        miss_p50: percentile(0.5),
+        miss_p90: percentile(0.9),
+        miss_max: misses.last().copied().unwrap_or(0.0),
+        time_of_flight_mean: runs.iter().map(|r| r.time_of_flight).sum::<f64>() / n,
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{resolve_law, write_output};
+use missile_sim::export::trajectory_table;
+use missile_sim::prelude::*;
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Scenario file (JSON, TOML or YAML).
+    scenario: PathBuf,
+    /// Guidance law spec, e.g. `APN{time_constant=1.2}`. Defaults to the scenario's own.
+    #[arg(long)]
+    law: Option<String>,
+    /// Overrides the scenario's RNG seed.
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Skip the time series and keep only the summary.
+    #[arg(long)]
+    summary_only: bool,
+    /// Write the metrics to this file: `.json` for the full metrics, `.csv` (or `.arrow`
+    /// with the `arrow` feature) for the per-step trajectory table.
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+pub fn execute(args: Args) -> anyhow::Result<()> {
+    let mut scenario = Scenario::load(&args.scenario)?;
+    let law = resolve_law(&GuidanceRegistry::builtin(), &scenario, args.law.as_deref())?;
+    if let Some(seed) = args.seed {
+        scenario.seed = seed;
+    }
+    if args.summary_only {
+        scenario.recording = RecordingPolicy::None;
+    }
+
+    let metrics = scenario.simulate(law.as_ref());
+
+    println!("Scenario: {}", scenario.name);
+    println!("Guidance: {}", law.spec());
+    println!("Result:   {}", metrics.console_summary());
+
+    if let Some(path) = &args.output {
+        if path
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("json"))
+        {
+            write_output(Some(path), &serde_json::to_string_pretty(&metrics)?)?;
+        } else {
+            trajectory_table(&metrics).save(path)?;
+        }
+        println!("Metrics written to {}", path.display());
+    } else {
+        let s = &metrics.summary;
+        println!("Time of flight:   {:.3} s", s.time_of_flight);
+        println!("Peak accel:       {:.2} g", s.peak_acceleration_g);
+        println!("RMS accel:        {:.2} g", s.rms_acceleration_g);
+        println!("Closing speed:    {:.1} m/s", s.final_closing_speed);
+        println!("Terminal speed:   {:.1} m/s", s.terminal_speed);
+        println!("Impact angle:     {:.1} deg", s.impact_angle.to_degrees());
+        println!("Saturation time:  {:.3} s", s.saturation_time);
+        if s.divert_delta_v > 0.0 {
+            println!("Divert delta-v:   {:.1} m/s", s.divert_delta_v);
+        }
+        if scenario.launcher.is_some() {
+            println!("Beam deviation:   {:.1} m peak", s.peak_beam_deviation);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use crate::{ResultRow, is_csv, write_output};
+use missile_sim::prelude::*;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Scenario file (JSON, TOML or YAML).
+    scenario: PathBuf,
+    /// Guidance law spec, swept parameters override its values. Defaults to the scenario's own.
+    #[arg(long)]
+    law: Option<String>,
+    /// Parameter range `name=start:end:count`, repeatable. The grid is the product of all
+    /// ranges. `navigation_constant` sets the missile's N', other names are law parameters.
+    #[arg(long = "param", required = true)]
+    params: Vec<String>,
+    /// Write the results to this file (`.csv` or `.json`), CSV to stdout otherwise.
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+struct Range {
+    name: String,
+    values: Vec<f64>,
+}
+
+#[derive(Serialize)]
+struct SweepRow {
+    params: Vec<(String, f64)>,
+    #[serde(flatten)]
+    result: ResultRow,
+}
+
+pub fn execute(args: Args) -> anyhow::Result<()> {
+    let mut base = Scenario::load(&args.scenario)?;
+    base.recording = RecordingPolicy::None;
+
+    let spec: GuidanceSpec = match (&args.law, &base.guidance) {
+        (Some(law), _) => law.parse()?,
+        (None, Some(spec)) => spec.clone(),
+        (None, None) => anyhow::bail!("scenario has no guidance law, pass one with --law"),
+    };
+    let ranges = args
+        .params
+        .iter()
+        .map(|p| parse_range(p))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let registry = GuidanceRegistry::builtin();
+    let grid = product(&ranges);
+    // build everything up front so a bad parameter fails before any run
+    let cases = grid
+        .into_iter()
+        .map(|point| {
+            let mut scenario = base.clone();
+            let mut spec = spec.clone();
+            for (name, value) in &point {
+                if name == "navigation_constant" {
+                    scenario.missile.navigation_constant = *value;
+                } else {
+                    spec.params.insert(name.clone(), *value);
+                }
+            }
+            Ok((point, scenario, registry.build(&spec)?))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let rows: Vec<SweepRow> = cases
+        .par_iter()
+        .map(|(point, scenario, law)| SweepRow {
+            params: point.clone(),
+            result: ResultRow::new(law.as_ref(), &scenario.simulate(law.as_ref())),
+        })
+        .collect();
+
+    let contents = if args.output.is_some() && !is_csv(args.output.as_ref()) {
+        serde_json::to_string_pretty(&rows)?
+    } else {
+        let names: Vec<&str> = ranges.iter().map(|r| r.name.as_str()).collect();
+        std::iter::once(format!("{},{}", names.join(","), ResultRow::CSV_HEADER))
+            .chain(rows.iter().map(|row| {
+                let values: Vec<String> = row.params.iter().map(|(_, v)| v.to_string()).collect();
+                format!("{},{}", values.join(","), row.result.csv())
+            }))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    write_output(args.output.as_deref(), &contents)
+}
+
+/// Parses `name=start:end:count`, or `name=value` for a single value.
+fn parse_range(text: &str) -> anyhow::Result<Range> {
+    let (name, range) = text
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("expected `name=start:end:count`, got `{text}`"))?;
+    let parts: Vec<&str> = range.split(':').collect();
+    let values = match parts.as_slice() {
+        [value] => vec![value.trim().parse()?],
+        [start, end, count] => {
+            let (start, end): (f64, f64) = (start.trim().parse()?, end.trim().parse()?);
+            let count: usize = count.trim().parse()?;
+            anyhow::ensure!(count > 0, "`{name}` needs at least one value");
+            if count == 1 {
+                vec![start]
+            } else {
+                (0..count)
+                    .map(|i| start + (end - start) * i as f64 / (count - 1) as f64)
+                    .collect()
+            }
+        }
+        _ => anyhow::bail!("expected `name=start:end:count`, got `{text}`"),
+    };
+
+    Ok(Range {
+        name: name.trim().to_string(),
+        values,
+    })
+}
+
+/// Cartesian product of all ranges.
+fn product(ranges: &[Range]) -> Vec<Vec<(String, f64)>> {
+    ranges.iter().fold(vec![Vec::new()], |points, range| {
+        points
+            .iter()
+            .flat_map(|point| {
+                range.values.iter().map(move |v| {
+                    let mut point = point.clone();
+                    point.push((range.name.clone(), *v));
+                    point
+                })
+            })
+            .collect()
+    })
+}
@@ -0,0 +1,206 @@
+use nalgebra::{Matrix3, Vector3};
+use serde::{Deserialize, Serialize};
+
+/// WGS-84 semi-major axis (m).
+pub const WGS84_A: f64 = 6_378_137.0;
+/// WGS-84 flattening.
+pub const WGS84_F: f64 = 1.0 / 298.257_223_563;
+/// WGS-84 first eccentricity squared.
+pub const WGS84_E2: f64 = WGS84_F * (2.0 - WGS84_F);
+
+/// Earth rotation rate about the ECEF `z` axis (rad/s).
+pub const EARTH_ROTATION_RATE: f64 = 7.292_115e-5;
+
+/// A WGS-84 geodetic position.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Geodetic {
+    /// Latitude in degrees, positive north.
+    pub latitude: f64,
+    /// Longitude in degrees, positive east.
+    pub longitude: f64,
+    /// Height above the ellipsoid in meters.
+    pub altitude: f64,
+}
+
+impl Geodetic {
+    /// Creates a geodetic position from degrees and meters.
+    pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Earth-centered, Earth-fixed position (m).
+    pub fn to_ecef(&self) -> Vector3<f64> {
+        let (sin_lat, cos_lat) = self.latitude.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = self.longitude.to_radians().sin_cos();
+        let n = WGS84_A / (1.0 - WGS84_E2 * sin_lat * sin_lat).sqrt();
+
+        Vector3::new(
+            (n + self.altitude) * cos_lat * cos_lon,
+            (n + self.altitude) * cos_lat * sin_lon,
+            (n * (1.0 - WGS84_E2) + self.altitude) * sin_lat,
+        )
+    }
+
+    /// Geodetic position of an ECEF point, iterated to sub-millimeter accuracy.
+    pub fn from_ecef(ecef: &Vector3<f64>) -> Self {
+        let p = ecef.x.hypot(ecef.y);
+        let longitude = ecef.y.atan2(ecef.x);
+
+        let mut lat = ecef.z.atan2(p * (1.0 - WGS84_E2));
+        for _ in 0..10 {
+            let sin_lat = lat.sin();
+            let n = WGS84_A / (1.0 - WGS84_E2 * sin_lat * sin_lat).sqrt();
+            let next = (ecef.z + WGS84_E2 * n * sin_lat).atan2(p);
+            let converged = (next - lat).abs() < 1e-14;
+            lat = next;
+            if converged {
+                break;
+            }
+        }
+
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let n = WGS84_A / (1.0 - WGS84_E2 * sin_lat * sin_lat).sqrt();
+        // valid at the poles too, unlike p / cos(lat) - n
+        let altitude = p * cos_lat + ecef.z * sin_lat - n * (1.0 - WGS84_E2 * sin_lat * sin_lat);
+
+        Self {
+            latitude: lat.to_degrees(),
+            longitude: longitude.to_degrees(),
+            altitude,
+        }
+    }
+}
+
+/// Axis convention of a [`LocalFrame`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Axes {
+    /// `x` east, `y` north, `z` up. Matches the examples, where `z` is altitude.
+    #[default]
+    Enu,
+    /// `x` north, `y` east, `z` down.
+    Ned,
+}
+
+/// A local tangent-plane frame anchored at a geodetic origin.
+///
+/// Simulation coordinates are meters in this frame. Near the origin it behaves like the
+/// flat frame the engine integrates in, and the conversions below put the results back on
+/// the curved Earth: a point 100 km east at `z = 0` is about 785 m above the ellipsoid.
+///
+/// ```
+/// use missile_sim::core::{Geodetic, LocalFrame};
+///
+/// let frame = LocalFrame::enu(Geodetic::new(36.0, -115.0, 0.0));
+/// let p = frame.from_geodetic(&Geodetic::new(36.1, -115.0, 1000.0));
+/// assert!((p.y - 11_100.0).abs() < 50.0); // ~11.1 km north
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LocalFrame {
+    /// Geodetic origin of the frame.
+    pub origin: Geodetic,
+    /// Axis convention.
+    #[serde(default)]
+    pub axes: Axes,
+}
+
+impl LocalFrame {
+    /// East-north-up frame at `origin`.
+    pub fn enu(origin: Geodetic) -> Self {
+        Self {
+            origin,
+            axes: Axes::Enu,
+        }
+    }
+
+    /// North-east-down frame at `origin`.
+    pub fn ned(origin: Geodetic) -> Self {
+        Self {
+            origin,
+            axes: Axes::Ned,
+        }
+    }
+
+    /// Rotation taking ECEF vectors into this frame.
+    pub fn ecef_to_local(&self) -> Matrix3<f64> {
+        let (sin_lat, cos_lat) = self.origin.latitude.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = self.origin.longitude.to_radians().sin_cos();
+
+        let east = [-sin_lon, cos_lon, 0.0];
+        let north = [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat];
+        let up = [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat];
+
+        let rows = match self.axes {
+            Axes::Enu => [east, north, up],
+            Axes::Ned => [north, east, up.map(|c| -c)],
+        };
+        Matrix3::from_row_slice(&rows.concat())
+    }
+
+    /// Converts a direction (velocity, acceleration) from ECEF into this frame.
+    pub fn vector_from_ecef(&self, v: &Vector3<f64>) -> Vector3<f64> {
+        self.ecef_to_local() * v
+    }
+
+    /// Converts a direction (velocity, acceleration) from this frame into ECEF.
+    pub fn vector_to_ecef(&self, v: &Vector3<f64>) -> Vector3<f64> {
+        self.ecef_to_local().transpose() * v
+    }
+
+    /// Local position of an ECEF point.
+    pub fn from_ecef(&self, ecef: &Vector3<f64>) -> Vector3<f64> {
+        self.vector_from_ecef(&(ecef - self.origin.to_ecef()))
+    }
+
+    /// ECEF position of a local point.
+    pub fn to_ecef(&self, local: &Vector3<f64>) -> Vector3<f64> {
+        self.origin.to_ecef() + self.vector_to_ecef(local)
+    }
+
+    /// Local position of a geodetic point, use it to place entities by lat/lon/alt.
+    pub fn from_geodetic(&self, geodetic: &Geodetic) -> Vector3<f64> {
+        self.from_ecef(&geodetic.to_ecef())
+    }
+
+    /// Geodetic position of a local point.
+    pub fn to_geodetic(&self, local: &Vector3<f64>) -> Geodetic {
+        Geodetic::from_ecef(&self.to_ecef(local))
+    }
+
+    /// Converts a local vector into east-north-up components.
+    pub fn to_enu(&self, v: &Vector3<f64>) -> Vector3<f64> {
+        match self.axes {
+            Axes::Enu => *v,
+            Axes::Ned => Vector3::new(v.y, v.x, -v.z),
+        }
+    }
+
+    /// Converts east-north-up components into this frame.
+    pub fn from_enu(&self, v: &Vector3<f64>) -> Vector3<f64> {
+        // the swap is its own inverse
+        self.to_enu(v)
+    }
+
+    /// Earth's angular velocity expressed in this frame (rad/s).
+    pub fn earth_rotation(&self) -> Vector3<f64> {
+        self.vector_from_ecef(&Vector3::new(0.0, 0.0, EARTH_ROTATION_RATE))
+    }
+
+    /// Coriolis and centrifugal acceleration of a body moving in this Earth-fixed frame.
+    ///
+    /// `-2 ω × v - ω × (ω × r)`, with `r` measured from the Earth's center. Gravity models
+    /// used alongside it should be pure gravitation, not effective gravity, or the
+    /// centrifugal term is counted twice.
+    pub fn rotating_acceleration(
+        &self,
+        position: &Vector3<f64>,
+        velocity: &Vector3<f64>,
+    ) -> Vector3<f64> {
+        let omega = self.earth_rotation();
+        let r = position + self.vector_from_ecef(&self.origin.to_ecef());
+        -2.0 * omega.cross(velocity) - omega.cross(&omega.cross(&r))
+    }
+}
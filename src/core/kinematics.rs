@@ -47,3 +47,47 @@ pub fn calculate_closing_speed(
     let relative_velocity = target_vel - missile_vel;
     -relative_velocity.dot(&range_vec) * inv_range
 }
+
+/// Standard gravity (m/s²), used to express accelerations in g.
+pub const STANDARD_GRAVITY: f64 = 9.80665;
+
+/// Calculates the time at which a constant-speed missile can meet a constant-velocity target.
+///
+/// Solves `|R + V_t * t| = V_m * t` for the smallest positive `t`, where `R` is the range vector.
+/// Returns `None` if no collision course exists (e.g. a faster target flying away).
+#[inline]
+pub fn calculate_intercept_time(
+    missile_pos: &Vector3<f64>,
+    missile_speed: f64,
+    target_pos: &Vector3<f64>,
+    target_vel: &Vector3<f64>,
+) -> Option<f64> {
+    let range_vec = target_pos - missile_pos;
+    let a = target_vel.norm_squared() - missile_speed * missile_speed;
+    let b = 2.0 * range_vec.dot(target_vel);
+    let c = range_vec.norm_squared();
+
+    if c < 1e-12 {
+        return Some(0.0);
+    }
+
+    if a.abs() < 1e-9 {
+        // equal speeds, the quadratic degenerates to b*t + c = 0
+        let t = -c / b;
+        return (b < 0.0 && t > 0.0).then_some(t);
+    }
+
+    let disc = b * b - 4.0 * a * c;
+    if disc < 0.0 {
+        return None;
+    }
+
+    let sqrt_disc = disc.sqrt();
+    let t1 = (-b - sqrt_disc) / (2.0 * a);
+    let t2 = (-b + sqrt_disc) / (2.0 * a);
+
+    [t1, t2]
+        .into_iter()
+        .filter(|t| *t > 0.0)
+        .min_by(|x, y| x.total_cmp(y))
+}
@@ -1,5 +1,13 @@
+mod frames;
 mod kinematics;
+mod rng;
 mod state;
+mod tables;
 
-pub use kinematics::{calculate_closing_speed, calculate_los_rate};
+pub use frames::{Axes, EARTH_ROTATION_RATE, Geodetic, LocalFrame, WGS84_A, WGS84_E2, WGS84_F};
+pub use kinematics::{
+    STANDARD_GRAVITY, calculate_closing_speed, calculate_intercept_time, calculate_los_rate,
+};
+pub use rng::SimRng;
 pub use state::State3D;
+pub use tables::{Extrapolation, Interpolation, LookupTable, MAX_TABLE_DIMENSIONS, TableAxis};
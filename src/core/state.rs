@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// Holds position and velocity vectors. Updated each simulation step
 /// by applying acceleration and integrating forward in time.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct State3D {
     /// Current position in world coordinates.
     pub position: Vector3<f64>,
@@ -0,0 +1,424 @@
+use anyhow::{Context, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Most axes a [`LookupTable`] can have.
+pub const MAX_TABLE_DIMENSIONS: usize = 4;
+
+/// How a [`LookupTable`] blends between breakpoints.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Interpolation {
+    /// Multilinear.
+    #[default]
+    Linear,
+    /// Cubic Hermite with finite-difference slopes (Catmull-Rom on uneven grids), per axis.
+    Cubic,
+}
+
+/// How a [`LookupTable`] answers outside its breakpoints.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Extrapolation {
+    /// Hold the edge value.
+    #[default]
+    Clamp,
+    /// Continue the edge interval's straight line.
+    Linear,
+}
+
+/// One axis of a [`LookupTable`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TableAxis {
+    /// Name of the variable, e.g. `mach`.
+    pub name: String,
+    /// Strictly increasing breakpoints.
+    pub breakpoints: Vec<f64>,
+}
+
+impl TableAxis {
+    /// Creates an axis.
+    pub fn new(name: &str, breakpoints: Vec<f64>) -> Self {
+        Self {
+            name: name.to_string(),
+            breakpoints,
+        }
+    }
+}
+
+/// Gridded data of 1 to 4 variables, e.g. `C_D0` vs Mach or `C_N` vs Mach × alpha × fin.
+///
+/// `values` is row-major with the last axis varying fastest. Lookups remember the last
+/// bracketing interval of every axis and search from there, so walking smoothly through
+/// a table in the simulation loop costs a comparison or two per axis.
+///
+/// Tables serialize as plain data (JSON, TOML, YAML) and [`LookupTable::from_csv`] reads long
+/// format CSV, one row per grid point:
+///
+/// ```text
+/// mach,alpha,cn
+/// 0.5,0.0,0.0
+/// 0.5,0.2,3.9
+/// ...
+/// ```
+///
+/// Deserialized tables go through [`LookupTable::new`] and fail on the same malformed grids.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawTable")]
+pub struct LookupTable {
+    /// Axes, outermost first.
+    pub axes: Vec<TableAxis>,
+    /// Values at the grid points.
+    pub values: Vec<f64>,
+    /// Interpolation, linear by default.
+    #[serde(default)]
+    pub interpolation: Interpolation,
+    /// Extrapolation, clamped by default.
+    #[serde(default)]
+    pub extrapolation: Extrapolation,
+    /// Last bracketing interval per axis.
+    #[serde(skip)]
+    hint: Hint,
+}
+
+/// Serialized form of a [`LookupTable`], checked by [`LookupTable::new`] on the way in.
+#[derive(Deserialize)]
+struct RawTable {
+    axes: Vec<TableAxis>,
+    values: Vec<f64>,
+    #[serde(default)]
+    interpolation: Interpolation,
+    #[serde(default)]
+    extrapolation: Extrapolation,
+}
+
+impl TryFrom<RawTable> for LookupTable {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: RawTable) -> anyhow::Result<Self> {
+        Ok(Self::new(raw.axes, raw.values)?
+            .with_interpolation(raw.interpolation)
+            .with_extrapolation(raw.extrapolation))
+    }
+}
+
+/// Up to four `(index, weight)` pairs along one axis.
+#[derive(Clone, Copy, Default)]
+struct Weights {
+    pairs: [(usize, f64); 4],
+    len: usize,
+}
+
+impl Weights {
+    #[inline(always)]
+    fn add(&mut self, index: usize, weight: f64) {
+        if let Some(pair) = self.pairs[..self.len].iter_mut().find(|p| p.0 == index) {
+            pair.1 += weight;
+        } else {
+            self.pairs[self.len] = (index, weight);
+            self.len += 1;
+        }
+    }
+}
+
+impl LookupTable {
+    /// Creates a table, checking that the axes are increasing and the values fill the grid.
+    pub fn new(axes: Vec<TableAxis>, values: Vec<f64>) -> anyhow::Result<Self> {
+        if axes.is_empty() || axes.len() > MAX_TABLE_DIMENSIONS {
+            bail!(
+                "tables have 1 to {MAX_TABLE_DIMENSIONS} axes, got {}",
+                axes.len()
+            );
+        }
+        for axis in &axes {
+            if axis.breakpoints.is_empty() {
+                bail!("axis `{}` has no breakpoints", axis.name);
+            }
+            if axis.breakpoints.windows(2).any(|w| w[0] >= w[1]) {
+                bail!("axis `{}` is not strictly increasing", axis.name);
+            }
+        }
+        let size: usize = axes.iter().map(|a| a.breakpoints.len()).product();
+        if values.len() != size {
+            bail!("table grid has {size} points but {} values", values.len());
+        }
+
+        Ok(Self {
+            axes,
+            values,
+            interpolation: Interpolation::Linear,
+            extrapolation: Extrapolation::Clamp,
+            hint: Hint::default(),
+        })
+    }
+
+    /// One-dimensional table of `values` at `breakpoints`.
+    pub fn new_1d(name: &str, breakpoints: Vec<f64>, values: Vec<f64>) -> anyhow::Result<Self> {
+        Self::new(vec![TableAxis::new(name, breakpoints)], values)
+    }
+
+    /// Sets the interpolation.
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Sets the extrapolation.
+    pub fn with_extrapolation(mut self, extrapolation: Extrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    /// Number of axes.
+    pub fn dimensions(&self) -> usize {
+        self.axes.len()
+    }
+
+    /// Parses a table from JSON, see [`LookupTable`] for the layout.
+    #[cfg(feature = "json")]
+    pub fn from_json(text: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(text)?)
+    }
+
+    /// Parses a long-format CSV table: a header naming the axes then the value, and one
+    /// row per grid point in any order.
+    pub fn from_csv(text: &str) -> anyhow::Result<Self> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        let (_, header) = lines.next().context("empty table")?;
+        let names: Vec<&str> = header.split(',').map(str::trim).collect();
+        if names.len() < 2 {
+            bail!("table header needs at least one axis and a value column");
+        }
+        let dimensions = names.len() - 1;
+
+        let rows = lines
+            .map(|(n, line)| {
+                let row = line
+                    .split(',')
+                    .map(|v| v.trim().parse::<f64>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .with_context(|| format!("invalid number on line {}", n + 1))?;
+                if row.len() != names.len() {
+                    bail!(
+                        "line {} has {} columns, expected {}",
+                        n + 1,
+                        row.len(),
+                        names.len()
+                    );
+                }
+                Ok(row)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let axes: Vec<TableAxis> = (0..dimensions)
+            .map(|d| {
+                let unique: BTreeSet<u64> = rows.iter().map(|row| ordered_bits(row[d])).collect();
+                TableAxis::new(
+                    names[d],
+                    unique.into_iter().map(from_ordered_bits).collect(),
+                )
+            })
+            .collect();
+
+        let size: usize = axes.iter().map(|a| a.breakpoints.len()).product();
+        let mut values = vec![f64::NAN; size];
+        for row in &rows {
+            let mut offset = 0;
+            for (d, axis) in axes.iter().enumerate() {
+                let i = axis.breakpoints.partition_point(|b| *b < row[d]);
+                offset = offset * axis.breakpoints.len() + i;
+            }
+            values[offset] = row[dimensions];
+        }
+        if values.iter().any(|v| v.is_nan()) {
+            bail!("table rows do not cover the full grid");
+        }
+
+        Self::new(axes, values)
+    }
+
+    /// Loads a table from a `.csv` or `.json` file.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        match extension.to_ascii_lowercase().as_str() {
+            "csv" => Self::from_csv(&text),
+            #[cfg(feature = "json")]
+            "json" => Self::from_json(&text),
+            _ => bail!("unsupported table file `{}`", path.display()),
+        }
+        .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Interpolated value at `point`, one coordinate per axis.
+    #[inline]
+    pub fn lookup(&self, point: &[f64]) -> f64 {
+        debug_assert_eq!(point.len(), self.axes.len());
+        let mut weights = [Weights::default(); MAX_TABLE_DIMENSIONS];
+        for (d, axis) in self.axes.iter().enumerate() {
+            weights[d] = self.axis_weights(d, &axis.breakpoints, point[d]);
+        }
+
+        // sum over the tensor product of per-axis weights
+        let dimensions = self.axes.len();
+        let mut counter = [0usize; MAX_TABLE_DIMENSIONS];
+        let mut total = 0.0;
+        loop {
+            let mut offset = 0;
+            let mut weight = 1.0;
+            for d in 0..dimensions {
+                let (index, w) = weights[d].pairs[counter[d]];
+                offset = offset * self.axes[d].breakpoints.len() + index;
+                weight *= w;
+            }
+            total += weight * self.values[offset];
+
+            // next combination, last axis fastest
+            let mut d = dimensions;
+            loop {
+                if d == 0 {
+                    return total;
+                }
+                d -= 1;
+                counter[d] += 1;
+                if counter[d] < weights[d].len {
+                    break;
+                }
+                counter[d] = 0;
+            }
+        }
+    }
+
+    /// Shorthand for 1-D tables.
+    #[inline]
+    pub fn lookup1(&self, x: f64) -> f64 {
+        self.lookup(&[x])
+    }
+
+    /// Interpolation weights of the grid values along one axis.
+    #[inline(always)]
+    fn axis_weights(&self, axis: usize, x: &[f64], query: f64) -> Weights {
+        let mut weights = Weights::default();
+        let n = x.len();
+        if n == 1 {
+            weights.add(0, 1.0);
+            return weights;
+        }
+
+        let i = self.hint.interval(axis, x, query);
+        let span = x[i + 1] - x[i];
+        let t = (query - x[i]) / span;
+        let outside = !(0.0..=1.0).contains(&t);
+
+        if outside && self.extrapolation == Extrapolation::Linear
+            || self.interpolation == Interpolation::Linear
+        {
+            let t = if outside && self.extrapolation == Extrapolation::Clamp {
+                t.clamp(0.0, 1.0)
+            } else {
+                t
+            };
+            weights.add(i, 1.0 - t);
+            weights.add(i + 1, t);
+            return weights;
+        }
+
+        // cubic Hermite, slopes as weights on the neighboring values
+        let t = t.clamp(0.0, 1.0);
+        let (t2, t3) = (t * t, t * t * t);
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+
+        weights.add(i, h00);
+        weights.add(i + 1, h01);
+        let (lo, hi) = (i.saturating_sub(1), i + 1);
+        let slope = span * h10 / (x[hi] - x[lo]);
+        weights.add(hi, slope);
+        weights.add(lo, -slope);
+        let (lo, hi) = (i, (i + 2).min(n - 1));
+        let slope = span * h11 / (x[hi] - x[lo]);
+        weights.add(hi, slope);
+        weights.add(lo, -slope);
+        weights
+    }
+}
+
+/// Last bracketing interval per axis, shared lock-free so tables stay `Sync`.
+#[derive(Default)]
+struct Hint([AtomicUsize; MAX_TABLE_DIMENSIONS]);
+
+impl Hint {
+    /// Index `i` with `x[i] <= query < x[i + 1]`, clamped to `0..=n - 2`.
+    #[inline(always)]
+    fn interval(&self, axis: usize, x: &[f64], query: f64) -> usize {
+        let last = x.len() - 2;
+        let hint = self.0[axis].load(Ordering::Relaxed).min(last);
+        let inside = |i: usize| (i == 0 || x[i] <= query) && (i == last || query < x[i + 1]);
+
+        let i = if inside(hint) {
+            hint
+        } else if hint < last && inside(hint + 1) {
+            hint + 1
+        } else if hint > 0 && inside(hint - 1) {
+            hint - 1
+        } else {
+            x.partition_point(|b| *b <= query)
+                .saturating_sub(1)
+                .min(last)
+        };
+        self.0[axis].store(i, Ordering::Relaxed);
+        i
+    }
+}
+
+impl Clone for Hint {
+    fn clone(&self) -> Self {
+        Self(std::array::from_fn(|d| {
+            AtomicUsize::new(self.0[d].load(Ordering::Relaxed))
+        }))
+    }
+}
+
+impl PartialEq for Hint {
+    /// Hints are a cache and never make tables differ.
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl std::fmt::Debug for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Hint")
+    }
+}
+
+/// Maps an `f64` to bits that sort like the number, for de-duplicating breakpoints.
+fn ordered_bits(value: f64) -> u64 {
+    let bits = value.to_bits();
+    if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits | 1 << 63
+    }
+}
+
+/// Inverse of [`ordered_bits`].
+fn from_ordered_bits(bits: u64) -> f64 {
+    f64::from_bits(if bits >> 63 == 1 {
+        bits & !(1 << 63)
+    } else {
+        !bits
+    })
+}
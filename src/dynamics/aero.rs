@@ -0,0 +1,228 @@
+use crate::core::LookupTable;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Aerodynamic coefficients of a 6-DOF airframe at one flight condition.
+///
+/// Missiles are treated as two identical lateral planes. In the pitch plane the angle is
+/// the angle of attack, the normal force points along body `-z` and a positive moment
+/// pitches the nose up. The yaw plane mirrors it with sideslip, the normal force along
+/// body `-y` and a positive moment yawing the nose left. This way one set of derivatives
+/// describes both planes of an axisymmetric body.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AeroCoefficients {
+    /// Axial force coefficient `C_A`, positive backwards.
+    pub axial: f64,
+    /// Normal force coefficients `C_N` of the pitch and yaw planes.
+    pub normal: [f64; 2],
+    /// Roll moment coefficient `C_l`.
+    pub roll: f64,
+    /// Moment coefficients `C_m` of the pitch and yaw planes.
+    pub moment: [f64; 2],
+}
+
+/// Aerodynamic model of a [`crate::dynamics::SixDof`] airframe.
+///
+/// Tagged by `model` in scenario files.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "model", rename_all = "snake_case")]
+pub enum Aerodynamics {
+    /// Constant stability and control derivatives.
+    Linear(LinearAero),
+    /// Coefficients interpolated from wind-tunnel or CFD tables.
+    Tabulated(Box<TabulatedAero>),
+}
+
+impl Default for Aerodynamics {
+    fn default() -> Self {
+        Self::Linear(LinearAero::default())
+    }
+}
+
+impl Aerodynamics {
+    /// Coefficients at `mach` for plane angles `angles` (angle of attack, sideslip, rad),
+    /// fin deflections `fins` (roll, pitch, yaw, rad) and non-dimensional body rates
+    /// `rates` (`ω d / 2V` for roll, pitch plane and yaw plane).
+    #[inline]
+    pub fn coefficients(
+        &self,
+        mach: f64,
+        angles: [f64; 2],
+        fins: &Vector3<f64>,
+        rates: &Vector3<f64>,
+    ) -> AeroCoefficients {
+        match self {
+            Self::Linear(linear) => linear.coefficients(mach, angles, fins, rates),
+            Self::Tabulated(tabulated) => tabulated.coefficients(mach, angles, fins, rates),
+        }
+    }
+}
+
+/// Linear aerodynamics from constant derivatives (per radian), independent of Mach.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LinearAero {
+    /// Axial force coefficient `C_A`.
+    pub axial: f64,
+    /// Normal force slope `C_Nα`.
+    pub normal_alpha: f64,
+    /// Normal force per fin deflection `C_Nδ`, negative for tail control.
+    pub normal_delta: f64,
+    /// Static stability `C_mα`, negative for a stable airframe.
+    pub moment_alpha: f64,
+    /// Control power `C_mδ`.
+    pub moment_delta: f64,
+    /// Pitch damping `C_mq`.
+    pub moment_damping: f64,
+    /// Roll control power `C_lδ`.
+    pub roll_delta: f64,
+    /// Roll damping `C_lp`.
+    pub roll_damping: f64,
+}
+
+impl Default for LinearAero {
+    /// A statically stable tail-controlled missile, half a caliber of static margin.
+    fn default() -> Self {
+        Self {
+            axial: 0.3,
+            normal_alpha: 20.0,
+            normal_delta: -3.0,
+            moment_alpha: -10.0,
+            moment_delta: 20.0,
+            moment_damping: -200.0,
+            roll_delta: 2.0,
+            roll_damping: -5.0,
+        }
+    }
+}
+
+impl LinearAero {
+    /// See [`Aerodynamics::coefficients`].
+    #[inline]
+    pub fn coefficients(
+        &self,
+        _mach: f64,
+        angles: [f64; 2],
+        fins: &Vector3<f64>,
+        rates: &Vector3<f64>,
+    ) -> AeroCoefficients {
+        let plane = |i: usize| {
+            (
+                self.normal_alpha * angles[i] + self.normal_delta * fins[i + 1],
+                self.moment_alpha * angles[i]
+                    + self.moment_delta * fins[i + 1]
+                    + self.moment_damping * rates[i + 1],
+            )
+        };
+        let (pitch_normal, pitch_moment) = plane(0);
+        let (yaw_normal, yaw_moment) = plane(1);
+
+        AeroCoefficients {
+            axial: self.axial,
+            normal: [pitch_normal, yaw_normal],
+            roll: self.roll_delta * fins.x + self.roll_damping * rates.x,
+            moment: [pitch_moment, yaw_moment],
+        }
+    }
+}
+
+/// Aerodynamics from [`LookupTable`]s, for data that is nonlinear in Mach and flow angle.
+///
+/// The normal force and moment tables are indexed by Mach, plane angle and fin deflection
+/// (rad). Both planes share them and the body is symmetric, `C(-α, -δ) = -C(α, δ)`, so
+/// the tables only need non-negative angles.
+///
+/// Deserialized models are checked with [`TabulatedAero::validate`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawTabulatedAero")]
+pub struct TabulatedAero {
+    /// `C_A` against Mach.
+    pub axial: LookupTable,
+    /// `C_N` against Mach, angle and fin deflection.
+    pub normal: LookupTable,
+    /// `C_m` against Mach, angle and fin deflection.
+    pub moment: LookupTable,
+    /// Pitch damping `C_mq` against Mach.
+    pub moment_damping: LookupTable,
+    /// Roll control power `C_lδ`.
+    pub roll_delta: f64,
+    /// Roll damping `C_lp`.
+    pub roll_damping: f64,
+}
+
+/// Serialized form of a [`TabulatedAero`], validated on the way in.
+#[derive(Deserialize)]
+struct RawTabulatedAero {
+    axial: LookupTable,
+    normal: LookupTable,
+    moment: LookupTable,
+    moment_damping: LookupTable,
+    roll_delta: f64,
+    roll_damping: f64,
+}
+
+impl TryFrom<RawTabulatedAero> for TabulatedAero {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: RawTabulatedAero) -> anyhow::Result<Self> {
+        let aero = Self {
+            axial: raw.axial,
+            normal: raw.normal,
+            moment: raw.moment,
+            moment_damping: raw.moment_damping,
+            roll_delta: raw.roll_delta,
+            roll_damping: raw.roll_damping,
+        };
+        aero.validate()?;
+        Ok(aero)
+    }
+}
+
+impl TabulatedAero {
+    /// Checks the table shapes: `axial` and `moment_damping` against Mach alone, `normal`
+    /// and `moment` against Mach, angle and fin deflection.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for (name, table, dimensions) in [
+            ("axial", &self.axial, 1),
+            ("normal", &self.normal, 3),
+            ("moment", &self.moment, 3),
+            ("moment_damping", &self.moment_damping, 1),
+        ] {
+            if table.dimensions() != dimensions {
+                anyhow::bail!(
+                    "tabulated `{name}` needs {dimensions} axes, got {}",
+                    table.dimensions()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// See [`Aerodynamics::coefficients`].
+    #[inline]
+    pub fn coefficients(
+        &self,
+        mach: f64,
+        angles: [f64; 2],
+        fins: &Vector3<f64>,
+        rates: &Vector3<f64>,
+    ) -> AeroCoefficients {
+        let damping = self.moment_damping.lookup1(mach);
+        let plane = |i: usize| {
+            let sign = if angles[i] < 0.0 { -1.0 } else { 1.0 };
+            let point = [mach, angles[i].abs(), sign * fins[i + 1]];
+            (
+                sign * self.normal.lookup(&point),
+                sign * self.moment.lookup(&point) + damping * rates[i + 1],
+            )
+        };
+        let (pitch_normal, pitch_moment) = plane(0);
+        let (yaw_normal, yaw_moment) = plane(1);
+
+        AeroCoefficients {
+            axial: self.axial.lookup1(mach),
+            normal: [pitch_normal, yaw_normal],
+            roll: self.roll_delta * fins.x + self.roll_damping * rates.x,
+            moment: [pitch_moment, yaw_moment],
+        }
+    }
+}
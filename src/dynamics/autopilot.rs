@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+/// Three-loop acceleration autopilot: acceleration feedback, an integrator for synthetic
+/// stability and an inner rate-damping loop.
+///
+/// Per lateral plane, with `e = a_c - a_m` the acceleration error:
+/// - `q_c = (a_c + K_A e + K_I ∫e) / V` — body rate needed to turn the velocity at `a_c`
+/// - `δ_c = ω_R (q_c - q) / M_δ` — fin command closing the rate loop at `ω_R`
+///
+/// `M_δ`, the pitch acceleration per radian of fin, is taken from the aerodynamic model
+/// at the current flight condition, so the gains are scheduled with dynamic pressure and
+/// Mach automatically. Roll rate is damped the same way at `roll_bandwidth`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThreeLoopAutopilot {
+    /// Proportional acceleration gain `K_A`.
+    pub acceleration_gain: f64,
+    /// Integral acceleration gain `K_I` (1/s).
+    pub integral_gain: f64,
+    /// Rate loop bandwidth `ω_R` (rad/s).
+    pub rate_bandwidth: f64,
+    /// Roll rate loop bandwidth (rad/s).
+    pub roll_bandwidth: f64,
+}
+
+impl Default for ThreeLoopAutopilot {
+    fn default() -> Self {
+        Self {
+            acceleration_gain: 6.0,
+            integral_gain: 15.0,
+            rate_bandwidth: 60.0,
+            roll_bandwidth: 20.0,
+        }
+    }
+}
+
+impl ThreeLoopAutopilot {
+    /// Fin command for one lateral plane.
+    ///
+    /// * `commanded`, `measured` — commanded and achieved acceleration (m/s²).
+    /// * `integral` — the plane's integrator, updated unless `frozen` (fins saturated).
+    /// * `rate` — body rate of the plane (rad/s), `airspeed` (m/s).
+    /// * `control_power` — `M_δ` (rad/s² per rad).
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub fn plane_command(
+        &self,
+        commanded: f64,
+        measured: f64,
+        integral: &mut f64,
+        frozen: bool,
+        rate: f64,
+        airspeed: f64,
+        control_power: f64,
+        dt: f64,
+    ) -> f64 {
+        let error = commanded - measured;
+        if !frozen {
+            *integral += error * dt;
+        }
+        let rate_command =
+            (commanded + self.acceleration_gain * error + self.integral_gain * *integral)
+                / airspeed;
+        self.rate_bandwidth * (rate_command - rate) / control_power
+    }
+
+    /// Fin command damping the roll rate `p` with roll control power `L_δ`.
+    #[inline]
+    pub fn roll_command(&self, roll_rate: f64, control_power: f64) -> f64 {
+        -self.roll_bandwidth * roll_rate / control_power
+    }
+}
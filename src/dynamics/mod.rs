@@ -0,0 +1,14 @@
+//! Rigid-body (6-DOF) missile dynamics.
+//!
+//! [`SixDof`] replaces the point-mass missile with an airframe that turns by flying at an
+//! angle of attack: [`Aerodynamics`] give forces and moments from Mach, flow angles, fin
+//! deflections and body rates, and a [`ThreeLoopAutopilot`] moves the fins to track the
+//! guidance law's acceleration command.
+
+mod aero;
+mod autopilot;
+mod six_dof;
+
+pub use aero::{AeroCoefficients, Aerodynamics, LinearAero, TabulatedAero};
+pub use autopilot::ThreeLoopAutopilot;
+pub use six_dof::{FinActuator, SixDof, SixDofState};
@@ -0,0 +1,313 @@
+use crate::core::{LookupTable, State3D};
+use crate::dynamics::{Aerodynamics, ThreeLoopAutopilot};
+use crate::environment::Atmosphere;
+use nalgebra::{UnitQuaternion, Vector3};
+use serde::{Deserialize, Serialize};
+
+/// Longest integration substep (s), the short-period and roll modes are fast.
+const MAX_SUBSTEP: f64 = 5e-4;
+
+/// Rigid-body missile airframe flown by a [`ThreeLoopAutopilot`].
+///
+/// Set on [`crate::simulation::Scenario::six_dof`] to replace the point-mass missile:
+/// the guidance law's acceleration command (limited to `max_acceleration` like the
+/// point mass) is tracked by the autopilot through fin deflections, and the missile
+/// turns by flying at an angle of attack. The [`crate::entity::Missile`] state is kept
+/// in sync, so any [`crate::guidance::GuidanceLaw`] drives it unchanged.
+///
+/// Body axes are `x` through the nose, `y` to the right and `z` down.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SixDof {
+    /// Mass (kg).
+    pub mass: f64,
+    /// Principal moments of inertia about body `x`, `y`, `z` (kg·m²).
+    pub inertia: [f64; 3],
+    /// Aerodynamic reference area `S` (m²).
+    pub reference_area: f64,
+    /// Aerodynamic reference length `d` (m), usually the diameter.
+    pub reference_length: f64,
+    /// Motor thrust along body `x` (N).
+    #[serde(default)]
+    pub thrust: f64,
+    /// Motor burn time (s).
+    #[serde(default)]
+    pub burn_time: f64,
+    /// Thrust (N) against time since launch (s), replacing `thrust` and `burn_time`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thrust_curve: Option<LookupTable>,
+    /// Force and moment coefficients.
+    #[serde(default)]
+    pub aerodynamics: Aerodynamics,
+    /// Acceleration autopilot.
+    #[serde(default)]
+    pub autopilot: ThreeLoopAutopilot,
+    /// Fin servos.
+    #[serde(default)]
+    pub actuator: FinActuator,
+}
+
+/// First-order fin servos with a deflection limit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FinActuator {
+    /// Time constant (s).
+    pub time_constant: f64,
+    /// Largest deflection (rad).
+    pub max_deflection: f64,
+}
+
+impl Default for FinActuator {
+    fn default() -> Self {
+        Self {
+            time_constant: 0.02,
+            max_deflection: 20f64.to_radians(),
+        }
+    }
+}
+
+impl Default for SixDof {
+    /// A 150 kg, 3 m, 18 cm diameter missile with [`crate::dynamics::LinearAero`] defaults.
+    fn default() -> Self {
+        let (mass, length, radius): (f64, f64, f64) = (150.0, 3.0, 0.09);
+        let transverse = mass * length * length / 12.0;
+        Self {
+            mass,
+            inertia: [0.5 * mass * radius * radius, transverse, transverse],
+            reference_area: std::f64::consts::PI * radius * radius,
+            reference_length: 2.0 * radius,
+            thrust: 0.0,
+            burn_time: 0.0,
+            thrust_curve: None,
+            aerodynamics: Aerodynamics::default(),
+            autopilot: ThreeLoopAutopilot::default(),
+            actuator: FinActuator::default(),
+        }
+    }
+}
+
+/// Rotational and control state of a [`SixDof`] airframe, kept by the engine.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SixDofState {
+    /// Body-to-world rotation.
+    pub attitude: UnitQuaternion<f64>,
+    /// Body rates `p`, `q`, `r` (rad/s).
+    pub angular_velocity: Vector3<f64>,
+    /// Fin deflections for roll, pitch and yaw (rad).
+    pub fins: Vector3<f64>,
+    /// Angle of attack (rad).
+    pub angle_of_attack: f64,
+    /// Sideslip angle (rad).
+    pub sideslip: f64,
+    /// Mach number.
+    pub mach: f64,
+    /// Time since launch, for the motor (s).
+    pub time: f64,
+    /// Autopilot integrators of the pitch and yaw planes.
+    integrals: [f64; 2],
+}
+
+impl SixDofState {
+    /// Wings-level attitude with the nose along `velocity`, at rest otherwise.
+    pub fn aligned(velocity: &Vector3<f64>) -> Self {
+        let forward = velocity.try_normalize(1e-9).unwrap_or_else(Vector3::x);
+        let right = forward
+            .cross(&Vector3::z())
+            .try_normalize(1e-9)
+            .unwrap_or_else(|| -Vector3::y());
+        let down = forward.cross(&right);
+        let rotation = nalgebra::Rotation3::from_basis_unchecked(&[forward, right, down]);
+
+        Self {
+            attitude: UnitQuaternion::from_rotation_matrix(&rotation),
+            angular_velocity: Vector3::zeros(),
+            fins: Vector3::zeros(),
+            angle_of_attack: 0.0,
+            sideslip: 0.0,
+            mach: 0.0,
+            time: 0.0,
+            integrals: [0.0; 2],
+        }
+    }
+}
+
+/// Forces on the airframe at one instant.
+struct Loads {
+    /// Aerodynamic and thrust force over mass, body axes (m/s²).
+    specific_force: Vector3<f64>,
+    /// Aerodynamic moment, body axes (N·m).
+    moment: Vector3<f64>,
+    /// `q̄ S d` (N·m).
+    moment_scale: f64,
+    /// Airspeed (m/s).
+    airspeed: f64,
+}
+
+impl SixDof {
+    /// Time since launch the motor burns out (s): the end of the thrust curve, or
+    /// `burn_time`. `None` without a motor.
+    pub fn burnout_time(&self) -> Option<f64> {
+        match &self.thrust_curve {
+            Some(curve) => curve.axes[0].breakpoints.last().copied(),
+            None => (self.thrust > 0.0 && self.burn_time > 0.0).then_some(self.burn_time),
+        }
+    }
+
+    /// Advances airframe and missile `state` by `dt`.
+    ///
+    /// * `command` — limited guidance acceleration, world axes (m/s²).
+    /// * `external` — gravity and other non-aerodynamic accelerations (m/s²).
+    /// * `wind` — air velocity around the missile (m/s).
+    ///
+    /// Returns the achieved lateral acceleration in world axes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn step(
+        &self,
+        body: &mut SixDofState,
+        state: &mut State3D,
+        command: Vector3<f64>,
+        external: Vector3<f64>,
+        wind: Vector3<f64>,
+        atmosphere: &Atmosphere,
+        dt: f64,
+    ) -> Vector3<f64> {
+        let substeps = (dt / MAX_SUBSTEP).ceil().max(1.0);
+        let h = dt / substeps;
+        let mut lateral = Vector3::zeros();
+
+        for _ in 0..substeps as usize {
+            let rotation = body.attitude.to_rotation_matrix();
+            let loads = self.loads(body, state, &wind, atmosphere);
+            self.autopilot(body, &loads, &(rotation.inverse() * command), h);
+
+            // translation, semi-implicit like the point mass
+            let force = rotation * loads.specific_force;
+            state.position += state.velocity * h;
+            state.velocity += (force + external) * h;
+
+            // rotation
+            let inertia = Vector3::from(self.inertia);
+            let omega = body.angular_velocity;
+            let gyroscopic = omega.cross(&inertia.component_mul(&omega));
+            body.angular_velocity += (loads.moment - gyroscopic).component_div(&inertia) * h;
+            body.attitude *= UnitQuaternion::from_scaled_axis(body.angular_velocity * h);
+            body.time += h;
+
+            let speed = state.velocity.norm().max(1e-9);
+            let along = state.velocity / speed;
+            lateral = force - along * along.dot(&force);
+        }
+        lateral
+    }
+
+    /// Aerodynamic and thrust loads for the current state, also updating the flow angles.
+    #[inline]
+    fn loads(
+        &self,
+        body: &mut SixDofState,
+        state: &State3D,
+        wind: &Vector3<f64>,
+        atmosphere: &Atmosphere,
+    ) -> Loads {
+        let altitude = state.position.z;
+        let air = body.attitude.inverse() * (state.velocity - wind);
+        let airspeed = air.norm().max(1.0);
+        body.angle_of_attack = air.z.atan2(air.x);
+        body.sideslip = (air.y / airspeed).clamp(-1.0, 1.0).asin();
+        body.mach = airspeed / atmosphere.speed_of_sound(altitude);
+
+        let pressure = 0.5 * atmosphere.density(altitude) * airspeed * airspeed;
+        let force_scale = pressure * self.reference_area;
+        let moment_scale = force_scale * self.reference_length;
+
+        let rates = self.plane_rates(body) * (self.reference_length / (2.0 * airspeed));
+        let c = self.aerodynamics.coefficients(
+            body.mach,
+            [body.angle_of_attack, body.sideslip],
+            &body.fins,
+            &rates,
+        );
+
+        let thrust = match &self.thrust_curve {
+            Some(curve) => curve.lookup1(body.time),
+            None if body.time < self.burn_time => self.thrust,
+            None => 0.0,
+        };
+        Loads {
+            specific_force: Vector3::new(
+                thrust - force_scale * c.axial,
+                -force_scale * c.normal[1],
+                -force_scale * c.normal[0],
+            ) / self.mass,
+            moment: Vector3::new(c.roll, c.moment[0], -c.moment[1]) * moment_scale,
+            moment_scale,
+            airspeed,
+        }
+    }
+
+    /// Roll rate and the pitch and yaw plane rates (rad/s), see [`Aerodynamics`].
+    #[inline(always)]
+    fn plane_rates(&self, body: &SixDofState) -> Vector3<f64> {
+        let omega = body.angular_velocity;
+        Vector3::new(omega.x, omega.y, -omega.z)
+    }
+
+    /// Runs the autopilot and moves the fins toward its command.
+    #[inline]
+    fn autopilot(&self, body: &mut SixDofState, loads: &Loads, command: &Vector3<f64>, dt: f64) {
+        let limit = self.actuator.max_deflection;
+        let rates = self.plane_rates(body);
+        let commanded = [-command.z, -command.y];
+        let measured = [-loads.specific_force.z, -loads.specific_force.y];
+        let inertia = [self.inertia[1], self.inertia[2]];
+
+        let mut fin_command = Vector3::zeros();
+        for plane in 0..2 {
+            let power =
+                nonzero(self.control_power(body, plane + 1) * loads.moment_scale / inertia[plane]);
+            let frozen = body.fins[plane + 1].abs() >= limit;
+            fin_command[plane + 1] = self.autopilot.plane_command(
+                commanded[plane],
+                measured[plane],
+                &mut body.integrals[plane],
+                frozen,
+                rates[plane + 1],
+                loads.airspeed,
+                power,
+                dt,
+            );
+        }
+        let roll_power =
+            nonzero(self.control_power(body, 0) * loads.moment_scale / self.inertia[0]);
+        fin_command.x = self.autopilot.roll_command(rates.x, roll_power);
+
+        let fin_command = fin_command.map(|fin| fin.clamp(-limit, limit));
+        body.fins += (fin_command - body.fins) * (dt / self.actuator.time_constant).min(1.0);
+    }
+
+    /// Moment coefficient per radian of fin `axis` (roll, pitch, yaw), by central difference.
+    #[inline]
+    fn control_power(&self, body: &SixDofState, axis: usize) -> f64 {
+        const STEP: f64 = 1e-3;
+        let moment = |offset: f64| {
+            let mut fins = body.fins;
+            fins[axis] += offset;
+            let c = self.aerodynamics.coefficients(
+                body.mach,
+                [body.angle_of_attack, body.sideslip],
+                &fins,
+                &Vector3::zeros(),
+            );
+            [c.roll, c.moment[0], c.moment[1]][axis]
+        };
+        (moment(STEP) - moment(-STEP)) / (2.0 * STEP)
+    }
+}
+
+/// Keeps a control power away from zero (e.g. in vacuum), the autopilot divides by it.
+#[inline(always)]
+fn nonzero(power: f64) -> f64 {
+    if power.abs() < 1e-9 {
+        1e-9_f64.copysign(power)
+    } else {
+        power
+    }
+}
@@ -0,0 +1,45 @@
+use crate::core::LookupTable;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Mass and drag properties of a missile airframe.
+///
+/// Without one the missile is a pure kinematic point that keeps its speed. With one,
+/// drag `-½ ρ |v_air| v_air C_D S / m` slows it down, where `v_air` is the velocity
+/// relative to the surrounding air.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Airframe {
+    /// Mass (kg).
+    pub mass: f64,
+    /// Aerodynamic reference area `S` (m²), usually the body cross-section.
+    pub reference_area: f64,
+    /// Drag coefficient `C_D`.
+    pub drag_coefficient: f64,
+    /// `C_D` against Mach, replacing `drag_coefficient` when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drag_table: Option<LookupTable>,
+}
+
+impl Airframe {
+    /// Drag coefficient at `mach`.
+    #[inline]
+    pub fn drag_coefficient_at(&self, mach: f64) -> f64 {
+        match &self.drag_table {
+            Some(table) => table.lookup1(mach),
+            None => self.drag_coefficient,
+        }
+    }
+
+    /// Drag acceleration for a velocity `airspeed` relative to the air (m/s²).
+    #[inline]
+    pub fn drag(&self, airspeed: &Vector3<f64>, density: f64, speed_of_sound: f64) -> Vector3<f64> {
+        let speed = airspeed.norm();
+        let coefficient = self.drag_coefficient_at(speed / speed_of_sound);
+        -0.5 * density * speed * airspeed * coefficient * self.reference_area / self.mass
+    }
+
+    /// Ballistic coefficient `m / (C_D S)` (kg/m²), with the constant `drag_coefficient`.
+    pub fn ballistic_coefficient(&self) -> f64 {
+        self.mass / (self.drag_coefficient * self.reference_area)
+    }
+}
@@ -0,0 +1,122 @@
+use crate::core::{SimRng, State3D};
+use crate::entity::Target;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Target updates uplinked from the launch platform during midcourse.
+///
+/// Set on [`crate::simulation::Scenario::datalink`]. Until its seeker acquires the target the
+/// missile flies on what the launcher's radar sends: every `update_interval` the radar
+/// measures the target with a Gaussian `position_error` and `velocity_error` per axis, the
+/// packet is lost with probability `packet_loss` and otherwise arrives `latency` seconds
+/// later. Guidance sees the last packet extrapolated at constant velocity to the current
+/// time, so it flies on stale, noisy tracks between updates and longer ones after a loss.
+/// A missile launched on the datalink has no track before the first update arrives and
+/// holds a zero guidance command until then.
+///
+/// The seeker acquires the target inside `seeker_range`, raising
+/// [`crate::simulation::SimulationEvent::LockAcquired`], and guidance sees the true target
+/// from then on. Without a seeker range the missile flies on the datalink to the end. Noise
+/// and losses are drawn from the run's generator, so the same
+/// [`crate::simulation::Scenario::seed`] gives the same updates.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Datalink {
+    /// Time between radar measurements (s).
+    pub update_interval: f64,
+    /// Delay from measurement to arrival at the missile (s).
+    #[serde(default)]
+    pub latency: f64,
+    /// Probability that an update is lost, in `[0, 1]`.
+    #[serde(default)]
+    pub packet_loss: f64,
+    /// Radar position error, 1σ per axis (m).
+    #[serde(default)]
+    pub position_error: f64,
+    /// Radar velocity error, 1σ per axis (m/s).
+    #[serde(default)]
+    pub velocity_error: f64,
+    /// Range at which the missile's seeker acquires the target (m).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seeker_range: Option<f64>,
+}
+
+/// A radar measurement on its way to the missile.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Packet {
+    /// Time the radar measured the target (s).
+    measured: f64,
+    /// Measured target state.
+    state: State3D,
+}
+
+/// Updates in flight and the radar schedule of a [`Datalink`], kept by the engine.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DatalinkState {
+    /// Packets sent and not yet arrived, oldest first.
+    in_flight: VecDeque<Packet>,
+    /// Time of the next radar measurement (s).
+    next_measurement: f64,
+    /// Updates received so far.
+    pub received: usize,
+    /// Updates lost so far.
+    pub lost: usize,
+}
+
+impl Datalink {
+    /// Whether the seeker sees a target `range` meters away.
+    #[inline]
+    pub fn in_seeker_range(&self, range: f64) -> bool {
+        self.seeker_range.is_some_and(|seeker| range < seeker)
+    }
+
+    /// Runs the radar and the link up to `time`, returns the newest update that arrived,
+    /// extrapolated to `time`.
+    ///
+    /// `dt` is the engine timestep, packets due within half a step count as arrived.
+    pub fn update(
+        &self,
+        state: &mut DatalinkState,
+        target: &Target,
+        time: f64,
+        dt: f64,
+        rng: &mut SimRng,
+    ) -> Option<Target> {
+        let due = time + 0.5 * dt;
+        while state.next_measurement <= due {
+            // losses are drawn first so the noise stream doesn't depend on them
+            if rng.uniform() < self.packet_loss {
+                state.lost += 1;
+            } else {
+                let noise = |rng: &mut SimRng, sigma: f64| {
+                    Vector3::new(rng.normal(), rng.normal(), rng.normal()) * sigma
+                };
+                let position = target.state.position + noise(rng, self.position_error);
+                let velocity = target.state.velocity + noise(rng, self.velocity_error);
+                state.in_flight.push_back(Packet {
+                    measured: time,
+                    state: State3D { position, velocity },
+                });
+            }
+            state.next_measurement += self.update_interval.max(dt);
+        }
+
+        let mut newest = None;
+        while let Some(packet) = state.in_flight.front()
+            && packet.measured + self.latency <= due
+        {
+            newest = state.in_flight.pop_front();
+            state.received += 1;
+        }
+        newest.map(|packet| {
+            let age = time - packet.measured;
+            Target {
+                state: State3D {
+                    position: packet.state.position + packet.state.velocity * age,
+                    velocity: packet.state.velocity,
+                },
+                acceleration: Vector3::zeros(),
+            }
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use crate::core::STANDARD_GRAVITY;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// On/off divert thrusters of an exo-atmospheric [`crate::entity::KillVehicle`].
+///
+/// One thruster pair per lateral axis (see [`divert_axes`]). Each pair fires at one of a
+/// few discrete `levels`, holds a pulse for at least `min_pulse` and burns propellant at
+/// `F / (Isp g₀)`, so the kill vehicle gets lighter and more agile as it diverts, until
+/// the tanks run dry. Commands between levels are rounded to the nearest one, use
+/// [`crate::guidance::PulseWidthModulation`] to turn continuous guidance commands into
+/// pulses that average out right.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DivertThrusters {
+    /// Full thrust of one axis (N).
+    pub thrust: f64,
+    /// Thrust levels as fractions of `thrust`, on/off (`[1.0]`) by default.
+    #[serde(default = "default_levels")]
+    pub levels: Vec<f64>,
+    /// Shortest pulse a thruster can fire (s).
+    #[serde(default)]
+    pub min_pulse: f64,
+    /// Specific impulse (s).
+    pub specific_impulse: f64,
+    /// Divert propellant, part of the kill vehicle mass (kg).
+    pub propellant: f64,
+}
+
+fn default_levels() -> Vec<f64> {
+    vec![1.0]
+}
+
+/// Firing state of [`DivertThrusters`], kept with the kill vehicle.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DivertState {
+    /// Signed level each axis fires at.
+    levels: [f64; 2],
+    /// Time since each axis last changed level (s).
+    pulse_time: [f64; 2],
+    /// Propellant burned so far (kg).
+    pub propellant_used: f64,
+}
+
+/// Lateral thruster axes for a vehicle flying along `velocity`: horizontal-right and
+/// the "up" direction completing the frame, both perpendicular to `velocity`.
+#[inline]
+pub fn divert_axes(velocity: &Vector3<f64>) -> [Vector3<f64>; 2] {
+    let forward = velocity.try_normalize(1e-9).unwrap_or_else(Vector3::x);
+    let right = forward
+        .cross(&Vector3::z())
+        .try_normalize(1e-9)
+        .unwrap_or_else(|| forward.cross(&Vector3::x()).normalize());
+    [right, right.cross(&forward)]
+}
+
+impl DivertThrusters {
+    /// Full-level acceleration of one axis for a vehicle of `mass` (m/s²).
+    #[inline]
+    pub fn axis_acceleration(&self, mass: f64) -> f64 {
+        let top = self.levels.iter().fold(0.0_f64, |a, b| a.max(b.abs()));
+        self.thrust * top / mass
+    }
+
+    /// Delta-v the remaining propellant gives a vehicle of `mass` (m/s), rocket equation.
+    pub fn delta_v_remaining(&self, state: &DivertState, mass: f64) -> f64 {
+        let left = (self.propellant - state.propellant_used).max(0.0);
+        self.exhaust_velocity() * (mass / (mass - left).max(1e-9)).ln()
+    }
+
+    /// Fires the thrusters toward `command` for `dt`, returns the acceleration they give.
+    ///
+    /// `mass` is the vehicle mass at the start of the step, propellant burned included in
+    /// `state` afterwards.
+    pub fn fire(
+        &self,
+        state: &mut DivertState,
+        command: &Vector3<f64>,
+        velocity: &Vector3<f64>,
+        mass: f64,
+        dt: f64,
+    ) -> Vector3<f64> {
+        let empty = state.propellant_used >= self.propellant;
+        let full = self.thrust / mass;
+        let axes = divert_axes(velocity);
+
+        let mut acceleration = Vector3::zeros();
+        let mut firing = 0.0;
+        for (i, axis) in axes.iter().enumerate() {
+            let wanted = if empty {
+                0.0
+            } else {
+                self.quantize(command.dot(axis) / full)
+            };
+            // a pulse runs for at least its minimum width
+            let holding = state.levels[i] != 0.0 && state.pulse_time[i] < self.min_pulse;
+            let level = if holding && !empty {
+                state.levels[i]
+            } else {
+                wanted
+            };
+            if level != state.levels[i] {
+                state.levels[i] = level;
+                state.pulse_time[i] = 0.0;
+            }
+            state.pulse_time[i] += dt;
+
+            acceleration += axis * level * full;
+            firing += level.abs();
+        }
+
+        let burned = firing * self.thrust / self.exhaust_velocity() * dt;
+        state.propellant_used = (state.propellant_used + burned).min(self.propellant);
+        acceleration
+    }
+
+    /// Nearest thrust level (or off) to a signed fraction of full thrust.
+    #[inline]
+    fn quantize(&self, fraction: f64) -> f64 {
+        let magnitude = fraction.abs();
+        let level = self.levels.iter().fold(0.0_f64, |best, level| {
+            if (level - magnitude).abs() < (best - magnitude).abs() {
+                *level
+            } else {
+                best
+            }
+        });
+        level.copysign(fraction)
+    }
+
+    /// `Isp g₀` (m/s).
+    #[inline(always)]
+    fn exhaust_velocity(&self) -> f64 {
+        self.specific_impulse * STANDARD_GRAVITY
+    }
+}
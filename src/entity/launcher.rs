@@ -0,0 +1,50 @@
+use crate::core::State3D;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Launch platform and tracker of a command-guided missile.
+///
+/// Set on [`crate::simulation::Scenario::launcher`]. The tracker looks at the target, its
+/// line of sight is the beam [`crate::guidance::CommandToLineOfSight`],
+/// [`crate::guidance::BeamRider`] and [`crate::guidance::ThreePoint`] keep the missile on.
+/// Ships and vehicles move with constant acceleration like the [`crate::entity::Target`],
+/// a ground site is a launcher with zero velocity.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Launcher {
+    /// Current kinematic state (position and velocity).
+    pub state: State3D,
+    /// Constant acceleration applied each update step.
+    #[serde(default)]
+    pub acceleration: Vector3<f64>,
+}
+
+impl Launcher {
+    /// A launcher standing still at `position`.
+    pub fn fixed(position: Vector3<f64>) -> Self {
+        Self {
+            state: State3D {
+                position,
+                velocity: Vector3::zeros(),
+            },
+            acceleration: Vector3::zeros(),
+        }
+    }
+
+    /// Advances the launcher state by `dt` seconds using its constant acceleration.
+    #[inline(always)]
+    pub fn update(&mut self, dt: f64) {
+        self.state.update(self.acceleration, dt);
+    }
+
+    /// Offset of `point` from the beam toward `target`, perpendicular to the beam (m).
+    ///
+    /// Zero when the target sits on the tracker.
+    #[inline]
+    pub fn beam_deviation(&self, point: &Vector3<f64>, target: &Vector3<f64>) -> Vector3<f64> {
+        let offset = point - self.state.position;
+        match (target - self.state.position).try_normalize(1e-9) {
+            Some(beam) => offset - beam * beam.dot(&offset),
+            None => Vector3::zeros(),
+        }
+    }
+}
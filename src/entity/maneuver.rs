@@ -0,0 +1,31 @@
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// One entry of a target maneuver schedule.
+///
+/// Set on [`crate::simulation::Scenario::maneuvers`]. From `start` on the target flies with
+/// `acceleration` instead of its own [`crate::entity::Target::acceleration`], until the next
+/// maneuver of the schedule starts. Steps, weaves and jinks are a list of these.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Maneuver {
+    /// Time the maneuver starts (s).
+    pub start: f64,
+    /// Target acceleration from then on (m/s²).
+    pub acceleration: Vector3<f64>,
+}
+
+impl Maneuver {
+    /// A maneuver switching the target to `acceleration` at `start`.
+    pub fn new(start: f64, acceleration: Vector3<f64>) -> Self {
+        Self {
+            start,
+            acceleration,
+        }
+    }
+
+    /// Whether the maneuver starts in the step beginning at `time`, `dt` long.
+    #[inline]
+    pub fn starts_within(&self, time: f64, dt: f64) -> bool {
+        self.start >= time - 0.5 * dt && self.start < time + 0.5 * dt
+    }
+}
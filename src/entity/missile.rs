@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 /// Contains both configuration parameters and runtime state.
 /// The missile's state is updated each step by the simulation engine
 /// using acceleration commands from a guidance law.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Missile {
     /// Current kinematic state (position and velocity).
     pub state: State3D,
@@ -24,9 +24,17 @@ pub struct Missile {
 impl Missile {
     /// Updates the missile state by applying the given acceleration for `dt` seconds.
     ///
-    /// The acceleration is clamped to `max_acceleration` if it exceeds the limit and projected perpendicular to the current velocity
+    /// The acceleration is clamped to `max_acceleration` if it exceeds the limit and projected perpendicular to the current velocity.
+    /// Returns the acceleration that was actually applied.
     #[inline(always)]
-    pub fn update(&mut self, acceleration: Vector3<f64>, dt: f64) {
+    pub fn update(&mut self, acceleration: Vector3<f64>, dt: f64) -> Vector3<f64> {
+        self.update_with_external(acceleration, Vector3::zeros(), dt)
+    }
+
+    /// Clamps `acceleration` to `max_acceleration` and projects it perpendicular to the
+    /// current velocity, as [`Missile::update`] applies it.
+    #[inline(always)]
+    pub fn limit(&self, acceleration: Vector3<f64>) -> Vector3<f64> {
         let clamped_accel = if acceleration.norm() > self.max_acceleration {
             acceleration.normalize() * self.max_acceleration
         } else {
@@ -35,13 +43,26 @@ impl Missile {
 
         // project acceleration perpendicular to velocity
         let speed = self.state.speed();
-        let perp_accel = if speed > 1e-6 {
+        if speed > 1e-6 {
             let v_hat = self.state.velocity / speed;
             clamped_accel - v_hat * v_hat.dot(&clamped_accel)
         } else {
             clamped_accel
-        };
+        }
+    }
 
-        self.state.update(perp_accel, dt);
+    /// Same as [`Missile::update`], plus an `external` acceleration (e.g. Coriolis and
+    /// centrifugal terms) that is applied as is, without limiting or projection.
+    /// Returns the commanded acceleration that was actually applied, without `external`.
+    #[inline(always)]
+    pub fn update_with_external(
+        &mut self,
+        acceleration: Vector3<f64>,
+        external: Vector3<f64>,
+        dt: f64,
+    ) -> Vector3<f64> {
+        let perp_accel = self.limit(acceleration);
+        self.state.update(perp_accel + external, dt);
+        perp_accel
     }
 }
@@ -1,5 +1,19 @@
+mod airframe;
+mod datalink;
+mod divert;
+mod launcher;
+mod maneuver;
 mod missile;
+mod seeker;
+mod staging;
 mod target;
 
+pub use airframe::Airframe;
+pub use datalink::{Datalink, DatalinkState};
+pub use divert::{DivertState, DivertThrusters, divert_axes};
+pub use launcher::Launcher;
+pub use maneuver::Maneuver;
 pub use missile::Missile;
+pub use seeker::Seeker;
+pub use staging::{KillVehicle, Stage, Staging, StagingTrigger};
 pub use target::Target;
@@ -0,0 +1,39 @@
+use crate::core::State3D;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Detection range and gimbal limit of the missile's seeker.
+///
+/// Set on [`crate::simulation::Scenario::seeker`]. The seeker sees the target while it is
+/// closer than `range` and within `field_of_view` of the missile's velocity. Out of sight
+/// the missile coasts on the last seen track like behind terrain, raising
+/// [`crate::simulation::SimulationEvent::LockLost`] and
+/// [`crate::simulation::SimulationEvent::LockAcquired`]. On a datalink, handover to the
+/// seeker also waits for [`crate::entity::Datalink::seeker_range`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Seeker {
+    /// Range at which the seeker detects the target (m), unlimited when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<f64>,
+    /// Gimbal limit, the largest angle between the missile's velocity and the line of sight
+    /// (rad), unlimited when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_of_view: Option<f64>,
+}
+
+impl Seeker {
+    /// Whether the seeker of a missile in `missile` sees a target at `target`.
+    #[inline]
+    pub fn sees(&self, missile: &State3D, target: &Vector3<f64>) -> bool {
+        let los = target - missile.position;
+        if self.range.is_some_and(|range| los.norm() >= range) {
+            return false;
+        }
+        match (self.field_of_view, missile.velocity.try_normalize(1e-9)) {
+            (Some(limit), Some(heading)) => los
+                .try_normalize(1e-9)
+                .is_none_or(|los| heading.dot(&los).clamp(-1.0, 1.0).acos() <= limit),
+            _ => true,
+        }
+    }
+}
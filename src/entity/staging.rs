@@ -0,0 +1,313 @@
+use crate::core::LookupTable;
+use crate::entity::{DivertState, DivertThrusters};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// When a [`Stage`] separates.
+///
+/// Tagged by `kind` in scenario files.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StagingTrigger {
+    /// As soon as the motor burns out.
+    #[default]
+    Burnout,
+    /// A fixed time after the stage ignited.
+    Time {
+        /// Seconds after ignition.
+        after: f64,
+    },
+    /// Once the missile climbs through an altitude.
+    Altitude {
+        /// Altitude (m).
+        altitude: f64,
+    },
+}
+
+/// One booster stage of a [`Staging`] stack.
+///
+/// Thrust acts along the velocity, so the stage flies a gravity turn unless the guidance
+/// law bends it. Drag uses the stage's own reference area and `C_D` and the mass of
+/// everything still attached.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stage {
+    /// Name used in reports.
+    #[serde(default)]
+    pub name: String,
+    /// Mass of the stage at ignition, propellant included (kg).
+    pub mass: f64,
+    /// Propellant burned over `burn_time` (kg).
+    #[serde(default)]
+    pub propellant: f64,
+    /// Motor thrust (N).
+    #[serde(default)]
+    pub thrust: f64,
+    /// Motor burn time (s).
+    #[serde(default)]
+    pub burn_time: f64,
+    /// Lateral acceleration limit while the stage flies (m/s²).
+    pub max_acceleration: f64,
+    /// Aerodynamic reference area `S` (m²).
+    #[serde(default)]
+    pub reference_area: f64,
+    /// Drag coefficient `C_D`.
+    #[serde(default)]
+    pub drag_coefficient: f64,
+    /// `C_D` against Mach, replacing `drag_coefficient` when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drag_table: Option<LookupTable>,
+    /// When the stage separates.
+    #[serde(default)]
+    pub trigger: StagingTrigger,
+}
+
+/// Exo-atmospheric kill vehicle released after the last stage.
+///
+/// Without aerodynamic lift it maneuvers on divert thrusters, so every bit of lateral
+/// acceleration spends a finite delta-v budget. By default the divert system is ideal,
+/// any acceleration up to `max_acceleration` until `delta_v` is gone. With
+/// [`DivertThrusters`] it fires discrete pulses and the budget is the propellant on board.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KillVehicle {
+    /// Mass, divert propellant included (kg).
+    pub mass: f64,
+    /// Divert acceleration of the ideal divert system (m/s²).
+    #[serde(default)]
+    pub max_acceleration: f64,
+    /// Delta-v budget of the ideal divert system (m/s).
+    #[serde(default)]
+    pub delta_v: f64,
+    /// Thrusters replacing the ideal divert system.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thrusters: Option<DivertThrusters>,
+}
+
+/// A multi-stage missile: booster stages fired in order, then an optional kill vehicle.
+///
+/// Set on [`crate::simulation::Scenario::stages`]. Stages replace the point-mass
+/// [`crate::entity::Airframe`] and the missile's `max_acceleration`: the engine adds
+/// thrust and drag of the active stage and drops it on its [`StagingTrigger`], raising
+/// [`crate::simulation::SimulationEvent::Burnout`] and
+/// [`crate::simulation::SimulationEvent::StageSeparation`]. The engine keeps the missile's
+/// `max_acceleration` at the limit of the active stage, so guidance laws see it. Without a
+/// kill vehicle the last stage stays attached to the end of the flight.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Staging {
+    /// Booster stages, first to fire first.
+    pub stages: Vec<Stage>,
+    /// Divert phase after the last stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kill_vehicle: Option<KillVehicle>,
+    /// Index of the active stage, `stages.len()` once the kill vehicle flies alone.
+    #[serde(default)]
+    active: usize,
+    /// Time since the active stage ignited (s).
+    #[serde(default)]
+    stage_time: f64,
+    /// Divert delta-v spent by the kill vehicle (m/s).
+    #[serde(default)]
+    delta_v_used: f64,
+    /// Divert thruster pulses and propellant.
+    #[serde(default)]
+    divert: DivertState,
+}
+
+impl Staging {
+    /// A stack of `stages` on the pad.
+    pub fn new(stages: Vec<Stage>) -> Self {
+        Self {
+            stages,
+            kill_vehicle: None,
+            active: 0,
+            stage_time: 0.0,
+            delta_v_used: 0.0,
+            divert: DivertState::default(),
+        }
+    }
+
+    /// Adds a kill vehicle released after the last stage.
+    pub fn with_kill_vehicle(mut self, kill_vehicle: KillVehicle) -> Self {
+        self.kill_vehicle = Some(kill_vehicle);
+        self
+    }
+
+    /// The stage currently attached and flying, `None` in the kill-vehicle phase.
+    pub fn active_stage(&self) -> Option<&Stage> {
+        self.stages.get(self.active)
+    }
+
+    /// Index of the active stage, `stages.len()` in the kill-vehicle phase.
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    /// Whether the kill vehicle has separated from the last stage.
+    pub fn kill_vehicle_released(&self) -> bool {
+        self.active >= self.stages.len()
+    }
+
+    /// Divert delta-v spent so far (m/s).
+    pub fn delta_v_used(&self) -> f64 {
+        self.delta_v_used
+    }
+
+    /// Divert delta-v left, zero before release or without a kill vehicle (m/s).
+    pub fn delta_v_remaining(&self) -> f64 {
+        match &self.kill_vehicle {
+            Some(kv) if self.kill_vehicle_released() => match &kv.thrusters {
+                Some(thrusters) => thrusters.delta_v_remaining(&self.divert, self.mass()),
+                None => (kv.delta_v - self.delta_v_used).max(0.0),
+            },
+            _ => 0.0,
+        }
+    }
+
+    /// Divert propellant burned so far (kg).
+    pub fn propellant_used(&self) -> f64 {
+        self.divert.propellant_used
+    }
+
+    /// Mass of everything still attached (kg).
+    pub fn mass(&self) -> f64 {
+        let kill_vehicle = self.kill_vehicle.as_ref().map_or(0.0, |kv| kv.mass);
+        let stages: f64 = self.stages.iter().skip(self.active).map(|s| s.mass).sum();
+        let burned = self.active_stage().map_or(0.0, |stage| {
+            if stage.burn_time > 0.0 {
+                stage.propellant * (self.stage_time / stage.burn_time).min(1.0)
+            } else {
+                stage.propellant
+            }
+        });
+        kill_vehicle + stages - burned - self.divert.propellant_used
+    }
+
+    /// Lateral acceleration limit of the active stage or kill vehicle (m/s²).
+    ///
+    /// Divert thrusters firing on both axes reach `√2` times their per-axis acceleration.
+    pub fn max_acceleration(&self) -> f64 {
+        match (self.active_stage(), &self.kill_vehicle) {
+            (Some(stage), _) => stage.max_acceleration,
+            (None, Some(kv)) if self.delta_v_remaining() > 0.0 => match &kv.thrusters {
+                Some(thrusters) => {
+                    std::f64::consts::SQRT_2 * thrusters.axis_acceleration(self.mass())
+                }
+                None => kv.max_acceleration,
+            },
+            _ => 0.0,
+        }
+    }
+
+    /// Full-level acceleration of one divert thruster axis once the kill vehicle flies on
+    /// [`DivertThrusters`] (m/s²).
+    pub fn divert_acceleration(&self) -> Option<f64> {
+        let thrusters = self.kill_vehicle.as_ref()?.thrusters.as_ref()?;
+        self.kill_vehicle_released()
+            .then(|| thrusters.axis_acceleration(self.mass()))
+    }
+
+    /// Thrust and drag of the active stage (m/s²).
+    ///
+    /// * `velocity` — missile velocity, thrust acts along it.
+    /// * `air` — velocity relative to the air, `density` and `speed_of_sound` around it.
+    #[inline]
+    pub fn propulsion(
+        &self,
+        velocity: &Vector3<f64>,
+        air: &Vector3<f64>,
+        density: f64,
+        speed_of_sound: f64,
+    ) -> Vector3<f64> {
+        let Some(stage) = self.active_stage() else {
+            return Vector3::zeros();
+        };
+        let mass = self.mass();
+
+        let thrust = if self.stage_time < stage.burn_time {
+            velocity.try_normalize(1e-9).unwrap_or_else(Vector3::zeros) * stage.thrust / mass
+        } else {
+            Vector3::zeros()
+        };
+
+        let speed = air.norm();
+        let coefficient = match &stage.drag_table {
+            Some(table) => table.lookup1(speed / speed_of_sound),
+            None => stage.drag_coefficient,
+        };
+        let drag = -0.5 * density * speed * air * coefficient * stage.reference_area / mass;
+        thrust + drag
+    }
+
+    /// Limits a lateral acceleration applied for `dt` to the divert delta-v left and books
+    /// it, during the kill-vehicle phase. Booster stages pass it through.
+    ///
+    /// Divert thrusters fire toward it instead, on axes set by the `velocity`.
+    #[inline]
+    pub fn spend(
+        &mut self,
+        acceleration: Vector3<f64>,
+        velocity: &Vector3<f64>,
+        dt: f64,
+    ) -> Vector3<f64> {
+        if !self.kill_vehicle_released() {
+            return acceleration;
+        }
+        let mass = self.mass();
+        if let Some(thrusters) = self
+            .kill_vehicle
+            .as_ref()
+            .and_then(|kv| kv.thrusters.as_ref())
+        {
+            let applied = thrusters.fire(&mut self.divert, &acceleration, velocity, mass, dt);
+            self.delta_v_used += applied.norm() * dt;
+            return applied;
+        }
+        let remaining = self.delta_v_remaining();
+        let delta_v = acceleration.norm() * dt;
+        if delta_v <= remaining {
+            self.delta_v_used += delta_v;
+            acceleration
+        } else {
+            self.delta_v_used += remaining;
+            acceleration * (remaining / delta_v)
+        }
+    }
+
+    /// Whether the active stage's motor burned out during the last `dt` seconds.
+    #[inline]
+    pub fn burned_out(&self, dt: f64) -> bool {
+        self.active_stage().is_some_and(|stage| {
+            stage.burn_time > 0.0
+                && self.stage_time >= stage.burn_time
+                && self.stage_time - dt < stage.burn_time
+        })
+    }
+
+    /// Drops the active stage if its trigger fired at `altitude`, returns its index.
+    ///
+    /// The last stage only separates when there is a kill vehicle to release.
+    pub fn separate(&mut self, altitude: f64) -> Option<usize> {
+        let stage = self.active_stage()?;
+        if self.active + 1 == self.stages.len() && self.kill_vehicle.is_none() {
+            return None;
+        }
+        let fired = match stage.trigger {
+            StagingTrigger::Burnout => self.stage_time >= stage.burn_time,
+            StagingTrigger::Time { after } => self.stage_time >= after,
+            StagingTrigger::Altitude { altitude: trigger } => altitude >= trigger,
+        };
+        if !fired {
+            return None;
+        }
+
+        let dropped = self.active;
+        self.active += 1;
+        self.stage_time = 0.0;
+        Some(dropped)
+    }
+
+    /// Advances the active stage's clock by `dt`.
+    #[inline(always)]
+    pub fn advance(&mut self, dt: f64) {
+        self.stage_time += dt;
+    }
+}
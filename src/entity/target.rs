@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// Contains both configuration parameters and runtime state.
 /// The target moves with constant acceleration defined in its config.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Target {
     /// Current kinematic state (position and velocity).
     pub state: State3D,
@@ -20,4 +20,11 @@ impl Target {
     pub fn update(&mut self, dt: f64) {
         self.state.update(self.acceleration, dt);
     }
+
+    /// Same as [`Target::update`], plus an `external` acceleration (e.g. Coriolis and
+    /// centrifugal terms).
+    #[inline(always)]
+    pub fn update_with_external(&mut self, external: Vector3<f64>, dt: f64) {
+        self.state.update(self.acceleration + external, dt);
+    }
 }
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+/// Specific gas constant of dry air (J/(kg·K)).
+const GAS_CONSTANT: f64 = 287.052_87;
+/// Ratio of specific heats of air.
+const GAMMA: f64 = 1.4;
+/// Gravity used by the ISA barometric formula (m/s²).
+const ISA_GRAVITY: f64 = 9.806_65;
+
+/// ISA temperature above the last layer (K).
+const MESOPAUSE_TEMPERATURE: f64 = 186.87;
+
+/// ISA layers up to the mesopause: base altitude (m), base temperature (K), base pressure
+/// (Pa) and lapse rate (K/m).
+const ISA_LAYERS: [(f64, f64, f64, f64); 7] = [
+    (0.0, 288.15, 101_325.0, -0.0065),
+    (11_000.0, 216.65, 22_632.06, 0.0),
+    (20_000.0, 216.65, 5_474.889, 0.001),
+    (32_000.0, 228.65, 868.018_7, 0.0028),
+    (47_000.0, 270.65, 110.906_3, 0.0),
+    (51_000.0, 270.65, 66.938_87, -0.0028),
+    (71_000.0, 214.65, 3.956_420, -0.002),
+];
+
+/// Air density and temperature model, altitude along `+z`.
+///
+/// Tagged by `model` in scenario files, e.g. `model = "isa"`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "model", rename_all = "snake_case")]
+pub enum Atmosphere {
+    /// 1976 US Standard / ICAO standard atmosphere, isothermal above 86 km.
+    #[default]
+    Isa,
+    /// `ρ = ρ0 exp(-h / H)`, at ISA sea-level temperature.
+    Exponential {
+        /// Density at `z = 0` (kg/m³).
+        sea_level_density: f64,
+        /// Scale height `H` (m).
+        scale_height: f64,
+    },
+    /// No air at all, drag vanishes.
+    Vacuum,
+}
+
+impl Atmosphere {
+    /// Air density at `altitude` (kg/m³).
+    #[inline]
+    pub fn density(&self, altitude: f64) -> f64 {
+        match self {
+            Self::Isa => {
+                let (temperature, pressure) = isa(altitude);
+                pressure / (GAS_CONSTANT * temperature)
+            }
+            Self::Exponential {
+                sea_level_density,
+                scale_height,
+            } => sea_level_density * (-altitude / scale_height).exp(),
+            Self::Vacuum => 0.0,
+        }
+    }
+
+    /// Static air temperature at `altitude` (K). Non-ISA models use the ISA sea-level value.
+    pub fn temperature(&self, altitude: f64) -> f64 {
+        match self {
+            Self::Isa => isa(altitude).0,
+            _ => ISA_LAYERS[0].1,
+        }
+    }
+
+    /// Speed of sound at `altitude` (m/s), for Mach numbers.
+    pub fn speed_of_sound(&self, altitude: f64) -> f64 {
+        (GAMMA * GAS_CONSTANT * self.temperature(altitude)).sqrt()
+    }
+}
+
+/// ISA temperature (K) and pressure (Pa), the lowest layer extended below sea level.
+#[inline]
+fn isa(altitude: f64) -> (f64, f64) {
+    let (base, base_temperature, base_pressure, lapse) = *ISA_LAYERS
+        .iter()
+        .rev()
+        .find(|layer| altitude >= layer.0)
+        .unwrap_or(&ISA_LAYERS[0]);
+    let height = altitude - base;
+
+    if lapse == 0.0 {
+        let pressure =
+            base_pressure * (-ISA_GRAVITY * height / (GAS_CONSTANT * base_temperature)).exp();
+        return (base_temperature, pressure);
+    }
+
+    let pressure_at = |temperature: f64| {
+        base_pressure * (temperature / base_temperature).powf(-ISA_GRAVITY / (GAS_CONSTANT * lapse))
+    };
+    let temperature = base_temperature + lapse * height;
+    if temperature >= MESOPAUSE_TEMPERATURE {
+        return (temperature, pressure_at(temperature));
+    }
+
+    // isothermal above the mesopause (~84.9 km)
+    let top = base + (MESOPAUSE_TEMPERATURE - base_temperature) / lapse;
+    let pressure = pressure_at(MESOPAUSE_TEMPERATURE)
+        * (-ISA_GRAVITY * (altitude - top) / (GAS_CONSTANT * MESOPAUSE_TEMPERATURE)).exp();
+    (MESOPAUSE_TEMPERATURE, pressure)
+}
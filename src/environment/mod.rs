@@ -0,0 +1,17 @@
+//! The world around the engagement.
+//!
+//! - [`Terrain`] — flat ground or a [`HeightMap`]. Runs end on ground impact and terrain
+//!   between missile and target breaks seeker lock.
+//! - [`Environment`] — gravity, [`Atmosphere`], mean [`Wind`] and gust [`Turbulence`].
+
+mod atmosphere;
+mod settings;
+mod terrain;
+mod turbulence;
+mod wind;
+
+pub use atmosphere::Atmosphere;
+pub use settings::Environment;
+pub use terrain::{HeightMap, Terrain};
+pub use turbulence::{GustState, Turbulence, TurbulenceModel};
+pub use wind::{Wind, WindLayer};
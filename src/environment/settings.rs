@@ -0,0 +1,45 @@
+use crate::environment::{Atmosphere, Turbulence, Wind};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Gravity, air and wind of a run, attached to [`crate::simulation::Scenario::environment`].
+///
+/// The default is the classic kinematic engagement: no gravity, calm ISA air. Air only
+/// matters for missiles with an [`crate::entity::Airframe`], whose drag uses the airspeed
+/// relative to wind and gusts. Gusts also push the target around its scripted path.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Environment {
+    /// Gravitational acceleration on the missile along `-z` (m/s²), `0` leaves it out.
+    /// Give ballistic targets their gravity through [`crate::entity::Target::acceleration`].
+    #[serde(default)]
+    pub gravity: f64,
+    /// Air density and temperature.
+    #[serde(default)]
+    pub atmosphere: Atmosphere,
+    /// Mean wind.
+    #[serde(default)]
+    pub wind: Wind,
+    /// Gust turbulence on top of the mean wind.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turbulence: Option<Turbulence>,
+}
+
+impl Environment {
+    /// Gravity vector (m/s²).
+    #[inline]
+    pub fn gravity_vector(&self) -> Vector3<f64> {
+        Vector3::new(0.0, 0.0, -self.gravity)
+    }
+
+    /// Air density at `position` (kg/m³).
+    #[inline]
+    pub fn density(&self, position: &Vector3<f64>) -> f64 {
+        self.atmosphere.density(position.z)
+    }
+
+    /// Mean wind at `position` (m/s).
+    #[inline]
+    pub fn wind_at(&self, position: &Vector3<f64>) -> Vector3<f64> {
+        self.wind.at(position)
+    }
+}
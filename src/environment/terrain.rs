@@ -0,0 +1,197 @@
+use anyhow::{Context, bail};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Ground under the engagement, heights along `+z`.
+///
+/// In scenario files it is tagged by `kind`:
+///
+/// ```toml
+/// [terrain]
+/// kind = "flat"
+/// elevation = 0.0
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Terrain {
+    /// A horizontal plane, e.g. the sea surface.
+    Flat {
+        /// Height of the plane (m).
+        #[serde(default)]
+        elevation: f64,
+    },
+    /// A gridded elevation model, see [`HeightMap`].
+    HeightMap(HeightMap),
+}
+
+impl Terrain {
+    /// A flat ground plane at `elevation`.
+    pub fn flat(elevation: f64) -> Self {
+        Self::Flat { elevation }
+    }
+
+    /// Ground height at horizontal position (`x`, `y`).
+    #[inline]
+    pub fn elevation(&self, x: f64, y: f64) -> f64 {
+        match self {
+            Self::Flat { elevation } => *elevation,
+            Self::HeightMap(map) => map.elevation(x, y),
+        }
+    }
+
+    /// Height of `position` above the ground, negative below it.
+    #[inline]
+    pub fn height_above(&self, position: &Vector3<f64>) -> f64 {
+        position.z - self.elevation(position.x, position.y)
+    }
+
+    /// Whether the straight line from `from` to `to` stays clear of the ground.
+    ///
+    /// Height maps are sampled every half grid spacing along the line.
+    pub fn line_of_sight(&self, from: &Vector3<f64>, to: &Vector3<f64>) -> bool {
+        if self.height_above(from) < 0.0 || self.height_above(to) < 0.0 {
+            return false;
+        }
+        match self {
+            // a segment between two points above a plane never dips below it
+            Self::Flat { .. } => true,
+            Self::HeightMap(map) => {
+                let delta = to - from;
+                let samples = (delta.norm() / (0.5 * map.spacing)).ceil().max(1.0) as usize;
+                (1..samples).all(|k| {
+                    self.height_above(&(from + delta * (k as f64 / samples as f64))) >= 0.0
+                })
+            }
+        }
+    }
+}
+
+/// Regular grid of ground heights with bilinear interpolation.
+///
+/// `heights` is row-major: row `j`, column `i` is the height at
+/// `(origin[0] + i * spacing, origin[1] + j * spacing)`. Positions outside the grid
+/// take the height of the nearest edge. Deserialized maps are checked like
+/// [`HeightMap::new`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawHeightMap")]
+pub struct HeightMap {
+    /// `x`, `y` of the first grid point (m).
+    pub origin: [f64; 2],
+    /// Distance between grid points along `x` and `y` (m).
+    pub spacing: f64,
+    /// Number of grid points along `x`.
+    pub columns: usize,
+    /// Heights (m), `columns` per row.
+    pub heights: Vec<f64>,
+}
+
+/// Serialized form of a [`HeightMap`], checked by [`HeightMap::new`] on the way in.
+#[derive(Deserialize)]
+struct RawHeightMap {
+    origin: [f64; 2],
+    spacing: f64,
+    columns: usize,
+    heights: Vec<f64>,
+}
+
+impl TryFrom<RawHeightMap> for HeightMap {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: RawHeightMap) -> anyhow::Result<Self> {
+        if raw.columns == 0 || !raw.heights.len().is_multiple_of(raw.columns) {
+            bail!(
+                "height map has {} heights, not a multiple of {} columns",
+                raw.heights.len(),
+                raw.columns
+            );
+        }
+        let rows = raw
+            .heights
+            .chunks(raw.columns)
+            .map(<[f64]>::to_vec)
+            .collect();
+        Self::new(raw.origin, raw.spacing, rows)
+    }
+}
+
+impl HeightMap {
+    /// Creates a height map from rows of heights along `x`, one row per `y` step.
+    /// Returns an error if the grid is empty, ragged, or `spacing` is not positive.
+    pub fn new(origin: [f64; 2], spacing: f64, rows: Vec<Vec<f64>>) -> anyhow::Result<Self> {
+        if spacing <= 0.0 {
+            bail!("height map spacing must be positive, got {spacing}");
+        }
+        let columns = rows.first().map_or(0, Vec::len);
+        if columns == 0 {
+            bail!("height map is empty");
+        }
+        if let Some(j) = rows.iter().position(|row| row.len() != columns) {
+            bail!(
+                "height map row {j} has {} values, expected {columns}",
+                rows[j].len()
+            );
+        }
+
+        Ok(Self {
+            origin,
+            spacing,
+            columns,
+            heights: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a comma separated grid, one row of heights per line.
+    pub fn from_csv(text: &str, origin: [f64; 2], spacing: f64) -> anyhow::Result<Self> {
+        let rows = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(n, line)| {
+                line.split(',')
+                    .map(|value| value.trim().parse::<f64>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .with_context(|| format!("invalid height on line {}", n + 1))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Self::new(origin, spacing, rows)
+    }
+
+    /// Loads a grid written as CSV, see [`HeightMap::from_csv`].
+    pub fn load_csv(
+        path: impl AsRef<Path>,
+        origin: [f64; 2],
+        spacing: f64,
+    ) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Self::from_csv(&text, origin, spacing)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Number of grid points along `y`.
+    pub fn rows(&self) -> usize {
+        self.heights.len() / self.columns
+    }
+
+    /// Bilinearly interpolated height at (`x`, `y`).
+    pub fn elevation(&self, x: f64, y: f64) -> f64 {
+        let (i0, i1, tx) = cell((x - self.origin[0]) / self.spacing, self.columns);
+        let (j0, j1, ty) = cell((y - self.origin[1]) / self.spacing, self.rows());
+        let at = |i: usize, j: usize| self.heights[j * self.columns + i];
+
+        let low = at(i0, j0) + (at(i1, j0) - at(i0, j0)) * tx;
+        let high = at(i0, j1) + (at(i1, j1) - at(i0, j1)) * tx;
+        low + (high - low) * ty
+    }
+}
+
+/// Grid cell and fraction along one axis, clamped to the grid.
+#[inline(always)]
+fn cell(coordinate: f64, count: usize) -> (usize, usize, f64) {
+    let clamped = coordinate.clamp(0.0, (count - 1) as f64);
+    let low = (clamped.floor() as usize).min(count.saturating_sub(2));
+    let high = (low + 1).min(count - 1);
+    (low, high, clamped - low as f64)
+}
@@ -0,0 +1,146 @@
+use crate::core::SimRng;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+/// Gust spectrum of a [`Turbulence`] model.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TurbulenceModel {
+    /// Rational Dryden spectrum.
+    #[default]
+    Dryden,
+    /// Von Kármán spectrum, through the usual rational approximation.
+    VonKarman,
+}
+
+/// Continuous gust turbulence, shaped white noise from the run's [`SimRng`].
+///
+/// Each body sees the gust field at its own airspeed `V`. Gusts are shaped along the
+/// body's air velocity: longitudinal along it, lateral across it in the horizontal plane
+/// and vertical completing the triad, then rotated into world axes. With `p = L s / V`,
+/// the longitudinal filter is `σ √(2L/πV)` times
+/// - Dryden: `1 / (1 + p)`
+/// - von Kármán: `(1 + 0.25 p) / (1 + 1.357 p + 0.1987 p²)`
+///
+/// and the lateral and vertical filters `σ √(L/πV)` times
+/// - Dryden: `(1 + √3 p) / (1 + p)²`
+/// - von Kármán: `(1 + 2.7478 p + 0.3398 p²) / (1 + 2.9958 p + 1.9754 p² + 0.1539 p³)`
+///
+/// so every axis has standard deviation `σ`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Turbulence {
+    /// Spectrum, Dryden by default.
+    #[serde(default)]
+    pub model: TurbulenceModel,
+    /// Gust intensity `σ` per axis (m/s).
+    pub intensity: f64,
+    /// Scale length `L` (m), 533 m (1750 ft) by default as for medium/high altitude.
+    #[serde(default = "default_length_scale")]
+    pub length_scale: f64,
+}
+
+fn default_length_scale() -> f64 {
+    533.4
+}
+
+/// Filter states of the gusts seen by one body, kept by the engine between steps.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GustState {
+    /// Shaping filter states, longitudinal, lateral and vertical.
+    filters: [[f64; 3]; 3],
+    /// Last gust velocity, world axes (m/s).
+    pub velocity: Vector3<f64>,
+}
+
+impl Turbulence {
+    /// Creates a Dryden turbulence of intensity `σ` with the default scale length.
+    pub fn dryden(intensity: f64) -> Self {
+        Self {
+            model: TurbulenceModel::Dryden,
+            intensity,
+            length_scale: default_length_scale(),
+        }
+    }
+
+    /// Creates a von Kármán turbulence of intensity `σ` with the default scale length.
+    pub fn von_karman(intensity: f64) -> Self {
+        Self {
+            model: TurbulenceModel::VonKarman,
+            ..Self::dryden(intensity)
+        }
+    }
+
+    /// Advances the gusts of one body flying at `air_velocity` (relative to the mean wind)
+    /// by `dt`, returns the gust velocity in world axes.
+    pub fn step(
+        &self,
+        gust: &mut GustState,
+        air_velocity: &Vector3<f64>,
+        dt: f64,
+        rng: &mut SimRng,
+    ) -> Vector3<f64> {
+        let airspeed = air_velocity.norm().max(1.0);
+        let tau = self.length_scale / airspeed;
+        let (longitudinal, lateral): (Shape, Shape) = match self.model {
+            TurbulenceModel::Dryden => ((&[1.0], &[1.0, 1.0]), (&[1.0, SQRT_3], &[1.0, 2.0, 1.0])),
+            TurbulenceModel::VonKarman => (
+                (&[1.0, 0.25], &[1.0, 1.357, 0.1987]),
+                (&[1.0, 2.7478, 0.3398], &[1.0, 2.9958, 1.9754, 0.1539]),
+            ),
+        };
+        let gain = self.intensity * (self.length_scale / (PI * airspeed)).sqrt();
+        // white noise of spectral density π, which gives the filters unit variance
+        let noise = (PI / dt).sqrt();
+
+        gust.velocity = Vector3::zeros();
+        for (axis, direction) in gust_axes(air_velocity).iter().enumerate() {
+            let (shape, scale) = if axis == 0 {
+                (longitudinal, gain * 2f64.sqrt())
+            } else {
+                (lateral, gain)
+            };
+            let input = noise * rng.normal();
+            gust.velocity +=
+                direction * (scale * filter(&mut gust.filters[axis], shape, input, dt / tau));
+        }
+        gust.velocity
+    }
+}
+
+/// Longitudinal, lateral and vertical gust axes of a body flying at `air_velocity`: along
+/// it, to its left in the horizontal plane, and completing the right-handed triad.
+#[inline]
+fn gust_axes(air_velocity: &Vector3<f64>) -> [Vector3<f64>; 3] {
+    let along = air_velocity.try_normalize(1e-6).unwrap_or_else(Vector3::x);
+    let left = Vector3::z()
+        .cross(&along)
+        .try_normalize(1e-6)
+        .unwrap_or_else(Vector3::y);
+    [along, left, along.cross(&left)]
+}
+
+/// Numerator and denominator coefficients of a filter in `p`, lowest power first.
+type Shape = (&'static [f64], &'static [f64]);
+
+/// Steps a filter in controllable canonical form by `h = dt / τ`, returns its output.
+#[inline(always)]
+fn filter(state: &mut [f64; 3], (numerator, denominator): Shape, input: f64, h: f64) -> f64 {
+    let order = denominator.len() - 1;
+    let lead = denominator[order];
+    // explicit Euler, substepped to stay well inside its stability region
+    let substeps = (h / 0.1).ceil().max(1.0);
+    let h = h / substeps;
+
+    for _ in 0..substeps as usize {
+        let top = (input - (0..order).map(|i| denominator[i] * state[i]).sum::<f64>()) / lead;
+        for i in 0..order - 1 {
+            state[i] += h * state[i + 1];
+        }
+        state[order - 1] += h * top;
+    }
+
+    numerator.iter().zip(state.iter()).map(|(b, x)| b * x).sum()
+}
@@ -0,0 +1,82 @@
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// Mean wind field, the air velocity (m/s) as a function of altitude along `+z`.
+///
+/// Tagged by `model` in scenario files:
+///
+/// ```toml
+/// [environment.wind]
+/// model = "power_law"
+/// reference_velocity = [12.0, 0.0, 0.0]
+/// reference_altitude = 10.0
+/// exponent = 0.14
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "model", rename_all = "snake_case")]
+pub enum Wind {
+    /// Still air.
+    #[default]
+    Calm,
+    /// The same wind everywhere.
+    Constant {
+        /// Air velocity (m/s).
+        velocity: Vector3<f64>,
+    },
+    /// Boundary-layer profile `v(h) = v_ref (h / h_ref)^α`, zero at and below the ground.
+    PowerLaw {
+        /// Air velocity at `reference_altitude` (m/s).
+        reference_velocity: Vector3<f64>,
+        /// Altitude of the reference measurement (m), positive.
+        reference_altitude: f64,
+        /// Shear exponent `α`, about 1/7 over open ground.
+        exponent: f64,
+    },
+    /// Measured profile, linearly interpolated between layers and held beyond them.
+    Layers {
+        /// Layers sorted by altitude.
+        layers: Vec<WindLayer>,
+    },
+}
+
+/// One altitude of a [`Wind::Layers`] profile.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WindLayer {
+    /// Altitude (m).
+    pub altitude: f64,
+    /// Air velocity at that altitude (m/s).
+    pub velocity: Vector3<f64>,
+}
+
+impl Wind {
+    /// Air velocity at `position` (m/s).
+    #[inline]
+    pub fn at(&self, position: &Vector3<f64>) -> Vector3<f64> {
+        let altitude = position.z;
+        match self {
+            Self::Calm => Vector3::zeros(),
+            Self::Constant { velocity } => *velocity,
+            Self::PowerLaw {
+                reference_velocity,
+                reference_altitude,
+                exponent,
+            } => {
+                if altitude <= 0.0 {
+                    return Vector3::zeros();
+                }
+                reference_velocity * (altitude / reference_altitude).powf(*exponent)
+            }
+            Self::Layers { layers } => {
+                let upper = layers.partition_point(|layer| layer.altitude <= altitude);
+                match (upper.checked_sub(1).map(|i| &layers[i]), layers.get(upper)) {
+                    (Some(low), Some(high)) => {
+                        let t = (altitude - low.altitude) / (high.altitude - low.altitude);
+                        low.velocity + (high.velocity - low.velocity) * t
+                    }
+                    (Some(only), None) | (None, Some(only)) => only.velocity,
+                    (None, None) => Vector3::zeros(),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use crate::core::LocalFrame;
+use crate::simulation::SimulationMetrics;
+use anyhow::Context;
+use nalgebra::Vector3;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Mean Earth radius used to turn local meters into degree offsets (m).
+const EARTH_RADIUS: f64 = 6_371_000.0;
+
+/// Geodetic origin of the local Cartesian frame: `x` east, `y` north, `z` up (altitude).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeoOrigin {
+    /// Latitude in degrees.
+    pub latitude: f64,
+    /// Longitude in degrees.
+    pub longitude: f64,
+}
+
+impl Default for GeoOrigin {
+    /// 42°N 5°E, over the Mediterranean.
+    fn default() -> Self {
+        Self {
+            latitude: 42.0,
+            longitude: 5.0,
+        }
+    }
+}
+
+/// One object track of an ACMI recording.
+#[derive(Clone, Debug)]
+pub struct AcmiObject {
+    /// Unique object id, written in hexadecimal.
+    pub id: u64,
+    /// Display name.
+    pub name: String,
+    /// Tacview type tags, e.g. `Weapon+Missile` or `Air+FixedWing`.
+    pub object_type: String,
+    /// Tacview color (`Red`, `Blue`, `Green`, `Orange`, ...).
+    pub color: String,
+    /// Sample times (s).
+    pub times: Vec<f64>,
+    /// Positions in the local frame (m).
+    pub positions: Vec<Vector3<f64>>,
+    /// Velocities in the local frame (m/s), used for the attitude. May be empty.
+    pub velocities: Vec<Vector3<f64>>,
+}
+
+/// A discrete ACMI event such as a hit or a message.
+#[derive(Clone, Debug)]
+pub struct AcmiEvent {
+    /// Time of the event (s).
+    pub time: f64,
+    /// Tacview event name, e.g. `Destroyed`, `Message` or `Timeout`.
+    pub kind: String,
+    /// Objects involved.
+    pub objects: Vec<u64>,
+    /// Free text.
+    pub text: String,
+}
+
+/// Tacview ACMI 2.1 text recording of one or more engagements.
+///
+/// ```no_run
+/// use missile_sim::export::{AcmiExport, GeoOrigin};
+/// use missile_sim::prelude::*;
+///
+/// let scenario = Scenario::load("scenarios/01_bvr_intercept.toml").unwrap();
+/// let metrics = scenario.simulate(&PureProportionalNavigation);
+///
+/// AcmiExport::new(GeoOrigin::default())
+///     .title(&scenario.name)
+///     .add_run("PPN", &metrics)
+///     .save("bvr.acmi")
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct AcmiExport {
+    origin: GeoOrigin,
+    frame: Option<LocalFrame>,
+    reference_time: String,
+    title: Option<String>,
+    objects: Vec<AcmiObject>,
+    events: Vec<AcmiEvent>,
+    next_id: u64,
+}
+
+impl AcmiExport {
+    /// Creates an empty recording around `origin`, treating the coordinates as flat
+    /// east-north-up meters.
+    pub fn new(origin: GeoOrigin) -> Self {
+        Self {
+            origin,
+            frame: None,
+            reference_time: "2000-01-01T00:00:00Z".to_string(),
+            title: None,
+            objects: Vec::new(),
+            events: Vec::new(),
+            next_id: 0x100,
+        }
+    }
+
+    /// Creates an empty recording for coordinates in a geodetic `frame`, positions are
+    /// converted exactly through WGS-84 instead of the flat approximation.
+    pub fn with_frame(frame: LocalFrame) -> Self {
+        let mut export = Self::new(GeoOrigin {
+            latitude: frame.origin.latitude,
+            longitude: frame.origin.longitude,
+        });
+        export.frame = Some(frame);
+        export
+    }
+
+    /// Sets the recording title.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Sets the UTC time of `t = 0`, ISO 8601 (`2024-05-01T12:00:00Z`).
+    pub fn reference_time(mut self, time: &str) -> Self {
+        self.reference_time = time.to_string();
+        self
+    }
+
+    /// Returns a fresh object id.
+    pub fn allocate_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    /// Adds an object track. Use [`AcmiExport::allocate_id`] for its id.
+    pub fn add_object(mut self, object: AcmiObject) -> Self {
+        self.objects.push(object);
+        self
+    }
+
+    /// Adds an event.
+    pub fn add_event(mut self, event: AcmiEvent) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Adds a run as a red missile and a blue target, with a `Destroyed` event on a hit
+    /// and a message with the miss distance either way.
+    pub fn add_run(mut self, name: &str, metrics: &SimulationMetrics) -> Self {
+        let missile_id = self.allocate_id();
+        let target_id = self.allocate_id();
+        let end = metrics.time_history.last().copied().unwrap_or(0.0);
+
+        self.objects.push(AcmiObject {
+            id: missile_id,
+            name: format!("{name} missile"),
+            object_type: "Weapon+Missile".to_string(),
+            color: "Red".to_string(),
+            times: metrics.time_history.clone(),
+            positions: metrics.missile_trajectory.clone(),
+            velocities: metrics.missile_velocity.clone(),
+        });
+        self.objects.push(AcmiObject {
+            id: target_id,
+            name: format!("{name} target"),
+            object_type: "Air+FixedWing".to_string(),
+            color: "Blue".to_string(),
+            times: metrics.time_history.clone(),
+            positions: metrics.target_trajectory.clone(),
+            velocities: metrics.target_velocity.clone(),
+        });
+
+        if metrics.hit {
+            self.events.push(AcmiEvent {
+                time: end,
+                kind: "Destroyed".to_string(),
+                objects: vec![target_id],
+                text: format!("{name}: hit"),
+            });
+        }
+        self.events.push(AcmiEvent {
+            time: end,
+            kind: "Message".to_string(),
+            objects: vec![missile_id, target_id],
+            text: format!(
+                "{name}: {} ({:.2} m)",
+                if metrics.hit { "hit" } else { "miss" },
+                metrics.miss_distance
+            ),
+        });
+
+        self
+    }
+
+    /// Writes the recording as ACMI text.
+    pub fn write<W: std::io::Write>(&self, mut writer: W) -> anyhow::Result<()> {
+        writer.write_all(self.to_acmi().as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Saves the recording, conventionally with an `.acmi` extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_acmi()).with_context(|| format!("writing {}", path.display()))
+    }
+
+    /// Renders the recording as ACMI text.
+    pub fn to_acmi(&self) -> String {
+        let mut out = String::new();
+        // writing into a String can't fail
+        let _ = self.render(&mut out);
+        out
+    }
+
+    fn render(&self, out: &mut String) -> std::fmt::Result {
+        writeln!(out, "FileType=text/acmi/tacview")?;
+        writeln!(out, "FileVersion=2.1")?;
+        writeln!(out, "0,ReferenceTime={}", self.reference_time)?;
+        writeln!(out, "0,ReferenceLongitude={}", self.origin.longitude)?;
+        writeln!(out, "0,ReferenceLatitude={}", self.origin.latitude)?;
+        writeln!(out, "0,DataSource=missile-sim")?;
+        if let Some(title) = &self.title {
+            writeln!(out, "0,Title={}", escape(title))?;
+        }
+
+        // (time, kind, object index, sample index); kind orders samples before events
+        let mut frames: Vec<(f64, u8, usize, usize)> = Vec::new();
+        for (o, object) in self.objects.iter().enumerate() {
+            frames.extend(object.times.iter().enumerate().map(|(i, t)| (*t, 0, o, i)));
+            if let Some(last) = object.times.last() {
+                frames.push((*last, 2, o, 0));
+            }
+        }
+        frames.extend(
+            self.events
+                .iter()
+                .enumerate()
+                .map(|(e, ev)| (ev.time, 1, e, 0)),
+        );
+        frames.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+        let mut current = f64::NAN;
+        for (time, kind, index, sample) in frames {
+            if time != current {
+                writeln!(out, "#{time}")?;
+                current = time;
+            }
+
+            match kind {
+                0 => {
+                    let object = &self.objects[index];
+                    let (lon, lat, alt) = self.geographic(&object.positions[sample]);
+                    let p = self.enu(&object.positions[sample]);
+                    let (pitch, heading) = object
+                        .velocities
+                        .get(sample)
+                        .map_or((0.0, 0.0), |v| attitude(&self.enu(v)));
+
+                    write!(
+                        out,
+                        "{:x},T={lon:.7}|{lat:.7}|{alt:.2}|0|{pitch:.2}|{heading:.2}|{:.2}|{:.2}|{heading:.2}",
+                        object.id, p.x, p.y
+                    )?;
+                    if sample == 0 {
+                        write!(
+                            out,
+                            ",Type={},Name={},Color={}",
+                            object.object_type,
+                            escape(&object.name),
+                            object.color
+                        )?;
+                    }
+                    writeln!(out)?;
+                }
+                1 => {
+                    let event = &self.events[index];
+                    let ids: Vec<String> =
+                        event.objects.iter().map(|id| format!("{id:x}")).collect();
+                    writeln!(
+                        out,
+                        "0,Event={}|{}|{}",
+                        event.kind,
+                        ids.join("|"),
+                        escape(&event.text)
+                    )?;
+                }
+                _ => writeln!(out, "-{:x}", self.objects[index].id)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Longitude and latitude offsets from the reference (degrees) and altitude (m).
+    fn geographic(&self, p: &Vector3<f64>) -> (f64, f64, f64) {
+        match &self.frame {
+            Some(frame) => {
+                let g = frame.to_geodetic(p);
+                (
+                    g.longitude - self.origin.longitude,
+                    g.latitude - self.origin.latitude,
+                    g.altitude,
+                )
+            }
+            None => {
+                let cos_lat = self.origin.latitude.to_radians().cos().max(1e-6);
+                (
+                    (p.x / (EARTH_RADIUS * cos_lat)).to_degrees(),
+                    (p.y / EARTH_RADIUS).to_degrees(),
+                    p.z,
+                )
+            }
+        }
+    }
+
+    /// East-north-up components of a local vector.
+    fn enu(&self, v: &Vector3<f64>) -> Vector3<f64> {
+        self.frame.map_or(*v, |frame| frame.to_enu(v))
+    }
+}
+
+/// Pitch and heading (degrees, heading clockwise from north) of a velocity vector.
+fn attitude(velocity: &Vector3<f64>) -> (f64, f64) {
+    let horizontal = velocity.x.hypot(velocity.y);
+    let pitch = velocity.z.atan2(horizontal).to_degrees();
+    let heading = velocity.x.atan2(velocity.y).to_degrees().rem_euclid(360.0);
+    (pitch, heading)
+}
+
+/// Escapes the characters ACMI treats as separators.
+fn escape(text: &str) -> String {
+    text.replace(',', "\\,").replace('\n', "\\\n")
+}
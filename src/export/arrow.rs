@@ -0,0 +1,39 @@
+use crate::export::{ColumnData, Table};
+use arrow_array::{ArrayRef, BooleanArray, Float64Array, RecordBatch, StringArray, UInt64Array};
+use arrow_ipc::writer::FileWriter;
+use arrow_schema::{DataType, Field, Schema};
+use std::io::Write;
+use std::sync::Arc;
+
+impl Table {
+    /// Writes the table as an Apache Arrow IPC file (Feather v2), a single record batch.
+    pub fn write_arrow_ipc<W: Write>(&self, writer: W) -> anyhow::Result<()> {
+        let (fields, arrays): (Vec<Field>, Vec<ArrayRef>) = self
+            .columns
+            .iter()
+            .map(|column| {
+                let (data_type, array): (DataType, ArrayRef) = match &column.data {
+                    ColumnData::F64(v) => {
+                        (DataType::Float64, Arc::new(Float64Array::from(v.clone())))
+                    }
+                    ColumnData::Bool(v) => {
+                        (DataType::Boolean, Arc::new(BooleanArray::from(v.clone())))
+                    }
+                    ColumnData::U64(v) => {
+                        (DataType::UInt64, Arc::new(UInt64Array::from(v.clone())))
+                    }
+                    ColumnData::Str(v) => (DataType::Utf8, Arc::new(StringArray::from(v.clone()))),
+                };
+                (Field::new(&column.name, data_type, false), array)
+            })
+            .unzip();
+
+        let schema = Arc::new(Schema::new(fields));
+        let batch = RecordBatch::try_new(schema.clone(), arrays)?;
+
+        let mut writer = FileWriter::try_new(writer, &schema)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::export::{ColumnData, Table};
+use std::io::Write;
+
+impl Table {
+    /// Writes the table as CSV with a header row.
+    ///
+    /// Floats use the shortest representation that round-trips, strings are quoted when
+    /// they contain separators, quotes or newlines.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> anyhow::Result<()> {
+        let header: Vec<String> = self.columns.iter().map(|c| escape(&c.name)).collect();
+        writeln!(writer, "{}", header.join(","))?;
+
+        let mut line = String::new();
+        for row in 0..self.rows() {
+            line.clear();
+            for (i, column) in self.columns.iter().enumerate() {
+                if i > 0 {
+                    line.push(',');
+                }
+                match &column.data {
+                    ColumnData::F64(v) => line.push_str(&v[row].to_string()),
+                    ColumnData::Bool(v) => line.push_str(if v[row] { "true" } else { "false" }),
+                    ColumnData::U64(v) => line.push_str(&v[row].to_string()),
+                    ColumnData::Str(v) => line.push_str(&escape(&v[row])),
+                }
+            }
+            writeln!(writer, "{line}")?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Returns the table as a CSV string.
+    pub fn to_csv(&self) -> String {
+        let mut buffer = Vec::new();
+        // writing into a Vec can't fail
+        self.write_csv(&mut buffer).expect("in-memory CSV write");
+        String::from_utf8(buffer).expect("CSV output is UTF-8")
+    }
+}
+
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
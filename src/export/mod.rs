@@ -0,0 +1,33 @@
+//! Tabular export of simulation results.
+//!
+//! Results are first turned into a columnar [`Table`], then written as CSV or, with the
+//! `arrow` feature, as an Apache Arrow IPC file (readable by pandas, polars, DuckDB, ...).
+//!
+//! - [`trajectory_table`] — one row per recorded step of a [`crate::simulation::SimulationMetrics`],
+//!   vectors flattened to `_x`, `_y`, `_z` columns.
+//! - [`summary_table`] — one row per run, from [`RunRecord`]s.
+//!
+//! [`AcmiExport`] writes runs as Tacview ACMI recordings for 3D replay instead.
+//!
+//! ```no_run
+//! use missile_sim::export::{RunRecord, summary_table, trajectory_table};
+//! use missile_sim::prelude::*;
+//!
+//! let scenario = Scenario::load("scenarios/01_bvr_intercept.toml").unwrap();
+//! let metrics = scenario.simulate(&PureProportionalNavigation);
+//!
+//! trajectory_table(&metrics).save("trajectory.csv").unwrap();
+//! let runs = [RunRecord::new(&scenario, &PureProportionalNavigation, &metrics)];
+//! summary_table(&runs).save("runs.csv").unwrap();
+//! ```
+
+mod acmi;
+#[cfg(feature = "arrow")]
+mod arrow;
+mod csv;
+mod records;
+mod table;
+
+pub use acmi::{AcmiEvent, AcmiExport, AcmiObject, GeoOrigin};
+pub use records::{RunRecord, summary_table, trajectory_table};
+pub use table::{Column, ColumnData, Table};
@@ -0,0 +1,112 @@
+use crate::export::{ColumnData, Table};
+use crate::guidance::GuidanceLaw;
+use crate::simulation::{EngagementSummary, Scenario, SimulationMetrics};
+use serde::{Deserialize, Serialize};
+
+/// Per-step table of a run.
+///
+/// Columns: `time`, `missile_{x,y,z}`, `missile_v{x,y,z}`, `target_{x,y,z}`,
+/// `target_v{x,y,z}`, `distance`, `acceleration`, `los_rate`, `closing_speed`, followed by
+/// `commanded_a*`, `achieved_a*`, `los_rate_*` and `los_*` when vector histories were recorded,
+/// and `beam_deviation` for runs with a launcher.
+pub fn trajectory_table(metrics: &SimulationMetrics) -> Table {
+    let mut table = Table::new();
+    table
+        .push_f64("time", metrics.time_history.iter().copied())
+        .push_vector("missile", &metrics.missile_trajectory)
+        .push_vector("missile_v", &metrics.missile_velocity)
+        .push_vector("target", &metrics.target_trajectory)
+        .push_vector("target_v", &metrics.target_velocity)
+        .push_f64("distance", metrics.distance_records.iter().copied())
+        .push_f64("acceleration", metrics.acceleration_records.iter().copied())
+        .push_f64("los_rate", metrics.los_rate_records.iter().copied())
+        .push_f64(
+            "closing_speed",
+            metrics.closing_speed_records.iter().copied(),
+        );
+
+    if !metrics.commanded_acceleration.is_empty() {
+        table
+            .push_vector("commanded_a", &metrics.commanded_acceleration)
+            .push_vector("achieved_a", &metrics.achieved_acceleration)
+            .push_vector("los_rate", &metrics.los_rate_vectors)
+            .push_vector("los", &metrics.los_unit_vectors);
+    }
+    if !metrics.beam_deviation_records.is_empty() {
+        table.push_f64(
+            "beam_deviation",
+            metrics.beam_deviation_records.iter().copied(),
+        );
+    }
+
+    table
+}
+
+/// Outcome of one run, a row of [`summary_table`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// Scenario name.
+    pub scenario: String,
+    /// Guidance law with its parameters, see [`crate::guidance::GuidanceSpec`].
+    pub guidance: String,
+    /// Whether the run was a hit.
+    pub hit: bool,
+    /// Miss distance (m).
+    pub miss_distance: f64,
+    /// Scalar summary of the run.
+    pub summary: EngagementSummary,
+}
+
+impl RunRecord {
+    /// Collects the record of a finished run.
+    pub fn new(
+        scenario: &Scenario,
+        guidance: &dyn GuidanceLaw,
+        metrics: &SimulationMetrics,
+    ) -> Self {
+        Self {
+            scenario: scenario.name.clone(),
+            guidance: guidance.spec().to_string(),
+            hit: metrics.hit,
+            miss_distance: metrics.miss_distance,
+            summary: metrics.summary.clone(),
+        }
+    }
+}
+
+/// One row per run: `scenario`, `guidance`, `hit`, `miss_distance` and every
+/// [`EngagementSummary`] field.
+pub fn summary_table(runs: &[RunRecord]) -> Table {
+    let f = |get: fn(&EngagementSummary) -> f64| runs.iter().map(move |r| get(&r.summary));
+
+    let mut table = Table::new();
+    table
+        .push(
+            "scenario",
+            ColumnData::Str(runs.iter().map(|r| r.scenario.clone()).collect()),
+        )
+        .push(
+            "guidance",
+            ColumnData::Str(runs.iter().map(|r| r.guidance.clone()).collect()),
+        )
+        .push(
+            "hit",
+            ColumnData::Bool(runs.iter().map(|r| r.hit).collect()),
+        )
+        .push_f64("miss_distance", runs.iter().map(|r| r.miss_distance))
+        .push_f64("time_of_flight", f(|s| s.time_of_flight))
+        .push_f64("peak_acceleration_g", f(|s| s.peak_acceleration_g))
+        .push_f64("rms_acceleration_g", f(|s| s.rms_acceleration_g))
+        .push_f64("control_effort", f(|s| s.control_effort))
+        .push_f64("final_aspect_angle", f(|s| s.final_aspect_angle))
+        .push_f64("impact_angle", f(|s| s.impact_angle))
+        .push_f64("final_closing_speed", f(|s| s.final_closing_speed))
+        .push_f64("launch_heading_error", f(|s| s.launch_heading_error))
+        .push_f64("peak_los_rate", f(|s| s.peak_los_rate))
+        .push_f64("saturation_time", f(|s| s.saturation_time))
+        .push_f64("terminal_speed", f(|s| s.terminal_speed))
+        .push_f64("divert_delta_v", f(|s| s.divert_delta_v))
+        .push_f64("peak_beam_deviation", f(|s| s.peak_beam_deviation))
+        .push_f64("duration", f(|s| s.duration));
+    table
+}
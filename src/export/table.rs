@@ -0,0 +1,122 @@
+use anyhow::Context;
+use std::path::Path;
+
+/// Values of one column.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnData {
+    /// 64-bit floats.
+    F64(Vec<f64>),
+    /// Booleans.
+    Bool(Vec<bool>),
+    /// Unsigned integers.
+    U64(Vec<u64>),
+    /// Strings.
+    Str(Vec<String>),
+}
+
+impl ColumnData {
+    /// Number of values.
+    pub fn len(&self) -> usize {
+        match self {
+            ColumnData::F64(v) => v.len(),
+            ColumnData::Bool(v) => v.len(),
+            ColumnData::U64(v) => v.len(),
+            ColumnData::Str(v) => v.len(),
+        }
+    }
+
+    /// Whether the column has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A named column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Column {
+    /// Column name.
+    pub name: String,
+    /// Column values.
+    pub data: ColumnData,
+}
+
+/// A simple columnar table, all columns have the same length.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Table {
+    /// Columns in output order.
+    pub columns: Vec<Column>,
+}
+
+impl Table {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a column. Panics if its length differs from the existing columns.
+    pub fn push(&mut self, name: &str, data: ColumnData) -> &mut Self {
+        if let Some(first) = self.columns.first() {
+            assert_eq!(
+                first.data.len(),
+                data.len(),
+                "column `{name}` has a different length than `{}`",
+                first.name
+            );
+        }
+        self.columns.push(Column {
+            name: name.to_string(),
+            data,
+        });
+        self
+    }
+
+    /// Appends a float column.
+    pub fn push_f64(&mut self, name: &str, values: impl IntoIterator<Item = f64>) -> &mut Self {
+        self.push(name, ColumnData::F64(values.into_iter().collect()))
+    }
+
+    /// Appends three float columns `<name>_x`, `<name>_y`, `<name>_z` from a vector series.
+    pub fn push_vector(&mut self, name: &str, values: &[nalgebra::Vector3<f64>]) -> &mut Self {
+        for (axis, suffix) in ["x", "y", "z"].iter().enumerate() {
+            self.push_f64(&format!("{name}_{suffix}"), values.iter().map(|v| v[axis]));
+        }
+        self
+    }
+
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        self.columns.first().map_or(0, |c| c.data.len())
+    }
+
+    /// Returns a column by name.
+    pub fn column(&self, name: &str) -> Option<&ColumnData> {
+        self.columns
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| &c.data)
+    }
+
+    /// Saves the table, the format is taken from the extension: `.csv`, or `.arrow`,
+    /// `.feather`, `.ipc` with the `arrow` feature.
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase)
+            .unwrap_or_default();
+        // pick the writer first, so an unsupported format leaves no empty file behind
+        let write: fn(&Self, std::io::BufWriter<std::fs::File>) -> anyhow::Result<()> =
+            match ext.as_str() {
+                "csv" => Self::write_csv,
+                #[cfg(feature = "arrow")]
+                "arrow" | "feather" | "ipc" => Self::write_arrow_ipc,
+                _ => anyhow::bail!("unsupported table format: {}", path.display()),
+            };
+        let file = std::io::BufWriter::new(
+            std::fs::File::create(path).with_context(|| format!("creating {}", path.display()))?,
+        );
+
+        write(self, file).with_context(|| format!("writing {}", path.display()))
+    }
+}
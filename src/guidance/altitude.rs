@@ -0,0 +1,116 @@
+use crate::entity::{Missile, Target};
+use crate::environment::Terrain;
+use crate::guidance::{GuidanceContext, GuidanceLaw, GuidanceSpec, WrapperSpec};
+use nalgebra::Vector3;
+use std::collections::BTreeMap;
+
+/// Minimum-altitude guard around another guidance law.
+///
+/// Predicts the missile's height `lookahead` seconds ahead under the inner command and,
+/// if it would sink below `min_altitude` over the ground, raises the vertical command to
+/// the larger of
+/// - `2 (floor - z - v_z T) / T²` — back to the floor within the lookahead
+/// - `v_z² / 2 (z - floor)` — stop sinking before reaching the floor
+///
+/// Below the floor while still sinking it pulls up at `max_acceleration`.
+///
+/// The ground is the run's [`Terrain`] under the current and predicted positions,
+/// or `z = 0` without terrain. Protects sea-skimmer interceptors from diving into the sea.
+pub struct MinimumAltitude {
+    inner: Box<dyn GuidanceLaw>,
+    min_altitude: f64,
+    lookahead: f64,
+    name: String,
+}
+
+impl MinimumAltitude {
+    /// Guards `inner` with a floor of `min_altitude` meters above the ground and a
+    /// 2 second lookahead.
+    pub fn new(inner: Box<dyn GuidanceLaw>, min_altitude: f64) -> Self {
+        let name = format!("{}+MinAlt", inner.name());
+        Self {
+            inner,
+            min_altitude,
+            lookahead: 2.0,
+            name,
+        }
+    }
+
+    /// Sets how far ahead (s) the guard predicts the missile's height.
+    pub fn with_lookahead(mut self, lookahead: f64) -> Self {
+        self.lookahead = lookahead.max(1e-3);
+        self
+    }
+
+    /// Returns the guarded law.
+    pub fn inner(&self) -> &dyn GuidanceLaw {
+        self.inner.as_ref()
+    }
+
+    /// Raises `acceleration` if the predicted height drops below the floor.
+    #[inline]
+    fn guard(
+        &self,
+        missile: &Missile,
+        terrain: Option<&Terrain>,
+        mut acceleration: Vector3<f64>,
+    ) -> Vector3<f64> {
+        let t = self.lookahead;
+        let position = missile.state.position;
+        let ahead = position + missile.state.velocity * t;
+        let ground = terrain.map_or(0.0, |terrain| {
+            terrain
+                .elevation(position.x, position.y)
+                .max(terrain.elevation(ahead.x, ahead.y))
+        });
+        let floor = ground + self.min_altitude;
+
+        let predicted = ahead.z + 0.5 * acceleration.z * t * t;
+        if predicted < floor {
+            let sink = missile.state.velocity.z.min(0.0);
+            let margin = position.z - floor;
+            let arrest = if margin > 0.0 {
+                sink * sink / (2.0 * margin)
+            } else if sink < 0.0 {
+                missile.max_acceleration
+            } else {
+                0.0
+            };
+            let required = (2.0 * (floor - ahead.z) / (t * t)).max(arrest);
+            acceleration.z = acceleration.z.max(required);
+        }
+        acceleration
+    }
+}
+
+impl GuidanceLaw for MinimumAltitude {
+    #[inline]
+    fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64> {
+        let acceleration = self.inner.calculate_acceleration(missile, target);
+        self.guard(missile, None, acceleration)
+    }
+
+    #[inline]
+    fn guide(&self, missile: &Missile, target: &Target, ctx: &mut GuidanceContext) -> Vector3<f64> {
+        let acceleration = self.inner.guide(missile, target, ctx);
+        self.guard(missile, ctx.terrain, acceleration)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn parameters(&self) -> BTreeMap<String, f64> {
+        let mut parameters = self.inner.parameters();
+        parameters.insert("min_altitude".to_string(), self.min_altitude);
+        parameters.insert("lookahead".to_string(), self.lookahead);
+        parameters
+    }
+
+    fn spec(&self) -> GuidanceSpec {
+        self.inner.spec().with_wrapper(WrapperSpec::MinAltitude {
+            min_altitude: self.min_altitude,
+            lookahead: self.lookahead,
+        })
+    }
+}
@@ -2,6 +2,7 @@ use crate::core::{calculate_closing_speed, calculate_los_rate};
 use crate::entity::{Missile, Target};
 use crate::guidance::GuidanceLaw;
 use nalgebra::Vector3;
+use std::collections::BTreeMap;
 
 /// Augmented Proportional Navigation (APN) with Zero Effort Miss (ZEM) compensation.
 ///
@@ -104,4 +105,8 @@ impl GuidanceLaw for AugmentedProportionalNavigation {
     fn name(&self) -> &str {
         "APN"
     }
+
+    fn parameters(&self) -> BTreeMap<String, f64> {
+        BTreeMap::from([("time_constant".to_string(), self.time_constant)])
+    }
 }
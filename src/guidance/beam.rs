@@ -0,0 +1,117 @@
+use crate::entity::{Launcher, Missile, Target};
+use crate::guidance::GuidanceContext;
+use nalgebra::Vector3;
+
+/// Missile motion relative to the tracker-to-target beam, shared by the command guidance laws.
+pub(crate) struct Beam {
+    /// Beam direction, tracker to target.
+    pub direction: Vector3<f64>,
+    /// Missile offset from the beam, perpendicular to it (m).
+    pub deviation: Vector3<f64>,
+    /// Rate of change of `deviation` (m/s).
+    pub deviation_rate: Vector3<f64>,
+    /// Acceleration perpendicular to the beam of the beam point abreast of the missile (m/s²).
+    ///
+    /// A missile already on the beam stays on it by flying exactly this.
+    pub feed_forward: Vector3<f64>,
+}
+
+impl Beam {
+    /// Beam geometry from `launcher` to `target`, `None` when the target sits on the tracker.
+    ///
+    /// With `w` the tracker-to-target vector, `R = |w|`, `u = w / R` and `s = u · r` the
+    /// missile's range along the beam, the beam turns at `ω = u × ẇ / R` and the point at `s`
+    /// accelerates across it by `a_L⊥ + 2 ṡ u̇ + s ü⊥`.
+    #[inline]
+    pub fn new(launcher: &Launcher, missile: &Missile, target: &Target) -> Option<Self> {
+        let l = &launcher.state;
+        let w = target.state.position - l.position;
+        let w_dot = target.state.velocity - l.velocity;
+        let w_ddot = target.acceleration - launcher.acceleration;
+        let range = w.norm();
+        if range < 1e-6 {
+            return None;
+        }
+
+        let u = w / range;
+        let range_rate = u.dot(&w_dot);
+        let omega = u.cross(&w_dot) / range;
+        let u_dot = omega.cross(&u);
+        let omega_dot =
+            (u_dot.cross(&w_dot) + u.cross(&w_ddot)) / range - omega * range_rate / range;
+        let u_ddot = omega_dot.cross(&u) + omega.cross(&u_dot);
+
+        let r = missile.state.position - l.position;
+        let r_dot = missile.state.velocity - l.velocity;
+        let s = u.dot(&r);
+        let s_dot = u_dot.dot(&r) + u.dot(&r_dot);
+
+        let across = |v: Vector3<f64>| v - u * u.dot(&v);
+        Some(Self {
+            direction: u,
+            deviation: r - u * s,
+            deviation_rate: r_dot - u * s_dot - u_dot * s,
+            feed_forward: across(launcher.acceleration + u_dot * (2.0 * s_dot) + u_ddot * s),
+        })
+    }
+
+    /// Beam from the run's launcher, or from a tracker fixed at the origin without one.
+    #[inline]
+    pub fn from_context(
+        missile: &Missile,
+        target: &Target,
+        ctx: Option<&GuidanceContext>,
+    ) -> Option<Self> {
+        match ctx.and_then(|ctx| ctx.launcher) {
+            Some(launcher) => Self::new(launcher, missile, target),
+            None => Self::new(&Launcher::fixed(Vector3::zeros()), missile, target),
+        }
+    }
+
+    /// Acceleration across the beam cancelling gravity (m/s²).
+    #[inline]
+    pub fn gravity_compensation(&self, ctx: Option<&GuidanceContext>) -> Vector3<f64> {
+        let Some(ctx) = ctx else {
+            return Vector3::zeros();
+        };
+        let gravity = ctx.environment.gravity_vector();
+        -(gravity - self.direction * self.direction.dot(&gravity))
+    }
+
+    /// PD correction toward the beam for a loop of natural frequency `bandwidth` (rad/s)
+    /// and `damping`.
+    #[inline]
+    pub fn correction(&self, bandwidth: f64, damping: f64) -> Vector3<f64> {
+        -(self.deviation * (bandwidth * bandwidth)
+            + self.deviation_rate * (2.0 * damping * bandwidth))
+    }
+
+    /// Lateral command, perpendicular to the missile velocity, whose component across the
+    /// beam is `across`, clamped to the missile's limit.
+    ///
+    /// A missile crabbing across a turning beam flies at an angle to it, a command across the
+    /// beam would partly push along the velocity and be lost. Tilting it along the beam keeps
+    /// all of it.
+    #[inline]
+    pub fn command(&self, missile: &Missile, across: Vector3<f64>) -> Vector3<f64> {
+        let velocity = &missile.state.velocity;
+        let along = self.direction.dot(velocity);
+        let lateral = if along.abs() > 0.1 * velocity.norm() {
+            across - self.direction * (across.dot(velocity) / along)
+        } else {
+            across
+        };
+        clamp(missile, lateral)
+    }
+}
+
+/// Clamps `acceleration` to the missile's limit.
+#[inline]
+fn clamp(missile: &Missile, acceleration: Vector3<f64>) -> Vector3<f64> {
+    let norm = acceleration.norm();
+    if norm > missile.max_acceleration {
+        acceleration * (missile.max_acceleration / norm)
+    } else {
+        acceleration
+    }
+}
@@ -0,0 +1,79 @@
+use crate::entity::{Missile, Target};
+use crate::guidance::beam::Beam;
+use crate::guidance::{GuidanceContext, GuidanceLaw};
+use nalgebra::Vector3;
+use std::collections::BTreeMap;
+
+/// Beam-rider guidance, the missile flies itself along the tracker's beam.
+///
+/// A receiver in the tail senses where the missile sits in the beam, so unlike
+/// [`crate::guidance::CommandToLineOfSight`] it only knows its own offset `e`, not how the
+/// beam turns. It corrects the offset alone and lags a beam following a crossing target:
+///
+/// `a_c = -ω² e - 2 ζ ω ė - g⊥`
+///
+/// The beam starts at the run's [`crate::entity::Launcher`], or at a tracker fixed at the
+/// origin for runs without one and in [`GuidanceLaw::calculate_acceleration`].
+pub struct BeamRider {
+    bandwidth: f64,
+    damping: f64,
+}
+
+impl BeamRider {
+    /// Creates a beam rider correcting deviations at `bandwidth` (rad/s), damping 0.7.
+    pub fn new(bandwidth: f64) -> Self {
+        Self {
+            bandwidth: bandwidth.max(0.0),
+            damping: 0.7,
+        }
+    }
+
+    /// Sets the damping ratio of the correction loop.
+    pub fn with_damping(mut self, damping: f64) -> Self {
+        self.damping = damping.max(0.0);
+        self
+    }
+
+    #[inline]
+    fn command(
+        &self,
+        missile: &Missile,
+        target: &Target,
+        ctx: Option<&GuidanceContext>,
+    ) -> Vector3<f64> {
+        let Some(beam) = Beam::from_context(missile, target, ctx) else {
+            return Vector3::zeros();
+        };
+        let command =
+            beam.correction(self.bandwidth, self.damping) + beam.gravity_compensation(ctx);
+        beam.command(missile, command)
+    }
+}
+
+impl Default for BeamRider {
+    fn default() -> Self {
+        Self::new(2.0)
+    }
+}
+
+impl GuidanceLaw for BeamRider {
+    #[inline]
+    fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64> {
+        self.command(missile, target, None)
+    }
+
+    fn guide(&self, missile: &Missile, target: &Target, ctx: &mut GuidanceContext) -> Vector3<f64> {
+        self.command(missile, target, Some(ctx))
+    }
+
+    fn name(&self) -> &str {
+        "BR"
+    }
+
+    fn parameters(&self) -> BTreeMap<String, f64> {
+        BTreeMap::from([
+            ("bandwidth".to_string(), self.bandwidth),
+            ("damping".to_string(), self.damping),
+        ])
+    }
+}
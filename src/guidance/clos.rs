@@ -0,0 +1,83 @@
+use crate::entity::{Missile, Target};
+use crate::guidance::beam::Beam;
+use crate::guidance::{GuidanceContext, GuidanceLaw};
+use nalgebra::Vector3;
+use std::collections::BTreeMap;
+
+/// Command to line of sight (CLOS), the ground-computed guidance of SACLOS systems.
+///
+/// The tracker measures both missile and target and drives the missile onto the beam, its
+/// line of sight to the target:
+///
+/// `a_c = a_ff - ω² e - 2 ζ ω ė - g⊥`
+///
+/// where `e` is the missile's offset from the beam, `a_ff` the acceleration that keeps a
+/// missile on the turning beam (see [`crate::guidance::ThreePoint`]) and `g⊥` gravity across
+/// the beam. `ω` is the bandwidth and `ζ` the damping of the correction loop.
+///
+/// The beam starts at the run's [`crate::entity::Launcher`], or at a tracker fixed at the
+/// origin for runs without one and in [`GuidanceLaw::calculate_acceleration`].
+pub struct CommandToLineOfSight {
+    bandwidth: f64,
+    damping: f64,
+}
+
+impl CommandToLineOfSight {
+    /// Creates CLOS guidance correcting deviations at `bandwidth` (rad/s), damping 0.7.
+    pub fn new(bandwidth: f64) -> Self {
+        Self {
+            bandwidth: bandwidth.max(0.0),
+            damping: 0.7,
+        }
+    }
+
+    /// Sets the damping ratio of the correction loop.
+    pub fn with_damping(mut self, damping: f64) -> Self {
+        self.damping = damping.max(0.0);
+        self
+    }
+
+    #[inline]
+    fn command(
+        &self,
+        missile: &Missile,
+        target: &Target,
+        ctx: Option<&GuidanceContext>,
+    ) -> Vector3<f64> {
+        let Some(beam) = Beam::from_context(missile, target, ctx) else {
+            return Vector3::zeros();
+        };
+        let command = beam.feed_forward
+            + beam.correction(self.bandwidth, self.damping)
+            + beam.gravity_compensation(ctx);
+        beam.command(missile, command)
+    }
+}
+
+impl Default for CommandToLineOfSight {
+    fn default() -> Self {
+        Self::new(2.0)
+    }
+}
+
+impl GuidanceLaw for CommandToLineOfSight {
+    #[inline]
+    fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64> {
+        self.command(missile, target, None)
+    }
+
+    fn guide(&self, missile: &Missile, target: &Target, ctx: &mut GuidanceContext) -> Vector3<f64> {
+        self.command(missile, target, Some(ctx))
+    }
+
+    fn name(&self) -> &str {
+        "CLOS"
+    }
+
+    fn parameters(&self) -> BTreeMap<String, f64> {
+        BTreeMap::from([
+            ("bandwidth".to_string(), self.bandwidth),
+            ("damping".to_string(), self.damping),
+        ])
+    }
+}
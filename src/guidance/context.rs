@@ -0,0 +1,101 @@
+use crate::core::SimRng;
+use crate::entity::{Airframe, Launcher, Staging};
+use crate::environment::{Environment, Terrain};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Per-run memory for guidance laws, owned by the [`crate::simulation::SimulationEngine`].
+///
+/// Guidance laws take `&self` and can be shared between runs, so anything they need to
+/// remember from one step to the next (filter states, previous LOS, phase flags) lives
+/// here instead. It is plain data and is saved with engine snapshots, which keeps
+/// restored runs deterministic.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuidanceState {
+    /// Named scalar values.
+    pub scalars: BTreeMap<String, f64>,
+    /// Named vector values.
+    pub vectors: BTreeMap<String, Vector3<f64>>,
+    /// Active phase of a [`crate::guidance::PhasedGuidance`], the engine reports changes
+    /// as [`crate::simulation::SimulationEvent::GuidancePhase`].
+    #[serde(default)]
+    pub phase: usize,
+    /// Memory of each phase of a [`crate::guidance::PhasedGuidance`], by phase index, so
+    /// laws keeping the same keys in different phases don't overwrite each other.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phases: Vec<GuidanceState>,
+}
+
+impl GuidanceState {
+    /// Returns a stored scalar.
+    pub fn scalar(&self, key: &str) -> Option<f64> {
+        self.scalars.get(key).copied()
+    }
+
+    /// Stores a scalar, only allocating the key the first time.
+    pub fn set_scalar(&mut self, key: &str, value: f64) {
+        match self.scalars.get_mut(key) {
+            Some(stored) => *stored = value,
+            None => {
+                self.scalars.insert(key.to_string(), value);
+            }
+        }
+    }
+
+    /// Returns a stored vector.
+    pub fn vector(&self, key: &str) -> Option<Vector3<f64>> {
+        self.vectors.get(key).copied()
+    }
+
+    /// Stores a vector, only allocating the key the first time.
+    pub fn set_vector(&mut self, key: &str, value: Vector3<f64>) {
+        match self.vectors.get_mut(key) {
+            Some(stored) => *stored = value,
+            None => {
+                self.vectors.insert(key.to_string(), value);
+            }
+        }
+    }
+
+    /// Memory of phase `index`, created empty on first use.
+    pub fn phase_state(&mut self, index: usize) -> &mut GuidanceState {
+        if self.phases.len() <= index {
+            self.phases.resize_with(index + 1, GuidanceState::default);
+        }
+        &mut self.phases[index]
+    }
+
+    /// Clears all stored values and returns to the first phase.
+    pub fn clear(&mut self) {
+        self.scalars.clear();
+        self.vectors.clear();
+        self.phase = 0;
+        self.phases.clear();
+    }
+}
+
+/// What the engine passes to [`crate::guidance::GuidanceLaw::guide`] besides the entities.
+pub struct GuidanceContext<'a> {
+    /// Simulation time in seconds.
+    pub time: f64,
+    /// Timestep in seconds.
+    pub dt: f64,
+    /// Memory kept between steps.
+    pub state: &'a mut GuidanceState,
+    /// The run's random generator, use it for any noise so runs stay reproducible.
+    pub rng: &'a mut SimRng,
+    /// Terrain of the run, if any.
+    pub terrain: Option<&'a Terrain>,
+    /// Gravity, air and wind of the run.
+    pub environment: &'a Environment,
+    /// Launch platform and tracker of the run, if any.
+    pub launcher: Option<&'a Launcher>,
+    /// Mass and drag of the missile, if any.
+    pub airframe: Option<&'a Airframe>,
+    /// Booster stages and kill vehicle of the missile, if any.
+    pub stages: Option<&'a Staging>,
+    /// Whether the seeker sees the target. While `false` the target handed to the law is
+    /// the last seen track, extrapolated at constant velocity.
+    pub seeker_locked: bool,
+}
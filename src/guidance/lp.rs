@@ -2,6 +2,7 @@ use crate::core::calculate_closing_speed;
 use crate::entity::{Missile, Target};
 use crate::guidance::GuidanceLaw;
 use nalgebra::Vector3;
+use std::collections::BTreeMap;
 
 /// Lead Pursuit (LP) guidance.
 ///
@@ -108,4 +109,8 @@ impl GuidanceLaw for LeadPursuit {
     fn name(&self) -> &str {
         "LP"
     }
+
+    fn parameters(&self) -> BTreeMap<String, f64> {
+        BTreeMap::from([("lead_time".to_string(), self.lead_time)])
+    }
 }
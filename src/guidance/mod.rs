@@ -1,13 +1,34 @@
+mod altitude;
 mod apn;
+mod beam;
+mod beam_rider;
+mod clos;
+mod context;
 mod lp;
+mod phased;
+mod pip;
 mod pp;
 mod ppn;
+mod pwm;
+mod registry;
+mod spec;
+mod three_point;
 mod tpn;
 mod traits;
 
+pub use altitude::MinimumAltitude;
 pub use apn::AugmentedProportionalNavigation;
+pub use beam_rider::BeamRider;
+pub use clos::CommandToLineOfSight;
+pub use context::{GuidanceContext, GuidanceState};
 pub use lp::LeadPursuit;
+pub use phased::{PhaseTrigger, PhasedGuidance};
+pub use pip::PredictedInterceptPoint;
 pub use pp::PurePursuit;
 pub use ppn::PureProportionalNavigation;
+pub use pwm::PulseWidthModulation;
+pub use registry::{GuidanceFactory, GuidanceRegistry};
+pub use spec::{GuidanceSpec, PhaseSpec, WrapperSpec};
+pub use three_point::ThreePoint;
 pub use tpn::TrueProportionalNavigation;
 pub use traits::GuidanceLaw;
@@ -0,0 +1,246 @@
+use crate::core::calculate_closing_speed;
+use crate::entity::{Missile, Target};
+use crate::guidance::{GuidanceContext, GuidanceLaw, GuidanceSpec};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Condition handing guidance over to the next phase of a [`PhasedGuidance`].
+///
+/// Altitudes are `z`, so they need a z-up frame like the rest of the altitude models, see
+/// [`crate::simulation::Scenario::validate`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PhaseTrigger {
+    /// Missile-target range below `range` (m).
+    Range {
+        /// Range (m).
+        range: f64,
+    },
+    /// Time to go, range over closing speed, below `time` (s).
+    TimeToGo {
+        /// Time to go (s).
+        time: f64,
+    },
+    /// The seeker sees the target, see [`GuidanceContext::seeker_locked`].
+    SeekerLock,
+    /// Missile altitude above `altitude` (m).
+    AltitudeAbove {
+        /// Altitude (m).
+        altitude: f64,
+    },
+    /// Missile altitude below `altitude` (m).
+    AltitudeBelow {
+        /// Altitude (m).
+        altitude: f64,
+    },
+}
+
+impl PhaseTrigger {
+    /// Whether the trigger holds for this geometry.
+    #[inline]
+    pub fn fired(&self, missile: &Missile, target: &Target, seeker_locked: bool) -> bool {
+        let m = &missile.state;
+        let t = &target.state;
+        match *self {
+            Self::Range { range } => (t.position - m.position).norm() < range,
+            Self::TimeToGo { time } => {
+                let closing =
+                    calculate_closing_speed(&m.position, &m.velocity, &t.position, &t.velocity);
+                closing > 0.0 && (t.position - m.position).norm() / closing < time
+            }
+            Self::SeekerLock => seeker_locked,
+            Self::AltitudeAbove { altitude } => m.position.z > altitude,
+            Self::AltitudeBelow { altitude } => m.position.z < altitude,
+        }
+    }
+}
+
+struct Phase {
+    law: Box<dyn GuidanceLaw>,
+    trigger: Option<PhaseTrigger>,
+}
+
+/// Guidance in phases, e.g. midcourse on one law and terminal homing on another.
+///
+/// Phases run in order: the first one flies from launch and each later one takes over
+/// once its [`PhaseTrigger`] fires, never going back. For `blend_time` seconds after a
+/// handover the command fades linearly from the previous law to the new one, so the
+/// missile doesn't see a step in commanded acceleration.
+///
+/// Each phase flies on its own memory in [`crate::guidance::GuidanceState::phases`], so two
+/// phases on laws keeping state, or a phased law nested in a phase, don't share keys.
+/// The active phase is kept in [`crate::guidance::GuidanceState::phase`] and every handover
+/// shows up in the run's metrics as a
+/// [`crate::simulation::SimulationEvent::GuidancePhase`] event.
+///
+/// ```
+/// use missile_sim::guidance::{PhaseTrigger, PhasedGuidance};
+/// use missile_sim::prelude::*;
+///
+/// let guidance = PhasedGuidance::new(Box::new(PurePursuit))
+///     .then(PhaseTrigger::Range { range: 3000.0 }, Box::new(PureProportionalNavigation))
+///     .with_blend_time(0.5);
+/// assert_eq!(guidance.name(), "PP>PPN");
+/// ```
+pub struct PhasedGuidance {
+    phases: Vec<Phase>,
+    blend_time: f64,
+    name: String,
+}
+
+impl PhasedGuidance {
+    /// Starts with `law` flying from launch.
+    pub fn new(law: Box<dyn GuidanceLaw>) -> Self {
+        let name = law.name().to_string();
+        Self {
+            phases: vec![Phase { law, trigger: None }],
+            blend_time: 0.0,
+            name,
+        }
+    }
+
+    /// Adds a phase flown by `law` once `trigger` fires.
+    pub fn then(mut self, trigger: PhaseTrigger, law: Box<dyn GuidanceLaw>) -> Self {
+        self.name = format!("{}>{}", self.name, law.name());
+        self.phases.push(Phase {
+            law,
+            trigger: Some(trigger),
+        });
+        self
+    }
+
+    /// Sets the time (s) over which commands blend at each handover, zero by default.
+    pub fn with_blend_time(mut self, blend_time: f64) -> Self {
+        self.blend_time = blend_time.max(0.0);
+        self
+    }
+
+    /// Number of phases.
+    pub fn len(&self) -> usize {
+        self.phases.len()
+    }
+
+    /// Always `false`, there is at least the launch phase.
+    pub fn is_empty(&self) -> bool {
+        self.phases.is_empty()
+    }
+
+    /// Law flying phase `index`.
+    pub fn law(&self, index: usize) -> Option<&dyn GuidanceLaw> {
+        self.phases.get(index).map(|phase| phase.law.as_ref())
+    }
+
+    /// Advances from `phase` through every following phase whose trigger holds.
+    #[inline]
+    fn next_phase(
+        &self,
+        mut phase: usize,
+        missile: &Missile,
+        target: &Target,
+        seeker_locked: bool,
+    ) -> usize {
+        while let Some(next) = self.phases.get(phase + 1)
+            && next
+                .trigger
+                .is_some_and(|trigger| trigger.fired(missile, target, seeker_locked))
+        {
+            phase += 1;
+        }
+        phase
+    }
+
+    /// Command of phase `index`, flown on that phase's memory.
+    #[inline]
+    fn guide_phase(
+        &self,
+        index: usize,
+        missile: &Missile,
+        target: &Target,
+        ctx: &mut GuidanceContext,
+    ) -> Vector3<f64> {
+        let mut ctx = GuidanceContext {
+            time: ctx.time,
+            dt: ctx.dt,
+            state: ctx.state.phase_state(index),
+            rng: &mut *ctx.rng,
+            terrain: ctx.terrain,
+            environment: ctx.environment,
+            launcher: ctx.launcher,
+            airframe: ctx.airframe,
+            stages: ctx.stages,
+            seeker_locked: ctx.seeker_locked,
+        };
+        self.phases[index].law.guide(missile, target, &mut ctx)
+    }
+}
+
+impl GuidanceLaw for PhasedGuidance {
+    /// Without memory the phase is found from scratch each call, and nothing is blended.
+    fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64> {
+        let phase = self.next_phase(0, missile, target, true);
+        self.phases[phase]
+            .law
+            .calculate_acceleration(missile, target)
+    }
+
+    fn guide(&self, missile: &Missile, target: &Target, ctx: &mut GuidanceContext) -> Vector3<f64> {
+        let current = ctx.state.phase.min(self.phases.len() - 1);
+        let phase = self.next_phase(current, missile, target, ctx.seeker_locked);
+        if phase != current {
+            ctx.state.phase = phase;
+            ctx.state.set_scalar("phase_switch_time", ctx.time);
+            ctx.state.set_scalar("phase_previous", current as f64);
+        }
+
+        let command = self.guide_phase(phase, missile, target, ctx);
+        if self.blend_time > 0.0
+            && let (Some(switched), Some(previous)) = (
+                ctx.state.scalar("phase_switch_time"),
+                ctx.state.scalar("phase_previous"),
+            )
+        {
+            let weight = (ctx.time - switched) / self.blend_time;
+            if weight < 1.0 {
+                let old = self.guide_phase(previous as usize, missile, target, ctx);
+                return old.lerp(&command, weight);
+            }
+        }
+        command
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn parameters(&self) -> BTreeMap<String, f64> {
+        let mut parameters = BTreeMap::new();
+        for (i, phase) in self.phases.iter().enumerate() {
+            for (key, value) in phase.law.parameters() {
+                parameters.insert(format!("phase{i}.{key}"), value);
+            }
+        }
+        parameters.insert("blend_time".to_string(), self.blend_time);
+        parameters
+    }
+
+    /// The first phase's spec with the later phases added, or just the name and parameters
+    /// when the first phase is itself phased or wrapped.
+    fn spec(&self) -> GuidanceSpec {
+        let first = self.phases[0].law.spec();
+        if !first.phases.is_empty() || !first.wrap.is_empty() {
+            return GuidanceSpec {
+                params: self.parameters(),
+                ..GuidanceSpec::new(self.name())
+            };
+        }
+        let mut spec = self.phases[1..]
+            .iter()
+            .filter_map(|phase| Some((phase.trigger?, phase.law.spec())))
+            .fold(first, |spec, (trigger, law)| spec.then(trigger, law));
+        if self.blend_time > 0.0 {
+            spec.blend_time = Some(self.blend_time);
+        }
+        spec
+    }
+}
@@ -0,0 +1,184 @@
+use crate::entity::{Airframe, Missile, Target};
+use crate::environment::Environment;
+use crate::guidance::{GuidanceContext, GuidanceLaw};
+use nalgebra::Vector3;
+use std::collections::BTreeMap;
+
+/// Predicted intercept point (PIP) guidance, for long-range midcourse flight.
+///
+/// Solves for the point where the missile meets the target by fixed-point iteration on
+/// the time of flight `t`:
+/// - the target flies on with its velocity and acceleration, `P = p_T + v_T t + ½ a_T t²`
+/// - the missile covers `s(t) = V t`, or with an [`crate::entity::Airframe`] the
+///   quadratic-drag coast `s(t) = ln(1 + k V t) / k`, `k = ρ C_D S / 2m`, at the density
+///   around it
+/// - `t` is updated to the time the missile needs to cover `|P - p_M|`
+///
+/// and steers the velocity toward that point with a heading-error controller: the turn
+/// rate is `K ε`, `ε` the angle between velocity and the line to the PIP, so the command
+/// is `K V ε` perpendicular to the velocity.
+///
+/// The latest solution is stored in the run's [`crate::guidance::GuidanceState`] as the
+/// vector `pip` and the scalar `pip_time` (time to go, s), both removed while there is no
+/// solution, see [`PredictedInterceptPoint::predict`] to compute it directly.
+pub struct PredictedInterceptPoint {
+    heading_gain: f64,
+    iterations: usize,
+}
+
+impl PredictedInterceptPoint {
+    /// Creates PIP guidance with the heading-error gain `K` (1/s).
+    pub fn new(heading_gain: f64) -> Self {
+        Self {
+            heading_gain: heading_gain.max(0.0),
+            iterations: 20,
+        }
+    }
+
+    /// Sets the most fixed-point iterations per solve, 20 by default.
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations.max(1);
+        self
+    }
+
+    /// Returns the heading-error gain.
+    pub fn heading_gain(&self) -> f64 {
+        self.heading_gain
+    }
+
+    /// Predicted intercept point and time to go, `None` when the missile can't catch the
+    /// target. Drag is modeled when `drag` gives the missile's airframe and the air it
+    /// flies through.
+    pub fn predict(
+        &self,
+        missile: &Missile,
+        target: &Target,
+        drag: Option<(&Airframe, &Environment)>,
+    ) -> Option<(Vector3<f64>, f64)> {
+        let m = &missile.state;
+        let t = &target.state;
+        let speed = m.speed();
+        if speed < 1e-6 {
+            return None;
+        }
+
+        // drag constant of the coast, zero without a drag model
+        let k = match drag {
+            Some((airframe, environment)) => {
+                let density = environment.density(&m.position);
+                let sound = environment.atmosphere.speed_of_sound(m.position.z);
+                let coefficient = airframe.drag_coefficient_at(speed / sound);
+                0.5 * density * coefficient * airframe.reference_area / airframe.mass
+            }
+            None => 0.0,
+        };
+        // time to cover `distance`, inverting s(t)
+        let time_to_cover = |distance: f64| {
+            if k * distance < 1e-9 {
+                distance / speed
+            } else {
+                (k * distance).exp_m1() / (k * speed)
+            }
+        };
+        let point_at =
+            |time: f64| t.position + t.velocity * time + target.acceleration * (0.5 * time * time);
+
+        let mut time = time_to_cover((t.position - m.position).norm());
+        for _ in 0..self.iterations {
+            let next = time_to_cover((point_at(time) - m.position).norm());
+            if !next.is_finite() {
+                return None;
+            }
+            let converged = (next - time).abs() < 1e-6 * next.max(1.0);
+            time = next;
+            if converged {
+                return Some((point_at(time), time));
+            }
+        }
+        // accept a slowly converging solve, reject one running away
+        let residual = time_to_cover((point_at(time) - m.position).norm()) - time;
+        (residual.abs() < 1e-2 * time.max(1.0)).then(|| (point_at(time), time))
+    }
+
+    /// Heading-error command toward `point`.
+    #[inline]
+    fn steer(&self, missile: &Missile, point: &Vector3<f64>) -> Vector3<f64> {
+        let speed = missile.state.speed();
+        let heading = missile.state.velocity / speed;
+        let Some(aim) = (point - missile.state.position).try_normalize(1e-9) else {
+            return Vector3::zeros();
+        };
+
+        let lateral = aim - heading * heading.dot(&aim);
+        let Some(direction) = lateral.try_normalize(1e-12) else {
+            // flying straight at it, or straight away from it
+            return if heading.dot(&aim) < 0.0 {
+                heading
+                    .cross(&Vector3::z())
+                    .try_normalize(1e-9)
+                    .unwrap_or_else(Vector3::y)
+                    * missile.max_acceleration
+            } else {
+                Vector3::zeros()
+            };
+        };
+        let error = heading.dot(&aim).clamp(-1.0, 1.0).acos();
+        direction * (self.heading_gain * speed * error).min(missile.max_acceleration)
+    }
+
+    /// Command toward the PIP, or the target itself when there is no solution.
+    #[inline]
+    fn command(
+        &self,
+        missile: &Missile,
+        target: &Target,
+        drag: Option<(&Airframe, &Environment)>,
+    ) -> (Vector3<f64>, Option<(Vector3<f64>, f64)>) {
+        if missile.state.speed() < 1e-6 {
+            let aim = (target.state.position - missile.state.position)
+                .try_normalize(1e-9)
+                .unwrap_or_else(Vector3::zeros);
+            return (aim * missile.max_acceleration, None);
+        }
+        let solution = self.predict(missile, target, drag);
+        let point = solution.map_or(target.state.position, |(point, _)| point);
+        (self.steer(missile, &point), solution)
+    }
+}
+
+impl Default for PredictedInterceptPoint {
+    fn default() -> Self {
+        Self::new(2.0)
+    }
+}
+
+impl GuidanceLaw for PredictedInterceptPoint {
+    #[inline]
+    fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64> {
+        self.command(missile, target, None).0
+    }
+
+    fn guide(&self, missile: &Missile, target: &Target, ctx: &mut GuidanceContext) -> Vector3<f64> {
+        let drag = ctx.airframe.map(|airframe| (airframe, ctx.environment));
+        let (command, solution) = self.command(missile, target, drag);
+        match solution {
+            Some((point, time)) => {
+                ctx.state.set_vector("pip", point);
+                ctx.state.set_scalar("pip_time", time);
+            }
+            None => {
+                ctx.state.vectors.remove("pip");
+                ctx.state.scalars.remove("pip_time");
+            }
+        }
+        command
+    }
+
+    fn name(&self) -> &str {
+        "PIP"
+    }
+
+    fn parameters(&self) -> BTreeMap<String, f64> {
+        BTreeMap::from([("heading_gain".to_string(), self.heading_gain)])
+    }
+}
@@ -0,0 +1,122 @@
+use crate::entity::{Missile, Staging, Target, divert_axes};
+use crate::guidance::{GuidanceContext, GuidanceLaw, GuidanceSpec, WrapperSpec};
+use nalgebra::Vector3;
+use std::collections::BTreeMap;
+
+/// Pulse-width modulation of another guidance law for on/off divert thrusters.
+///
+/// Every `period` the inner command is split onto the thruster axes
+/// ([`crate::entity::divert_axes`]) and each axis fires at full acceleration for
+/// `|a| / a_max · period`, so the average over the period matches the command. Pulses
+/// shorter than `min_pulse` are skipped and the impulse they would have given carries over
+/// to the next period, as does anything beyond a full period.
+///
+/// `a_max` is the per-axis acceleration of the missile's [`crate::entity::DivertThrusters`]
+/// once the kill vehicle is released, `max_acceleration` for missiles without stages.
+/// Booster stages fly the inner command unchanged.
+pub struct PulseWidthModulation {
+    inner: Box<dyn GuidanceLaw>,
+    period: f64,
+    min_pulse: f64,
+    name: String,
+}
+
+impl PulseWidthModulation {
+    /// Modulates `inner` over periods of `period` seconds, without a minimum pulse.
+    pub fn new(inner: Box<dyn GuidanceLaw>, period: f64) -> Self {
+        let name = format!("{}+PWM", inner.name());
+        Self {
+            inner,
+            period: period.max(1e-6),
+            min_pulse: 0.0,
+            name,
+        }
+    }
+
+    /// Sets the shortest pulse (s), usually the thrusters' own minimum.
+    pub fn with_min_pulse(mut self, min_pulse: f64) -> Self {
+        self.min_pulse = min_pulse.max(0.0);
+        self
+    }
+
+    /// Returns the modulated law.
+    pub fn inner(&self) -> &dyn GuidanceLaw {
+        self.inner.as_ref()
+    }
+
+    /// Per-axis thruster acceleration, `None` while the inner command should pass through.
+    #[inline]
+    fn axis_acceleration(missile: &Missile, stages: Option<&Staging>) -> Option<f64> {
+        let full = match stages {
+            Some(stages) => stages.divert_acceleration()?,
+            None => missile.max_acceleration,
+        };
+        (full > 0.0).then_some(full)
+    }
+}
+
+impl GuidanceLaw for PulseWidthModulation {
+    /// Stateless calls cannot keep a pulse train going, they get the inner command.
+    #[inline]
+    fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64> {
+        self.inner.calculate_acceleration(missile, target)
+    }
+
+    fn guide(&self, missile: &Missile, target: &Target, ctx: &mut GuidanceContext) -> Vector3<f64> {
+        let command = self.inner.guide(missile, target, ctx);
+        let Some(full) = Self::axis_acceleration(missile, ctx.stages) else {
+            return command;
+        };
+        let axes = divert_axes(&missile.state.velocity);
+        let state = &mut *ctx.state;
+
+        // plan the pulses of a new period
+        let start = state.scalar("pwm_start");
+        let mut pulses = state.vector("pwm_pulses").unwrap_or_default();
+        let start = match start {
+            Some(start) if ctx.time < start + self.period - 0.5 * ctx.dt => start,
+            _ => {
+                let mut carry = state.vector("pwm_carry").unwrap_or_default();
+                for (i, axis) in axes.iter().enumerate() {
+                    // signed impulse wanted this period, in seconds of full thrust
+                    let wanted = command.dot(axis) / full * self.period + carry[i];
+                    let mut width = wanted.abs().min(self.period);
+                    if width < self.min_pulse {
+                        width = 0.0;
+                    }
+                    pulses[i] = width.copysign(wanted);
+                    carry[i] = (wanted - pulses[i]).clamp(-self.period, self.period);
+                }
+                state.set_vector("pwm_carry", carry);
+                state.set_vector("pwm_pulses", pulses);
+                state.set_scalar("pwm_start", ctx.time);
+                ctx.time
+            }
+        };
+
+        let elapsed = ctx.time - start;
+        axes.iter()
+            .enumerate()
+            .filter(|(i, _)| elapsed + 0.5 * ctx.dt < pulses[*i].abs())
+            .map(|(i, axis)| axis * full.copysign(pulses[i]))
+            .sum()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn parameters(&self) -> BTreeMap<String, f64> {
+        let mut parameters = self.inner.parameters();
+        parameters.insert("period".to_string(), self.period);
+        parameters.insert("min_pulse".to_string(), self.min_pulse);
+        parameters
+    }
+
+    fn spec(&self) -> GuidanceSpec {
+        self.inner.spec().with_wrapper(WrapperSpec::Pwm {
+            period: self.period,
+            min_pulse: self.min_pulse,
+        })
+    }
+}
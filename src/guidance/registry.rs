@@ -0,0 +1,150 @@
+use crate::guidance::{
+    AugmentedProportionalNavigation, BeamRider, CommandToLineOfSight, GuidanceLaw, GuidanceSpec,
+    LeadPursuit, PhasedGuidance, PredictedInterceptPoint, PureProportionalNavigation, PurePursuit,
+    ThreePoint, TrueProportionalNavigation,
+};
+use std::collections::BTreeMap;
+
+/// Builds a guidance law from its resolved parameters (defaults filled in).
+pub type GuidanceFactory =
+    dyn Fn(&BTreeMap<String, f64>) -> anyhow::Result<Box<dyn GuidanceLaw>> + Send + Sync;
+
+struct Entry {
+    name: String,
+    defaults: BTreeMap<String, f64>,
+    factory: Box<GuidanceFactory>,
+}
+
+/// Builds guidance laws from names and parameters.
+///
+/// [`GuidanceRegistry::builtin`] knows every law shipped with the crate, user crates can
+/// [`GuidanceRegistry::register`] their own. Names are case-insensitive.
+///
+/// ```
+/// use missile_sim::guidance::GuidanceRegistry;
+///
+/// let registry = GuidanceRegistry::builtin();
+/// let apn = registry.build_str("APN{time_constant=1.2}").unwrap();
+/// assert_eq!(apn.parameters()["time_constant"], 1.2);
+/// ```
+#[derive(Default)]
+pub struct GuidanceRegistry {
+    laws: BTreeMap<String, Entry>,
+}
+
+impl GuidanceRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry with all built-in laws: `PPN`, `TPN`, `APN`, `PP`, `LP`, `PIP`,
+    /// `CLOS`, `BR` and `3PT`.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register("PPN", &[], |_| Ok(Box::new(PureProportionalNavigation)))
+            .register("TPN", &[], |_| Ok(Box::new(TrueProportionalNavigation)))
+            .register("APN", &[("time_constant", 0.5)], |p| {
+                Ok(Box::new(AugmentedProportionalNavigation::new(
+                    p["time_constant"],
+                )))
+            })
+            .register("PP", &[], |_| Ok(Box::new(PurePursuit)))
+            .register("LP", &[("lead_time", 1.0)], |p| {
+                Ok(Box::new(LeadPursuit::new(p["lead_time"])))
+            })
+            .register("PIP", &[("heading_gain", 2.0)], |p| {
+                Ok(Box::new(PredictedInterceptPoint::new(p["heading_gain"])))
+            })
+            .register("CLOS", &[("bandwidth", 2.0), ("damping", 0.7)], |p| {
+                Ok(Box::new(
+                    CommandToLineOfSight::new(p["bandwidth"]).with_damping(p["damping"]),
+                ))
+            })
+            .register("BR", &[("bandwidth", 2.0), ("damping", 0.7)], |p| {
+                Ok(Box::new(
+                    BeamRider::new(p["bandwidth"]).with_damping(p["damping"]),
+                ))
+            })
+            .register("3PT", &[], |_| Ok(Box::new(ThreePoint)));
+        registry
+    }
+
+    /// Registers a law under `name`, replacing any law with the same name.
+    ///
+    /// `defaults` lists every parameter the law accepts with its default value. The factory
+    /// always receives all of them, with the values given by the caller where set.
+    pub fn register<F>(&mut self, name: &str, defaults: &[(&str, f64)], factory: F) -> &mut Self
+    where
+        F: Fn(&BTreeMap<String, f64>) -> anyhow::Result<Box<dyn GuidanceLaw>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.laws.insert(
+            name.to_ascii_uppercase(),
+            Entry {
+                name: name.to_string(),
+                defaults: defaults.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+                factory: Box::new(factory),
+            },
+        );
+        self
+    }
+
+    /// Names of the registered laws.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.laws.values().map(|e| e.name.as_str())
+    }
+
+    /// Accepted parameters of a law with their defaults.
+    pub fn defaults(&self, name: &str) -> Option<&BTreeMap<String, f64>> {
+        self.entry(name).map(|e| &e.defaults)
+    }
+
+    /// Builds the law described by `spec`, with its phases and wrappers.
+    ///
+    /// Fails on unknown laws and on parameters the law doesn't accept, so typos in
+    /// config files don't go unnoticed.
+    pub fn build(&self, spec: &GuidanceSpec) -> anyhow::Result<Box<dyn GuidanceLaw>> {
+        let entry = self.entry(&spec.law).ok_or_else(|| {
+            anyhow::anyhow!(
+                "unknown guidance law `{}`, expected one of: {}",
+                spec.law,
+                self.names().collect::<Vec<_>>().join(", ")
+            )
+        })?;
+
+        let mut params = entry.defaults.clone();
+        for (key, value) in &spec.params {
+            match params.get_mut(key) {
+                Some(slot) => *slot = *value,
+                None => anyhow::bail!("guidance law `{}` has no parameter `{key}`", entry.name),
+            }
+        }
+
+        let mut law = (entry.factory)(&params)?;
+        if !spec.phases.is_empty() {
+            let mut phased = PhasedGuidance::new(law);
+            for phase in &spec.phases {
+                phased = phased.then(phase.trigger, self.build(&phase.guidance)?);
+            }
+            law = Box::new(phased.with_blend_time(spec.blend_time.unwrap_or(0.0)));
+        }
+        for wrapper in &spec.wrap {
+            law = wrapper.apply(law);
+        }
+        Ok(law)
+    }
+
+    /// Parses a spec string such as `APN{time_constant=1.2}` and builds the law, see
+    /// [`GuidanceSpec`] for the compact form of phases and wrappers.
+    pub fn build_str(&self, spec: &str) -> anyhow::Result<Box<dyn GuidanceLaw>> {
+        self.build(&spec.parse()?)
+    }
+
+    fn entry(&self, name: &str) -> Option<&Entry> {
+        self.laws.get(&name.to_ascii_uppercase())
+    }
+}
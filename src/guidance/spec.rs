@@ -0,0 +1,374 @@
+use crate::guidance::{
+    GuidanceLaw, GuidanceRegistry, MinimumAltitude, PhaseTrigger, PulseWidthModulation,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A guidance law described as data, as found in scenario files, CLIs and results.
+///
+/// `law` is the registered name, case-insensitive, of a law in the
+/// [`GuidanceRegistry`] (see [`GuidanceRegistry::builtin`] for the laws shipped with the
+/// crate), and the remaining keys are the law's parameters:
+///
+/// ```toml
+/// [guidance]
+/// law = "APN"
+/// time_constant = 0.5
+/// ```
+///
+/// `phases` hands guidance over to further laws, giving a
+/// [`crate::guidance::PhasedGuidance`], and `wrap` puts wrappers around the whole, innermost
+/// first:
+///
+/// ```toml
+/// [guidance]
+/// law = "PIP"
+/// blend_time = 0.5
+/// wrap = [{ kind = "min_altitude", min_altitude = 20.0 }]
+///
+/// [[guidance.phases]]
+/// trigger = { kind = "time_to_go", time = 4.0 }
+/// law = "PPN"
+/// ```
+///
+/// The compact string form is available through [`FromStr`] and [`fmt::Display`]:
+/// `APN{time_constant=0.5}` for a single law, `>[trigger]` before each later phase, where
+/// the trigger is written `range=3000`, `time_to_go=4`, `seeker_lock`, `altitude_above=100`
+/// or `altitude_below=100` and may carry the `blend_time`, and `+PWM{...}` or
+/// `+MINALT{...}` for each wrapper. A phase that has phases or wrappers of its own goes in
+/// parentheses:
+///
+/// ```
+/// use missile_sim::guidance::GuidanceSpec;
+///
+/// let spec: GuidanceSpec = "PIP>[time_to_go=4,blend_time=0.5]PPN+MINALT{min_altitude=20}"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(spec.phases[0].guidance.law, "PPN");
+/// assert_eq!(spec.build().unwrap().name(), "PIP>PPN+MinAlt");
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GuidanceSpec {
+    /// Registered name of the guidance law, the first phase when there are `phases`.
+    pub law: String,
+    /// Later phases, each taking over once its trigger fires.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phases: Vec<PhaseSpec>,
+    /// Time (s) over which commands blend at each handover between `phases`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blend_time: Option<f64>,
+    /// Wrappers around the law and its phases, innermost first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrap: Vec<WrapperSpec>,
+    /// Numeric parameters of the law, by name.
+    #[serde(flatten)]
+    pub params: BTreeMap<String, f64>,
+}
+
+/// A later phase of a [`GuidanceSpec`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PhaseSpec {
+    /// Condition handing guidance over to this phase.
+    pub trigger: PhaseTrigger,
+    /// Law flying the phase.
+    #[serde(flatten)]
+    pub guidance: GuidanceSpec,
+}
+
+/// A wrapper around a guidance law in a [`GuidanceSpec`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WrapperSpec {
+    /// [`PulseWidthModulation`] for on/off divert thrusters.
+    Pwm {
+        /// Modulation period (s).
+        period: f64,
+        /// Shortest pulse (s).
+        #[serde(default)]
+        min_pulse: f64,
+    },
+    /// [`MinimumAltitude`] guard.
+    MinAltitude {
+        /// Floor above the ground (m).
+        min_altitude: f64,
+        /// How far ahead the guard predicts the height (s).
+        #[serde(default = "default_lookahead")]
+        lookahead: f64,
+    },
+}
+
+fn default_lookahead() -> f64 {
+    2.0
+}
+
+impl GuidanceSpec {
+    /// Creates a spec for the given law with no parameters.
+    pub fn new(law: &str) -> Self {
+        Self {
+            law: law.to_string(),
+            phases: Vec::new(),
+            blend_time: None,
+            wrap: Vec::new(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// Sets a parameter.
+    pub fn with_param(mut self, name: &str, value: f64) -> Self {
+        self.params.insert(name.to_string(), value);
+        self
+    }
+
+    /// Adds a phase flown by `guidance` once `trigger` fires.
+    pub fn then(mut self, trigger: PhaseTrigger, guidance: GuidanceSpec) -> Self {
+        self.phases.push(PhaseSpec { trigger, guidance });
+        self
+    }
+
+    /// Sets the blend time (s) at each handover between phases.
+    pub fn with_blend_time(mut self, blend_time: f64) -> Self {
+        self.blend_time = Some(blend_time);
+        self
+    }
+
+    /// Adds a wrapper around the law and everything wrapped so far.
+    pub fn with_wrapper(mut self, wrapper: WrapperSpec) -> Self {
+        self.wrap.push(wrapper);
+        self
+    }
+
+    /// Instantiates a built-in guidance law, see [`GuidanceRegistry::builtin`].
+    ///
+    /// Use [`GuidanceRegistry::build`] for custom laws.
+    pub fn build(&self) -> anyhow::Result<Box<dyn GuidanceLaw>> {
+        GuidanceRegistry::builtin().build(self)
+    }
+}
+
+impl WrapperSpec {
+    /// Wraps `inner`.
+    pub fn apply(&self, inner: Box<dyn GuidanceLaw>) -> Box<dyn GuidanceLaw> {
+        match *self {
+            Self::Pwm { period, min_pulse } => {
+                Box::new(PulseWidthModulation::new(inner, period).with_min_pulse(min_pulse))
+            }
+            Self::MinAltitude {
+                min_altitude,
+                lookahead,
+            } => Box::new(MinimumAltitude::new(inner, min_altitude).with_lookahead(lookahead)),
+        }
+    }
+
+    /// Parses `PWM{period=..,min_pulse=..}` or `MINALT{min_altitude=..,lookahead=..}`.
+    fn parse(s: &str) -> anyhow::Result<Self> {
+        let (name, mut params) = parse_law(s)?;
+        let mut take = |key: &str, default: Option<f64>| {
+            params
+                .remove(key)
+                .or(default)
+                .ok_or_else(|| anyhow::anyhow!("wrapper `{name}` needs `{key}`"))
+        };
+        let wrapper = match name.to_ascii_uppercase().as_str() {
+            "PWM" => Self::Pwm {
+                period: take("period", None)?,
+                min_pulse: take("min_pulse", Some(0.0))?,
+            },
+            "MINALT" => Self::MinAltitude {
+                min_altitude: take("min_altitude", None)?,
+                lookahead: take("lookahead", Some(default_lookahead()))?,
+            },
+            _ => anyhow::bail!("unknown guidance wrapper `{name}`, expected PWM or MINALT"),
+        };
+        if let Some(key) = params.keys().next() {
+            anyhow::bail!("guidance wrapper `{name}` has no parameter `{key}`");
+        }
+        Ok(wrapper)
+    }
+}
+
+impl fmt::Display for WrapperSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pwm { period, min_pulse } => {
+                write!(f, "PWM{{period={period},min_pulse={min_pulse}}}")
+            }
+            Self::MinAltitude {
+                min_altitude,
+                lookahead,
+            } => write!(
+                f,
+                "MINALT{{min_altitude={min_altitude},lookahead={lookahead}}}"
+            ),
+        }
+    }
+}
+
+/// Splits `s` on `separator` outside braces, brackets and parentheses.
+fn split_top_level(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0i32, 0);
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' | '[' | '(' => depth += 1,
+            '}' | ']' | ')' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// Parses `key=value` pairs separated by commas.
+fn parse_params(body: &str) -> anyhow::Result<BTreeMap<String, f64>> {
+    let mut params = BTreeMap::new();
+    for pair in body.split(',') {
+        if pair.trim().is_empty() {
+            continue;
+        }
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("expected `key=value`, got `{}`", pair.trim()))?;
+        let value: f64 = value.trim().parse().map_err(|_| {
+            anyhow::anyhow!("invalid value for `{}`: `{}`", key.trim(), value.trim())
+        })?;
+        params.insert(key.trim().to_string(), value);
+    }
+    Ok(params)
+}
+
+/// Parses `NAME` or `NAME{key=value, ...}`.
+fn parse_law(s: &str) -> anyhow::Result<(&str, BTreeMap<String, f64>)> {
+    let s = s.trim();
+    let (law, body) = match s.split_once('{') {
+        Some((law, rest)) => {
+            let body = rest
+                .strip_suffix('}')
+                .ok_or_else(|| anyhow::anyhow!("missing `}}` in guidance spec `{s}`"))?;
+            (law.trim(), body)
+        }
+        None => (s, ""),
+    };
+    if law.is_empty() {
+        anyhow::bail!("missing law name in guidance spec `{s}`");
+    }
+    Ok((law, parse_params(body)?))
+}
+
+/// Parses the inside of a `[...]` handover: the trigger, then optionally `blend_time`.
+fn parse_trigger(s: &str) -> anyhow::Result<(PhaseTrigger, Option<f64>)> {
+    let mut parts = s.split(',');
+    let first = parts.next().unwrap_or_default().trim();
+    let trigger = match first.split_once('=') {
+        None if first == "seeker_lock" => PhaseTrigger::SeekerLock,
+        None => anyhow::bail!("unknown phase trigger `{first}`"),
+        Some((kind, value)) => {
+            let value: f64 = value
+                .trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("invalid value in phase trigger `{first}`"))?;
+            match kind.trim() {
+                "range" => PhaseTrigger::Range { range: value },
+                "time_to_go" => PhaseTrigger::TimeToGo { time: value },
+                "altitude_above" => PhaseTrigger::AltitudeAbove { altitude: value },
+                "altitude_below" => PhaseTrigger::AltitudeBelow { altitude: value },
+                kind => anyhow::bail!("unknown phase trigger `{kind}`"),
+            }
+        }
+    };
+    let mut rest = parse_params(&parts.collect::<Vec<_>>().join(","))?;
+    let blend_time = rest.remove("blend_time");
+    if let Some(key) = rest.keys().next() {
+        anyhow::bail!("unexpected `{key}` in phase trigger `{s}`");
+    }
+    Ok((trigger, blend_time))
+}
+
+fn fmt_trigger(trigger: &PhaseTrigger) -> String {
+    match *trigger {
+        PhaseTrigger::Range { range } => format!("range={range}"),
+        PhaseTrigger::TimeToGo { time } => format!("time_to_go={time}"),
+        PhaseTrigger::SeekerLock => "seeker_lock".to_string(),
+        PhaseTrigger::AltitudeAbove { altitude } => format!("altitude_above={altitude}"),
+        PhaseTrigger::AltitudeBelow { altitude } => format!("altitude_below={altitude}"),
+    }
+}
+
+impl FromStr for GuidanceSpec {
+    type Err = anyhow::Error;
+
+    /// Parses the compact form, see [`GuidanceSpec`].
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let s = s.trim();
+        let mut parts = split_top_level(s, '+').into_iter();
+        let mut chain = split_top_level(parts.next().unwrap_or_default(), '>').into_iter();
+
+        let (law, params) = parse_law(chain.next().unwrap_or_default())?;
+        let mut spec = Self {
+            params,
+            ..Self::new(law)
+        };
+        for phase in chain {
+            let phase = phase.trim();
+            let (trigger, rest) = phase
+                .strip_prefix('[')
+                .and_then(|rest| rest.split_once(']'))
+                .ok_or_else(|| anyhow::anyhow!("expected `[trigger]` before phase `{phase}`"))?;
+            let (trigger, blend_time) = parse_trigger(trigger)?;
+            spec.blend_time = blend_time.or(spec.blend_time);
+
+            let rest = rest.trim();
+            let guidance = match rest.strip_prefix('(').and_then(|r| r.strip_suffix(')')) {
+                Some(nested) => nested.parse()?,
+                None => {
+                    let (law, params) = parse_law(rest)?;
+                    Self {
+                        params,
+                        ..Self::new(law)
+                    }
+                }
+            };
+            spec.phases.push(PhaseSpec { trigger, guidance });
+        }
+        for wrapper in parts {
+            spec.wrap.push(WrapperSpec::parse(wrapper)?);
+        }
+
+        Ok(spec)
+    }
+}
+
+impl fmt::Display for GuidanceSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.law)?;
+        if !self.params.is_empty() {
+            let params: Vec<_> = self
+                .params
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect();
+            write!(f, "{{{}}}", params.join(","))?;
+        }
+        for (i, phase) in self.phases.iter().enumerate() {
+            write!(f, ">[{}", fmt_trigger(&phase.trigger))?;
+            if let (0, Some(blend_time)) = (i, self.blend_time) {
+                write!(f, ",blend_time={blend_time}")?;
+            }
+            let nested = !phase.guidance.phases.is_empty() || !phase.guidance.wrap.is_empty();
+            if nested {
+                write!(f, "]({})", phase.guidance)?;
+            } else {
+                write!(f, "]{}", phase.guidance)?;
+            }
+        }
+        for wrapper in &self.wrap {
+            write!(f, "+{wrapper}")?;
+        }
+        Ok(())
+    }
+}
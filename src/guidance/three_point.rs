@@ -0,0 +1,45 @@
+use crate::entity::{Missile, Target};
+use crate::guidance::beam::Beam;
+use crate::guidance::{GuidanceContext, GuidanceLaw};
+use nalgebra::Vector3;
+
+/// Three-point guidance, the kinematic rule keeping tracker, missile and target in line.
+///
+/// The missile flies the acceleration of the beam point abreast of it, which in the plane
+/// of the engagement is the classic
+///
+/// `a_c = R_M σ̈ + 2 Ṙ_M σ̇`
+///
+/// with `R_M` the missile's range from the tracker and `σ` the beam angle, plus the tracker's
+/// own acceleration and the cancellation of gravity across the beam. There is no feedback:
+/// a missile launched along the beam stays on it, an offset is never corrected, which is
+/// what [`crate::guidance::CommandToLineOfSight`] adds.
+///
+/// The beam starts at the run's [`crate::entity::Launcher`], or at a tracker fixed at the
+/// origin for runs without one and in [`GuidanceLaw::calculate_acceleration`].
+pub struct ThreePoint;
+
+impl ThreePoint {
+    #[inline]
+    fn command(missile: &Missile, target: &Target, ctx: Option<&GuidanceContext>) -> Vector3<f64> {
+        let Some(beam) = Beam::from_context(missile, target, ctx) else {
+            return Vector3::zeros();
+        };
+        beam.command(missile, beam.feed_forward + beam.gravity_compensation(ctx))
+    }
+}
+
+impl GuidanceLaw for ThreePoint {
+    #[inline]
+    fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64> {
+        Self::command(missile, target, None)
+    }
+
+    fn guide(&self, missile: &Missile, target: &Target, ctx: &mut GuidanceContext) -> Vector3<f64> {
+        Self::command(missile, target, Some(ctx))
+    }
+
+    fn name(&self) -> &str {
+        "3PT"
+    }
+}
@@ -1,16 +1,22 @@
 use crate::entity::{Missile, Target};
+use crate::guidance::{GuidanceContext, GuidanceSpec};
 use nalgebra::Vector3;
+use std::collections::BTreeMap;
 
 /// A guidance law for missile-target engagement.
 ///
 /// Implement this trait to create custom guidance algorithms.
-/// The library provides six built-in implementations:
+/// The library provides these built-in implementations:
 ///
 /// - [`crate::guidance::PureProportionalNavigation`] (PPN)
 /// - [`crate::guidance::TrueProportionalNavigation`] (TPN)
 /// - [`crate::guidance::AugmentedProportionalNavigation`] (APN)
 /// - [`crate::guidance::PurePursuit`] (PP)
 /// - [`crate::guidance::LeadPursuit`] (LP)
+/// - [`crate::guidance::PredictedInterceptPoint`] (PIP)
+/// - [`crate::guidance::CommandToLineOfSight`] (CLOS)
+/// - [`crate::guidance::BeamRider`] (BR)
+/// - [`crate::guidance::ThreePoint`] (3PT)
 /// ```
 /// use missile_sim::prelude::*;
 /// use nalgebra::Vector3;
@@ -36,6 +42,36 @@ pub trait GuidanceLaw: Send + Sync {
     /// this to the missile's `max_acceleration`.
     fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64>;
 
+    /// Computes the acceleration command with access to the run's context.
+    ///
+    /// This is what [`crate::simulation::SimulationEngine`] calls. The default forwards to
+    /// [`GuidanceLaw::calculate_acceleration`], override it for laws that need memory
+    /// between steps, the current time or randomness.
+    #[inline]
+    fn guide(
+        &self,
+        missile: &Missile,
+        target: &Target,
+        _ctx: &mut GuidanceContext,
+    ) -> Vector3<f64> {
+        self.calculate_acceleration(missile, target)
+    }
+
     /// Returns a human-readable name for this guidance law.
     fn name(&self) -> &str;
+
+    /// Returns the law's tunable parameters by name, empty by default.
+    ///
+    /// Keys match the parameter names accepted by [`crate::guidance::GuidanceRegistry`].
+    fn parameters(&self) -> BTreeMap<String, f64> {
+        BTreeMap::new()
+    }
+
+    /// Describes this law as data, so results can record the exact configuration.
+    fn spec(&self) -> GuidanceSpec {
+        GuidanceSpec {
+            params: self.parameters(),
+            ..GuidanceSpec::new(self.name())
+        }
+    }
 }
@@ -47,6 +47,10 @@
 //! | **APN** | Augmented PN with Zero Effort Miss compensation for maneuvering targets |
 //! | **PP**  | Pure Pursuit — steers directly toward current target position |
 //! | **LP**  | Lead Pursuit — predicts intercept point and aims there |
+//! | **PIP** | Predicted Intercept Point — iterates the intercept solution, steers on heading error |
+//! | **CLOS** | Command to Line of Sight — tracker drives the missile onto its line of sight to the target |
+//! | **BR**  | Beam Rider — missile corrects its own offset from the tracker's beam |
+//! | **3PT** | Three-Point — keeps tracker, missile and target in line kinematically |
 //!
 //! ### Custom Guidance Laws
 //!
@@ -72,6 +76,83 @@
 //!
 //! ```
 //!
+//! [`guidance::PhasedGuidance`] chains laws into phases, e.g. midcourse then terminal homing,
+//! handing over on range, time to go, seeker lock or altitude with optional command blending.
+//!
+//! ### Scenario Files
+//!
+//! [`simulation::Scenario`] is serde-serializable, guidance laws included as
+//! [`guidance::GuidanceSpec`] with their phases and wrappers, along with the target's
+//! [`entity::Maneuver`] schedule and the [`entity::Seeker`] limits.
+//! [`simulation::Scenario::load`] reads JSON, TOML or YAML files
+//! (features `json`, `toml`, `yaml`) and [`simulation::load_scenario_dir`] loads a whole
+//! directory, see `scenarios/` for examples.
+//!
+//! ### Terrain and Environment
+//!
+//! Give a scenario an [`environment::Terrain`] (flat ground or a height map) and the run ends on
+//! ground impact, while terrain masking the line of sight breaks seeker lock until the target is
+//! visible again. [`guidance::MinimumAltitude`] keeps low-flying interceptors off the ground.
+//!
+//! An [`environment::Environment`] adds gravity, a standard atmosphere, wind profiles and
+//! Dryden or von Kármán gusts. Missiles with an [`entity::Airframe`] feel drag from the air
+//! they fly through, the target is pushed around by the gusts.
+//!
+//! ### 6-DOF Airframe
+//!
+//! [`simulation::Scenario::six_dof`] swaps the point-mass missile for a rigid body
+//! ([`dynamics::SixDof`]) with aerodynamic forces and moments, fin servos and a three-loop
+//! autopilot tracking the guidance command. Every guidance law drives both models, so the
+//! same scenario can be compared in 3-DOF and 6-DOF.
+//!
+//! Measured data comes in as [`core::LookupTable`]s, gridded lookups of one to four variables
+//! loaded from CSV or JSON: Mach-dependent drag on an [`entity::Airframe`], tabulated
+//! aerodynamics ([`dynamics::TabulatedAero`]) and thrust curves.
+//!
+//! ### Staged Interceptors
+//!
+//! [`simulation::Scenario::stages`] describes a multi-stage missile ([`entity::Staging`]):
+//! booster stages with their own mass, thrust, drag and acceleration limit, dropped on
+//! burnout, time or altitude, then a kill vehicle that diverts on a finite delta-v budget.
+//! Separations are recorded as events in the metrics.
+//!
+//! Above the atmosphere the kill vehicle can fly on pulsed [`entity::DivertThrusters`], and
+//! [`guidance::PulseWidthModulation`] turns any law's continuous command into thruster pulses.
+//! The delta-v spent is reported in [`simulation::EngagementSummary::divert_delta_v`].
+//!
+//! ### Datalink
+//!
+//! Long-range shots fly midcourse on the launcher's radar instead of their own seeker. A
+//! [`entity::Datalink`] on [`simulation::Scenario::datalink`] uplinks noisy target updates at a
+//! fixed interval, with latency and packet loss, and guidance sees the last one extrapolated
+//! until the seeker acquires the target inside its range.
+//!
+//! ### Command Guidance
+//!
+//! An [`entity::Launcher`] on [`simulation::Scenario::launcher`] is the launch platform and
+//! tracker of SACLOS systems, fixed or moving. [`guidance::CommandToLineOfSight`],
+//! [`guidance::BeamRider`] and [`guidance::ThreePoint`] keep the missile on its line of sight to
+//! the target, and the metrics record how far the missile strays from it
+//! ([`simulation::SimulationMetrics::beam_deviation_records`]).
+//!
+//! ### Export
+//!
+//! The [`export`] module turns metrics into tables of per-step trajectories or per-run summaries,
+//! written as CSV or, with the `arrow` feature, Apache Arrow IPC files for pandas and polars.
+//!
+//! ### Observers
+//!
+//! Implement [`simulation::SimulationObserver`] to watch a run step by step, react to events such as
+//! the fuze or termination, or stop the run early, and pass it to
+//! [`simulation::Scenario::simulate_with_observer`].
+//!
+//! ### Analysis
+//!
+//! The [`analysis`] module builds on [`simulation::Scenario::simulate`] for engagement-level studies,
+//! such as launch envelopes ([`analysis::LaunchEnvelope`]) found by bisecting launch range and
+//! guidance gain tuning ([`analysis::TuningProblem`]) with Nelder-Mead or a genetic algorithm, and
+//! linearized adjoint miss-distance analysis ([`analysis::LinearEngagement`]).
+//!
 //! ### Performance Comparison
 //!
 //! These runs showcase the performance & accuracy of the six guidance laws against a maneuvering target over 1000, 5000, and 10000 simulations.
@@ -84,19 +165,31 @@
 //! #### 10000
 //! ![10000-run](https://raw.githubusercontent.com/ronakgh97/missile-sim/refs/heads/master/assets/Summary_10000.png)
 
+pub mod analysis;
 pub mod core;
+pub mod dynamics;
 pub mod entity;
+pub mod environment;
+pub mod export;
 pub mod guidance;
 pub mod simulation;
 
 /// Re-exports of the most commonly used types for convenient `use missile_sim::prelude::*;`.
 pub mod prelude {
-    pub use crate::core::{State3D, calculate_closing_speed, calculate_los_rate};
-    pub use crate::entity::{Missile, Target};
+    pub use crate::analysis::{EnvelopeConfig, LaunchEnvelope, Optimizer, TuningProblem};
+    pub use crate::core::{SimRng, State3D, calculate_closing_speed, calculate_los_rate};
+    pub use crate::entity::{Airframe, Launcher, Missile, Target};
+    pub use crate::environment::{Environment, Terrain};
     pub use crate::guidance::{
-        AugmentedProportionalNavigation, GuidanceLaw, LeadPursuit, PureProportionalNavigation,
-        PurePursuit, TrueProportionalNavigation,
+        AugmentedProportionalNavigation, BeamRider, CommandToLineOfSight, GuidanceContext,
+        GuidanceLaw, GuidanceRegistry, GuidanceSpec, GuidanceState, LeadPursuit, MinimumAltitude,
+        PhaseTrigger, PhasedGuidance, PredictedInterceptPoint, PulseWidthModulation,
+        PureProportionalNavigation, PurePursuit, ThreePoint, TrueProportionalNavigation,
+    };
+    pub use crate::simulation::{
+        Decimation, EngagementSummary, EngineSnapshot, RecordingPolicy, Scenario, ScenarioBuilder,
+        SimulationEngine, SimulationEvent, SimulationMetrics, SimulationObserver, StepSample,
+        TerminationReason,
     };
-    pub use crate::simulation::{Scenario, ScenarioBuilder, SimulationEngine, SimulationMetrics};
     pub use nalgebra::*;
 }
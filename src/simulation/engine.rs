@@ -1,7 +1,19 @@
+use crate::core::{LocalFrame, SimRng};
 use crate::core::{calculate_closing_speed, calculate_los_rate};
-use crate::entity::{Missile, Target};
-use crate::guidance::GuidanceLaw;
+use crate::dynamics::{SixDof, SixDofState};
+use crate::entity::{
+    Airframe, Datalink, DatalinkState, Launcher, Maneuver, Missile, Seeker, Staging, Target,
+};
+use crate::environment::{Environment, GustState, Terrain};
+use crate::guidance::{GuidanceContext, GuidanceLaw, GuidanceState};
 use crate::simulation::metrics::SimulationMetrics;
+use crate::simulation::observer::{
+    SimulationEvent, SimulationObserver, StepSample, TerminationReason,
+};
+use crate::simulation::recording::RecordingPolicy;
+use crate::simulation::snapshot::EngineSnapshot;
+use nalgebra::Vector3;
+use std::ops::ControlFlow;
 
 /// The simulation engine that runs the missile-target engagement loop.
 ///
@@ -23,6 +35,50 @@ pub struct SimulationEngine {
     pub max_time: f64,
     /// Distance threshold for hit detection.
     pub hit_threshold: f64,
+    /// Record full vector histories in the metrics produced by [`SimulationEngine::run`].
+    pub record_vectors: bool,
+    /// Which steps [`SimulationEngine::run`] keeps in the metrics time series.
+    pub recording: RecordingPolicy,
+    /// Memory of the guidance law between steps.
+    pub guidance_state: GuidanceState,
+    /// Random generator for the run, seeded by [`crate::simulation::Scenario::seed`].
+    pub rng: SimRng,
+    /// Earth-fixed frame the entities move in, adding Coriolis and centrifugal accelerations.
+    /// `None` integrates in a non-rotating (inertial) frame.
+    pub earth_rotation: Option<LocalFrame>,
+    /// Ground under the engagement. Ends the run on impact and can mask the seeker.
+    pub terrain: Option<Terrain>,
+    /// Gravity, air and wind.
+    pub environment: Environment,
+    /// Mass and drag of the missile, `None` keeps its speed.
+    pub airframe: Option<Airframe>,
+    /// Booster stages and kill vehicle of the missile, replacing `airframe`.
+    pub stages: Option<Staging>,
+    /// Turbulence filter states of the missile and the target.
+    gusts: [GustState; 2],
+    /// Rigid-body airframe flying the missile instead of the point mass.
+    pub six_dof: Option<SixDof>,
+    /// Attitude, rates and fins of the 6-DOF airframe, set up on the first step.
+    six_dof_state: Option<SixDofState>,
+    /// Launch platform and tracker of command-guided missiles.
+    pub launcher: Option<Launcher>,
+    /// Target updates from the launch platform, flown on until the seeker acquires.
+    pub datalink: Option<Datalink>,
+    /// Updates in flight of the datalink.
+    uplink: DatalinkState,
+    /// Range and field of view of the seeker, `None` sees the target everywhere.
+    pub seeker: Option<Seeker>,
+    /// Target maneuver schedule, see [`crate::simulation::Scenario::maneuvers`].
+    pub maneuvers: Vec<Maneuver>,
+    /// Track guidance flies on while the seeker doesn't see the target, coasting on the last
+    /// seen or uplinked state, `None` while locked.
+    memory: Option<Target>,
+    /// Launched on the datalink with no update arrived yet, guidance holds a zero command.
+    awaiting_uplink: bool,
+    /// Ring buffer of the last 10 missile-target distances, used for termination.
+    recent_distances: [f64; 10],
+    /// Number of distances pushed into `recent_distances`.
+    distance_count: usize,
 }
 
 impl SimulationEngine {
@@ -47,28 +103,136 @@ impl SimulationEngine {
             dt,
             max_time,
             hit_threshold,
+            record_vectors: false,
+            recording: RecordingPolicy::Full,
+            guidance_state: GuidanceState::default(),
+            rng: SimRng::seed_from_u64(0),
+            earth_rotation: None,
+            terrain: None,
+            environment: Environment::default(),
+            airframe: None,
+            stages: None,
+            gusts: Default::default(),
+            six_dof: None,
+            six_dof_state: None,
+            launcher: None,
+            datalink: None,
+            uplink: DatalinkState::default(),
+            seeker: None,
+            maneuvers: Vec::new(),
+            memory: None,
+            awaiting_uplink: false,
+            recent_distances: [0.0; 10],
+            distance_count: 0,
         }
     }
 
+    /// Recreates an engine from a snapshot, see [`SimulationEngine::snapshot`].
+    pub fn from_snapshot(snapshot: EngineSnapshot) -> Self {
+        Self {
+            missile: snapshot.missile,
+            target: snapshot.target,
+            time: snapshot.time,
+            dt: snapshot.dt,
+            max_time: snapshot.max_time,
+            hit_threshold: snapshot.hit_threshold,
+            record_vectors: snapshot.record_vectors,
+            recording: snapshot.recording,
+            guidance_state: snapshot.guidance_state,
+            rng: snapshot.rng,
+            earth_rotation: snapshot.earth_rotation,
+            terrain: snapshot.terrain,
+            environment: snapshot.environment,
+            airframe: snapshot.airframe,
+            stages: snapshot.stages,
+            gusts: snapshot.gusts,
+            six_dof: snapshot.six_dof,
+            six_dof_state: snapshot.six_dof_state,
+            launcher: snapshot.launcher,
+            datalink: snapshot.datalink,
+            uplink: snapshot.uplink,
+            seeker: snapshot.seeker,
+            maneuvers: snapshot.maneuvers,
+            memory: snapshot.memory,
+            awaiting_uplink: snapshot.awaiting_uplink,
+            recent_distances: snapshot.recent_distances,
+            distance_count: snapshot.distance_count,
+        }
+    }
+
+    /// Captures the complete engine state.
+    ///
+    /// Restoring it with [`SimulationEngine::restore`] or [`SimulationEngine::from_snapshot`]
+    /// and stepping with the same guidance law reproduces the original run bit for bit.
+    /// Metrics and observers are not part of the engine and are not captured.
+    pub fn snapshot(&self) -> EngineSnapshot {
+        EngineSnapshot {
+            missile: self.missile.clone(),
+            target: self.target.clone(),
+            time: self.time,
+            dt: self.dt,
+            max_time: self.max_time,
+            hit_threshold: self.hit_threshold,
+            record_vectors: self.record_vectors,
+            recording: self.recording,
+            guidance_state: self.guidance_state.clone(),
+            rng: self.rng.clone(),
+            earth_rotation: self.earth_rotation,
+            terrain: self.terrain.clone(),
+            environment: self.environment.clone(),
+            airframe: self.airframe.clone(),
+            stages: self.stages.clone(),
+            gusts: self.gusts.clone(),
+            six_dof: self.six_dof.clone(),
+            six_dof_state: self.six_dof_state.clone(),
+            launcher: self.launcher.clone(),
+            datalink: self.datalink.clone(),
+            uplink: self.uplink.clone(),
+            seeker: self.seeker.clone(),
+            maneuvers: self.maneuvers.clone(),
+            memory: self.memory.clone(),
+            awaiting_uplink: self.awaiting_uplink,
+            recent_distances: self.recent_distances,
+            distance_count: self.distance_count,
+        }
+    }
+
+    /// Rewinds (or fast-forwards) the engine to a previously captured snapshot.
+    pub fn restore(&mut self, snapshot: &EngineSnapshot) {
+        *self = Self::from_snapshot(snapshot.clone());
+    }
+
     /// Runs the simulation to completion with the given guidance law.
     ///
     /// The loop terminates when:
-    /// - Time exceeds `max_time`
     /// - Distance drops below `hit_threshold` (hit)
+    /// - Time exceeds `max_time`
     /// - Distance increases rapidly (miss — target escaping)
+    /// - Missile or target hits the [`SimulationEngine::terrain`]
     pub fn run(&mut self, guidance: &dyn GuidanceLaw) -> SimulationMetrics {
+        self.run_with_observer(guidance, &mut ())
+    }
+
+    /// Runs the simulation to completion, reporting every step and event to `observer`
+    /// alongside the metrics.
+    ///
+    /// The observer can stop the run early by returning [`ControlFlow::Break`] from
+    /// [`SimulationObserver::on_step`], the run then ends with [`TerminationReason::Observer`].
+    pub fn run_with_observer(
+        &mut self,
+        guidance: &dyn GuidanceLaw,
+        observer: &mut dyn SimulationObserver,
+    ) -> SimulationMetrics {
         const PRE_ALLOC: f64 = 768_000.0;
         let steps = ((self.max_time / self.dt).ceil() + 1.0).min(PRE_ALLOC) as usize;
 
-        let mut metrics = SimulationMetrics::init(steps);
-        self.record_metrics(&mut metrics, 0.0);
-
-        // Step loop till terminate
-        while !self.should_terminate(&metrics) {
-            self.step(guidance, &mut metrics);
+        let mut metrics = SimulationMetrics::init(self.recording.capacity(steps, self.dt))
+            .with_recording(self.recording);
+        if self.record_vectors {
+            metrics = metrics.with_vector_histories();
         }
 
-        metrics.finalize(self.hit_threshold);
+        self.drive(guidance, &mut Fanout(&mut metrics, observer));
         metrics
     }
 
@@ -79,26 +243,311 @@ impl SimulationEngine {
     /// or process data between steps.
     #[inline(always)]
     pub fn step(&mut self, guidance: &dyn GuidanceLaw, metrics: &mut SimulationMetrics) {
-        let acceleration = guidance.calculate_acceleration(&self.missile, &self.target);
+        self.update_seeker(metrics);
+        self.update_stages(metrics);
+        self.update_maneuvers();
+        let (time, phase) = (self.time, self.guidance_state.phase);
+        let (commanded, applied) = self.advance(guidance);
+        self.record_metrics(metrics, commanded, applied);
+        self.report_phase(time, phase, metrics);
+    }
+
+    /// Full engagement loop: launch sample, steps until termination, then events and finish.
+    fn drive(&mut self, guidance: &dyn GuidanceLaw, observer: &mut dyn SimulationObserver) {
+        observer.on_start(self);
+        // a restored engine already holds the distance of the state it resumes from
+        let resumed = self.distance_count > 0;
+        let mut flow = self.sample(observer, Vector3::zeros(), Vector3::zeros(), !resumed);
+
+        // Step loop till terminate
+        let reason = loop {
+            if flow.is_break() {
+                break TerminationReason::Observer;
+            }
+            if let Some(reason) = self.termination_reason() {
+                break reason;
+            }
+            self.update_seeker(observer);
+            self.update_stages(observer);
+            self.update_maneuvers();
+            let (time, phase) = (self.time, self.guidance_state.phase);
+            let (commanded, applied) = self.advance(guidance);
+            flow = self.sample(observer, commanded, applied, true);
+            self.report_phase(time, phase, observer);
+        };
+
+        if reason == TerminationReason::Hit {
+            let distance = self.recent_distances[(self.distance_count - 1) % 10];
+            observer.on_event(self.time, &SimulationEvent::Fuze { distance });
+        }
+        observer.on_event(self.time, &SimulationEvent::Termination { reason });
+        observer.on_finish(self);
+    }
+
+    /// Whether the seeker sees the target, always `true` without terrain, datalink or
+    /// [`SimulationEngine::seeker`].
+    pub fn seeker_locked(&self) -> bool {
+        self.memory.is_none()
+    }
+
+    /// Datalink updates received and lost so far.
+    pub fn datalink_state(&self) -> &DatalinkState {
+        &self.uplink
+    }
+
+    /// Breaks or regains seeker lock depending on terrain masking, the seeker's range and
+    /// field of view and, on a datalink, its seeker range, raising lock events. Uplinked
+    /// updates replace the coasting track.
+    fn update_seeker(&mut self, observer: &mut dyn SimulationObserver) {
+        let missile = &self.missile.state.position;
+        let target = &self.target.state.position;
+        let in_range = self
+            .datalink
+            .as_ref()
+            .is_none_or(|datalink| datalink.in_seeker_range((target - missile).norm()))
+            && self
+                .seeker
+                .as_ref()
+                .is_none_or(|seeker| seeker.sees(&self.missile.state, target));
+        let visible = in_range
+            && self
+                .terrain
+                .as_ref()
+                .is_none_or(|terrain| terrain.line_of_sight(missile, target));
+
+        if !visible && self.memory.is_none() {
+            // coast on the last seen track at constant velocity
+            self.memory = Some(Target {
+                state: self.target.state.clone(),
+                acceleration: Vector3::zeros(),
+            });
+            // a missile launched on the datalink, or out of its seeker's sight, has no lock
+            // to lose
+            if self.time > 0.0 || in_range {
+                observer.on_event(self.time, &SimulationEvent::LockLost);
+            } else {
+                // nor a track until the first update arrives
+                self.awaiting_uplink = self.datalink.is_some();
+            }
+        } else if visible && self.memory.is_some() {
+            self.memory = None;
+            self.awaiting_uplink = false;
+            observer.on_event(self.time, &SimulationEvent::LockAcquired);
+        }
+
+        if let Some(datalink) = &self.datalink {
+            let update = datalink.update(
+                &mut self.uplink,
+                &self.target,
+                self.time,
+                self.dt,
+                &mut self.rng,
+            );
+            if let (Some(memory), Some(update)) = (&mut self.memory, update) {
+                *memory = update;
+                self.awaiting_uplink = false;
+            }
+        }
+    }
+
+    /// Reports motor burnout, drops the missile's active stage once its trigger fires,
+    /// raising a separation event, and sets `max_acceleration` to the limit of what flies now.
+    fn update_stages(&mut self, observer: &mut dyn SimulationObserver) {
+        // a 6-DOF motor, its clock starts with the first step
+        if let (Some(six_dof), Some(body)) = (&self.six_dof, &self.six_dof_state)
+            && let Some(burnout) = six_dof.burnout_time()
+            && body.time >= burnout
+            && body.time - self.dt < burnout
+        {
+            observer.on_event(self.time, &SimulationEvent::Burnout { stage: 0 });
+        }
+
+        let Some(stages) = &mut self.stages else {
+            return;
+        };
+        if stages.burned_out(self.dt) {
+            let stage = stages.active_index();
+            observer.on_event(self.time, &SimulationEvent::Burnout { stage });
+        }
+        while let Some(stage) = stages.separate(self.missile.state.position.z) {
+            observer.on_event(self.time, &SimulationEvent::StageSeparation { stage });
+        }
+        // guidance laws read the limit off the missile
+        self.missile.max_acceleration = stages.max_acceleration();
+    }
+
+    /// Switches the target to the maneuver starting in this step, if any.
+    #[inline(always)]
+    fn update_maneuvers(&mut self) {
+        if let Some(maneuver) = self
+            .maneuvers
+            .iter()
+            .rfind(|maneuver| maneuver.starts_within(self.time, self.dt))
+        {
+            self.target.acceleration = maneuver.acceleration;
+        }
+    }
+
+    /// Raises a phase event if guidance left `phase` during the step that started at `time`.
+    #[inline(always)]
+    fn report_phase(&self, time: f64, phase: usize, observer: &mut dyn SimulationObserver) {
+        if self.guidance_state.phase != phase {
+            let phase = self.guidance_state.phase;
+            observer.on_event(time, &SimulationEvent::GuidancePhase { phase });
+        }
+    }
+
+    /// Guidance command on the track the seeker or the datalink gives.
+    #[inline(always)]
+    fn guide(&mut self, guidance: &dyn GuidanceLaw) -> Vector3<f64> {
+        let mut ctx = GuidanceContext {
+            time: self.time,
+            dt: self.dt,
+            state: &mut self.guidance_state,
+            rng: &mut self.rng,
+            terrain: self.terrain.as_ref(),
+            environment: &self.environment,
+            launcher: self.launcher.as_ref(),
+            airframe: self.airframe.as_ref(),
+            stages: self.stages.as_ref(),
+            seeker_locked: self.memory.is_none(),
+        };
+        let seen = self.memory.as_ref().unwrap_or(&self.target);
+        guidance.guide(&self.missile, seen, &mut ctx)
+    }
+
+    /// Advances the entities by one timestep, returns the commanded and applied accelerations.
+    #[inline(always)]
+    fn advance(&mut self, guidance: &dyn GuidanceLaw) -> (Vector3<f64>, Vector3<f64>) {
+        let acceleration = if self.awaiting_uplink {
+            Vector3::zeros()
+        } else {
+            self.guide(guidance)
+        };
+        let (missile_external, target_external, missile_wind) = self.external_accelerations();
 
         // update entities with the calculated acceleration
-        self.missile.update(acceleration, self.dt);
-        self.target.update(self.dt);
+        let applied = match &self.six_dof {
+            Some(six_dof) => {
+                let command = self.missile.limit(acceleration);
+                let body = self
+                    .six_dof_state
+                    .get_or_insert_with(|| SixDofState::aligned(&self.missile.state.velocity));
+                six_dof.step(
+                    body,
+                    &mut self.missile.state,
+                    command,
+                    missile_external,
+                    missile_wind,
+                    &self.environment.atmosphere,
+                    self.dt,
+                )
+            }
+            None => {
+                let mut command = self.missile.limit(acceleration);
+                // a released kill vehicle pays for it out of its delta-v budget
+                if let Some(stages) = &mut self.stages {
+                    command = stages.spend(command, &self.missile.state.velocity, self.dt);
+                }
+                self.missile
+                    .state
+                    .update(command + missile_external, self.dt);
+                command
+            }
+        };
+        self.target.update_with_external(target_external, self.dt);
+        if let Some(launcher) = &mut self.launcher {
+            launcher.update(self.dt);
+        }
+        if let Some(stages) = &mut self.stages {
+            stages.advance(self.dt);
+        }
+        if let Some(memory) = &mut self.memory {
+            memory.update(self.dt);
+        }
         // advance time
         self.time += self.dt;
-        // record
-        self.record_metrics(metrics, acceleration.norm());
+
+        (acceleration, applied)
     }
 
+    /// Attitude, rates and fins of the 6-DOF airframe, once it has flown a step.
+    pub fn six_dof_state(&self) -> Option<&SixDofState> {
+        self.six_dof_state.as_ref()
+    }
+
+    /// Accelerations from outside the guidance loop: Earth rotation, gravity, drag and gusts,
+    /// for missile and target, plus the air velocity (wind and gusts) around the missile.
     #[inline(always)]
-    fn record_metrics(&self, metrics: &mut SimulationMetrics, accel_magnitude: f64) {
-        let los_rate_vec = calculate_los_rate(
+    fn external_accelerations(&mut self) -> (Vector3<f64>, Vector3<f64>, Vector3<f64>) {
+        let missile = &self.missile.state;
+        let target = &self.target.state;
+        let (mut missile_external, mut target_external) = match &self.earth_rotation {
+            Some(frame) => (
+                frame.rotating_acceleration(&missile.position, &missile.velocity),
+                frame.rotating_acceleration(&target.position, &target.velocity),
+            ),
+            None => (Vector3::zeros(), Vector3::zeros()),
+        };
+        missile_external += self.environment.gravity_vector();
+
+        let missile_wind = self.environment.wind_at(&missile.position);
+        let mut missile_air = missile.velocity - missile_wind;
+        if let Some(turbulence) = &self.environment.turbulence {
+            let [missile_gust, target_gust] = &mut self.gusts;
+            missile_air -= turbulence.step(missile_gust, &missile_air, self.dt, &mut self.rng);
+
+            // the target drifts with the gusts around its scripted path
+            let target_air = target.velocity - self.environment.wind_at(&target.position);
+            let previous = target_gust.velocity;
+            let gust = turbulence.step(target_gust, &target_air, self.dt, &mut self.rng);
+            target_external += (gust - previous) / self.dt;
+        }
+
+        // a 6-DOF airframe computes its own aerodynamics
+        if self.six_dof.is_none() {
+            let density = self.environment.density(&missile.position);
+            let sound = self
+                .environment
+                .atmosphere
+                .speed_of_sound(missile.position.z);
+            let air = missile.velocity - missile_wind;
+            if let Some(stages) = &self.stages {
+                missile_external += stages.propulsion(&missile.velocity, &air, density, sound);
+            } else if let Some(airframe) = &self.airframe {
+                missile_external += airframe.drag(&air, density, sound);
+            }
+        }
+
+        (missile_external, target_external, missile_wind)
+    }
+
+    #[inline(always)]
+    pub(crate) fn record_metrics(
+        &mut self,
+        metrics: &mut SimulationMetrics,
+        commanded: Vector3<f64>,
+        applied: Vector3<f64>,
+    ) {
+        let _ = self.sample(metrics, commanded, applied, true);
+    }
+
+    /// Builds the [`StepSample`] for the current state and hands it to the observer,
+    /// pushing the distance into the termination ring buffer if `track` is set.
+    #[inline(always)]
+    fn sample(
+        &mut self,
+        observer: &mut dyn SimulationObserver,
+        commanded: Vector3<f64>,
+        applied: Vector3<f64>,
+        track: bool,
+    ) -> ControlFlow<()> {
+        let los_rate = calculate_los_rate(
             &self.missile.state.position,
             &self.missile.state.velocity,
             &self.target.state.position,
             &self.target.state.velocity,
         );
-        let los_rate = los_rate_vec.norm();
 
         let closing_speed = calculate_closing_speed(
             &self.missile.state.position,
@@ -107,39 +556,90 @@ impl SimulationEngine {
             &self.target.state.velocity,
         );
 
-        metrics.record(
-            self.time,
-            self.missile.state.position,
-            self.missile.state.velocity,
-            self.target.state.position,
-            self.target.state.velocity,
-            accel_magnitude,
+        if track {
+            let distance = (self.target.state.position - self.missile.state.position).norm();
+            self.recent_distances[self.distance_count % 10] = distance;
+            self.distance_count += 1;
+        }
+
+        observer.on_step(&StepSample {
+            time: self.time,
+            missile: &self.missile,
+            target: &self.target,
+            commanded,
+            applied,
             los_rate,
             closing_speed,
-        );
+            guidance: &self.guidance_state,
+            perceived: self.memory.as_ref().unwrap_or(&self.target),
+            launcher: self.launcher.as_ref(),
+        })
     }
 
-    /// Determine if the simulation should terminate
-    #[inline(always)]
-    fn should_terminate(&self, metrics: &SimulationMetrics) -> bool {
-        if self.time >= self.max_time {
-            return true;
-        }
+    /// Returns why the simulation should stop now, or `None` to keep going.
+    pub fn termination_reason(&self) -> Option<TerminationReason> {
+        if self.distance_count > 0 {
+            let distance = self.recent_distances[(self.distance_count - 1) % 10];
 
-        let distance = metrics.distance_records.last().unwrap_or(&f64::INFINITY);
+            // Hit threshold
+            if distance < self.hit_threshold {
+                return Some(TerminationReason::Hit);
+            }
 
-        // Hit threshold
-        if *distance < self.hit_threshold {
-            return true;
-        }
+            if let Some(terrain) = &self.terrain {
+                if terrain.height_above(&self.missile.state.position) < 0.0 {
+                    return Some(TerminationReason::GroundImpact);
+                }
+                if terrain.height_above(&self.target.state.position) < 0.0 {
+                    return Some(TerminationReason::TargetGroundImpact);
+                }
+            }
 
-        if metrics.distance_records.len() > 10 {
-            let recent_dist = metrics.distance_records[metrics.distance_records.len() - 10];
-            if *distance > recent_dist + 500.0 {
-                return true;
+            if self.distance_count > 10 {
+                // oldest of the last 10 distances
+                let recent_dist = self.recent_distances[self.distance_count % 10];
+                if distance > recent_dist + 500.0 {
+                    return Some(TerminationReason::Diverging);
+                }
             }
         }
 
-        false
+        if self.time >= self.max_time {
+            return Some(TerminationReason::TimeLimit);
+        }
+
+        None
+    }
+
+    /// Determine if the simulation should terminate
+    #[inline(always)]
+    pub(crate) fn should_terminate(&self) -> bool {
+        self.termination_reason().is_some()
+    }
+}
+
+/// Forwards everything to the run's metrics first, then to the user observer.
+struct Fanout<'a>(&'a mut SimulationMetrics, &'a mut dyn SimulationObserver);
+
+impl SimulationObserver for Fanout<'_> {
+    fn on_start(&mut self, engine: &SimulationEngine) {
+        self.0.on_start(engine);
+        self.1.on_start(engine);
+    }
+
+    #[inline(always)]
+    fn on_step(&mut self, step: &StepSample) -> ControlFlow<()> {
+        let _ = self.0.on_step(step);
+        self.1.on_step(step)
+    }
+
+    fn on_event(&mut self, time: f64, event: &SimulationEvent) {
+        self.0.on_event(time, event);
+        self.1.on_event(time, event);
+    }
+
+    fn on_finish(&mut self, engine: &SimulationEngine) {
+        self.0.on_finish(engine);
+        self.1.on_finish(engine);
     }
 }
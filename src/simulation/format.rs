@@ -0,0 +1,123 @@
+use crate::simulation::Scenario;
+use anyhow::Context;
+use std::path::Path;
+
+/// File formats for scenario definitions.
+///
+/// Each format sits behind the cargo feature of the same name (`json`, `toml`, `yaml`),
+/// all enabled by default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScenarioFormat {
+    /// JSON (`.json`).
+    Json,
+    /// TOML (`.toml`).
+    Toml,
+    /// YAML (`.yaml`, `.yml`).
+    Yaml,
+}
+
+impl ScenarioFormat {
+    /// Picks the format from a file extension, `None` if it isn't a scenario file.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Parses a scenario and checks it with [`Scenario::validate`].
+    pub fn parse(self, text: &str) -> anyhow::Result<Scenario> {
+        let scenario = self.deserialize(text)?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    /// Parses a scenario without checking it.
+    fn deserialize(self, text: &str) -> anyhow::Result<Scenario> {
+        match self {
+            #[cfg(feature = "json")]
+            Self::Json => Ok(serde_json::from_str(text)?),
+            #[cfg(feature = "toml")]
+            Self::Toml => Ok(toml::from_str(text)?),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => Ok(serde_yaml::from_str(text)?),
+            #[allow(unreachable_patterns)]
+            _ => {
+                let _ = text;
+                anyhow::bail!(
+                    "{self:?} scenario files need the `{}` feature",
+                    self.feature()
+                )
+            }
+        }
+    }
+
+    /// Serializes a scenario.
+    pub fn write(self, scenario: &Scenario) -> anyhow::Result<String> {
+        match self {
+            #[cfg(feature = "json")]
+            Self::Json => Ok(serde_json::to_string_pretty(scenario)?),
+            #[cfg(feature = "toml")]
+            Self::Toml => Ok(toml::to_string_pretty(scenario)?),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => Ok(serde_yaml::to_string(scenario)?),
+            #[allow(unreachable_patterns)]
+            _ => {
+                let _ = scenario;
+                anyhow::bail!(
+                    "{self:?} scenario files need the `{}` feature",
+                    self.feature()
+                )
+            }
+        }
+    }
+
+    /// Cargo feature that enables this format.
+    pub fn feature(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Toml => "toml",
+            Self::Yaml => "yaml",
+        }
+    }
+}
+
+impl Scenario {
+    /// Loads a scenario file, the format is taken from the extension.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let format = ScenarioFormat::from_path(path)
+            .ok_or_else(|| anyhow::anyhow!("unknown scenario format: {}", path.display()))?;
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        format
+            .parse(&text)
+            .with_context(|| format!("parsing {}", path.display()))
+    }
+
+    /// Saves the scenario, the format is taken from the extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let format = ScenarioFormat::from_path(path)
+            .ok_or_else(|| anyhow::anyhow!("unknown scenario format: {}", path.display()))?;
+        std::fs::write(path, format.write(self)?)
+            .with_context(|| format!("writing {}", path.display()))
+    }
+}
+
+/// Loads every scenario file (`.json`, `.toml`, `.yaml`, `.yml`) in a directory, sorted by
+/// file name. Other files are ignored, subdirectories are not searched.
+pub fn load_scenario_dir(dir: impl AsRef<Path>) -> anyhow::Result<Vec<Scenario>> {
+    let dir = dir.as_ref();
+    let mut paths = std::fs::read_dir(dir)
+        .with_context(|| format!("reading {}", dir.display()))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.retain(|p| p.is_file() && ScenarioFormat::from_path(p).is_some());
+    paths.sort();
+
+    paths.iter().map(Scenario::load).collect()
+}
@@ -1,11 +1,85 @@
+use crate::core::{STANDARD_GRAVITY, calculate_intercept_time};
+use crate::entity::{Missile, Target};
+use crate::simulation::SimulationEngine;
+use crate::simulation::observer::{EventRecord, SimulationEvent, SimulationObserver, StepSample};
+use crate::simulation::recording::{Decimation, RecordingPolicy};
 use nalgebra::Vector3;
 use serde::{Deserialize, Serialize};
+use std::ops::ControlFlow;
+
+/// Scalar post-engagement summary, accumulated at every step.
+///
+/// Values tagged "at CPA" are captured at the closest point of approach, i.e. the
+/// step where [`SimulationMetrics::miss_distance`] was reached. Angles are in radians.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct EngagementSummary {
+    /// Time of flight at CPA (s).
+    pub time_of_flight: f64,
+    /// Peak achieved missile acceleration (g).
+    pub peak_acceleration_g: f64,
+    /// RMS achieved missile acceleration over the whole run (g).
+    pub rms_acceleration_g: f64,
+    /// Total control effort, `∫|a| dt` (m/s), i.e. the delta-v spent maneuvering.
+    pub control_effort: f64,
+    /// Aspect angle at CPA: between the target velocity and the line from target to missile.
+    /// `0` means the missile arrives from the target's nose.
+    pub final_aspect_angle: f64,
+    /// Impact angle at CPA: between the missile and target velocity vectors (`π` is head-on).
+    pub impact_angle: f64,
+    /// Closing speed at CPA (m/s).
+    pub final_closing_speed: f64,
+    /// Angle between the launch velocity and the collision-course heading
+    /// (the LOS when no collision course exists).
+    pub launch_heading_error: f64,
+    /// Peak line-of-sight rate magnitude (rad/s).
+    pub peak_los_rate: f64,
+    /// Time the guidance command spent at or above `max_acceleration` (s), never counted
+    /// while the missile can't maneuver at all.
+    pub saturation_time: f64,
+    /// Missile speed at CPA (m/s).
+    pub terminal_speed: f64,
+    /// Delta-v the kill vehicle spent on divert maneuvers (m/s), see [`crate::entity::Staging`].
+    #[serde(default)]
+    pub divert_delta_v: f64,
+    /// Largest missile offset from the tracker-to-target beam (m), with a
+    /// [`crate::entity::Launcher`].
+    #[serde(default)]
+    pub peak_beam_deviation: f64,
+    /// Simulated time from the first sample to the last (s), what a run resumed from a
+    /// snapshot flew, not the time it ended at.
+    pub duration: f64,
+    /// `∫|a|² dt`, finalized into `rms_acceleration_g`.
+    #[serde(skip)]
+    accel_sq_integral: f64,
+    /// Time of the previous sample.
+    #[serde(skip)]
+    last_time: Option<f64>,
+    /// Time of the first sample.
+    #[serde(skip)]
+    start_time: f64,
+}
+
+/// One time-series sample, kept aside until the recording policy decides to store it.
+#[derive(Clone, Copy)]
+struct Sample {
+    time: f64,
+    missile_pos: Vector3<f64>,
+    missile_vel: Vector3<f64>,
+    target_pos: Vector3<f64>,
+    target_vel: Vector3<f64>,
+    commanded: Vector3<f64>,
+    applied: Vector3<f64>,
+    los_rate: Vector3<f64>,
+    closing_speed: f64,
+    beam_deviation: Option<f64>,
+}
 
 /// Time-series metrics collected during a simulation run.
 /// (Can be S/Derialize for logging or analysis using `serde`)
 ///
-/// All trajectory and state data is stored as parallel vectors indexed by timestep.
-/// After simulation completes, `hit` and `miss_distance` contain the final result.
+/// All trajectory and state data is stored as parallel vectors indexed by recorded sample,
+/// which is every timestep unless a coarser [`RecordingPolicy`] is set.
+/// After simulation completes, `hit`, `miss_distance` and `summary` contain the final result.
 #[derive(Serialize, Deserialize)]
 pub struct SimulationMetrics {
     /// Missile positions at each timestep.
@@ -26,10 +100,49 @@ pub struct SimulationMetrics {
     pub los_rate_records: Vec<f64>,
     /// Closing speed at each timestep.
     pub closing_speed_records: Vec<f64>,
+    /// Missile offset from the tracker-to-target beam at each timestep (only with a
+    /// [`crate::entity::Launcher`]).
+    #[serde(default)]
+    pub beam_deviation_records: Vec<f64>,
+    /// Commanded acceleration vectors at each timestep (only with vector histories enabled).
+    #[serde(default)]
+    pub commanded_acceleration: Vec<Vector3<f64>>,
+    /// Achieved (limited, perpendicular-projected) acceleration vectors at each timestep
+    /// (only with vector histories enabled).
+    #[serde(default)]
+    pub achieved_acceleration: Vec<Vector3<f64>>,
+    /// Line-of-sight rate vectors (rad/s) at each timestep (only with vector histories enabled).
+    #[serde(default)]
+    pub los_rate_vectors: Vec<Vector3<f64>>,
+    /// Line-of-sight unit vectors, missile to target, at each timestep
+    /// (only with vector histories enabled).
+    #[serde(default)]
+    pub los_unit_vectors: Vec<Vector3<f64>>,
     /// Whether the engagement resulted in a hit.
     pub hit: bool,
     /// Minimum distance achieved during the engagement.
     pub miss_distance: f64,
+    /// Scalar summary of the engagement.
+    #[serde(default)]
+    pub summary: EngagementSummary,
+    /// Events raised by the engine during the run, in order.
+    #[serde(default)]
+    pub events: Vec<EventRecord>,
+    /// Whether the full vector histories are recorded.
+    #[serde(skip)]
+    vector_histories: bool,
+    /// Which steps make it into the time series.
+    #[serde(skip)]
+    recording: RecordingPolicy,
+    /// Steps seen so far, recorded or not.
+    #[serde(skip)]
+    steps_seen: usize,
+    /// Next sample time for [`Decimation::Interval`].
+    #[serde(skip)]
+    next_sample_time: f64,
+    /// Latest step that wasn't recorded, flushed by `finalize`.
+    #[serde(skip)]
+    pending: Option<Sample>,
 }
 
 impl Default for SimulationMetrics {
@@ -52,11 +165,161 @@ impl SimulationMetrics {
             acceleration_records: Vec::with_capacity(capacity),
             los_rate_records: Vec::with_capacity(capacity),
             closing_speed_records: Vec::with_capacity(capacity),
+            beam_deviation_records: Vec::new(),
+            commanded_acceleration: Vec::new(),
+            achieved_acceleration: Vec::new(),
+            los_rate_vectors: Vec::new(),
+            los_unit_vectors: Vec::new(),
             hit: false,
             miss_distance: f64::INFINITY,
+            summary: EngagementSummary::default(),
+            events: Vec::new(),
+            vector_histories: false,
+            recording: RecordingPolicy::Full,
+            steps_seen: 0,
+            next_sample_time: f64::NEG_INFINITY,
+            pending: None,
         }
     }
 
+    /// Sets which steps are kept in the time series. Summary values stay exact regardless.
+    pub fn with_recording(mut self, recording: RecordingPolicy) -> Self {
+        self.recording = recording;
+        self
+    }
+
+    /// Enables recording of the full vector histories (commanded and achieved acceleration,
+    /// LOS rate and LOS unit vectors), preallocated to the same capacity as the other series.
+    ///
+    /// These are off by default to keep long runs lean.
+    pub fn with_vector_histories(mut self) -> Self {
+        let capacity = self.time_history.capacity();
+        self.commanded_acceleration.reserve(capacity);
+        self.achieved_acceleration.reserve(capacity);
+        self.los_rate_vectors.reserve(capacity);
+        self.los_unit_vectors.reserve(capacity);
+        self.vector_histories = true;
+        self
+    }
+
+    /// Updates the engagement summary and records the step.
+    #[inline]
+    pub(crate) fn record_step(&mut self, step: &StepSample) {
+        let missile = &step.missile.state;
+        let target = &step.target.state;
+        let distance = (missile.position - target.position).norm();
+        let summary = &mut self.summary;
+
+        let dt = match summary.last_time {
+            Some(last) => step.time - last,
+            None => {
+                summary.start_time = step.time;
+                summary.launch_heading_error = launch_heading_error(step.missile, step.target);
+                0.0
+            }
+        };
+        summary.last_time = Some(step.time);
+
+        let accel = step.applied.norm();
+        summary.peak_acceleration_g = summary.peak_acceleration_g.max(accel / STANDARD_GRAVITY);
+        summary.control_effort += accel * dt;
+        summary.accel_sq_integral += accel * accel * dt;
+        summary.peak_los_rate = summary.peak_los_rate.max(step.los_rate.norm());
+        let limit = step.missile.max_acceleration;
+        if limit > 0.0 && step.commanded.norm() >= limit * (1.0 - 1e-9) {
+            summary.saturation_time += dt;
+        }
+        let beam_deviation = step.launcher.map(|launcher| {
+            launcher
+                .beam_deviation(&missile.position, &target.position)
+                .norm()
+        });
+        if let Some(deviation) = beam_deviation {
+            summary.peak_beam_deviation = summary.peak_beam_deviation.max(deviation);
+        }
+
+        if distance < self.miss_distance {
+            summary.time_of_flight = step.time;
+            summary.final_closing_speed = step.closing_speed;
+            summary.terminal_speed = missile.speed();
+            summary.final_aspect_angle =
+                angle_between(&target.velocity, &(missile.position - target.position));
+            summary.impact_angle = angle_between(&missile.velocity, &target.velocity);
+            self.miss_distance = distance;
+        }
+
+        let sample = Sample {
+            time: step.time,
+            missile_pos: missile.position,
+            missile_vel: missile.velocity,
+            target_pos: target.position,
+            target_vel: target.velocity,
+            commanded: step.commanded,
+            applied: step.applied,
+            los_rate: step.los_rate,
+            closing_speed: step.closing_speed,
+            beam_deviation,
+        };
+
+        let keep = match self.recording {
+            RecordingPolicy::None => false,
+            RecordingPolicy::Full => true,
+            RecordingPolicy::EventOnly => self.steps_seen == 0,
+            RecordingPolicy::Decimated(Decimation::EveryNthStep(n)) => {
+                self.steps_seen.is_multiple_of(n.max(1))
+            }
+            RecordingPolicy::Decimated(Decimation::Interval(interval)) => {
+                if step.time >= self.next_sample_time {
+                    self.next_sample_time = step.time + interval;
+                    true
+                } else {
+                    false
+                }
+            }
+        };
+        self.steps_seen += 1;
+
+        if keep {
+            self.push_sample(&sample);
+            self.pending = None;
+        } else if self.recording != RecordingPolicy::None {
+            self.pending = Some(sample);
+        }
+    }
+
+    /// Appends one sample to the time series.
+    #[inline]
+    fn push_sample(&mut self, sample: &Sample) {
+        if self.vector_histories {
+            let range_vec = sample.target_pos - sample.missile_pos;
+            let range = range_vec.norm();
+            let los_unit = if range > 1e-6 {
+                range_vec / range
+            } else {
+                Vector3::zeros()
+            };
+
+            self.commanded_acceleration.push(sample.commanded);
+            self.achieved_acceleration.push(sample.applied);
+            self.los_rate_vectors.push(sample.los_rate);
+            self.los_unit_vectors.push(los_unit);
+        }
+        if let Some(deviation) = sample.beam_deviation {
+            self.beam_deviation_records.push(deviation);
+        }
+
+        self.record(
+            sample.time,
+            sample.missile_pos,
+            sample.missile_vel,
+            sample.target_pos,
+            sample.target_vel,
+            sample.commanded.norm(),
+            sample.los_rate.norm(),
+            sample.closing_speed,
+        );
+    }
+
     /// Records the state and metrics for a single timestep.
     /// This is called internally by the [`crate::simulation::SimulationEngine::run()`] at each step.
     #[inline]
@@ -89,10 +352,49 @@ impl SimulationMetrics {
         }
     }
 
+    /// Stores an event and, under [`RecordingPolicy::EventOnly`], the step it happened on.
+    fn record_event(&mut self, time: f64, event: &SimulationEvent) {
+        if self.recording == RecordingPolicy::EventOnly
+            && let Some(sample) = self.pending.take()
+        {
+            self.push_sample(&sample);
+        }
+        self.events.push(EventRecord {
+            time,
+            event: event.clone(),
+        });
+    }
+
     /// Finalizes the metrics by determining hit/miss based on the threshold.
     #[inline]
     pub fn finalize(&mut self, hit_threshold: f64) {
         self.hit = self.miss_distance < hit_threshold;
+
+        if let Some(sample) = self.pending.take() {
+            self.push_sample(&sample);
+        }
+
+        let summary = &mut self.summary;
+        let duration = summary.last_time.unwrap_or(0.0) - summary.start_time;
+        summary.duration = duration;
+        summary.rms_acceleration_g = if duration > 0.0 {
+            (summary.accel_sq_integral / duration).sqrt() / STANDARD_GRAVITY
+        } else {
+            0.0
+        };
+    }
+
+    /// Guidance phase active at `time`, from the [`SimulationEvent::GuidancePhase`] events.
+    pub fn phase_at(&self, time: f64) -> usize {
+        self.events
+            .iter()
+            .take_while(|record| record.time <= time)
+            .filter_map(|record| match record.event {
+                SimulationEvent::GuidancePhase { phase } => Some(phase),
+                _ => None,
+            })
+            .last()
+            .unwrap_or(0)
     }
 
     /// Returns a one-line summary of the simulation result.
@@ -100,9 +402,49 @@ impl SimulationMetrics {
     pub fn console_summary(&self) -> String {
         format!(
             "Duration: {:.2}s | Miss Distance: {:.2} | Hit: {}",
-            self.time_history.last().unwrap_or(&0.0),
+            self.summary.duration,
             self.miss_distance,
             if self.hit { "YES" } else { "NO" },
         )
     }
 }
+
+/// Angle between two vectors in radians, zero if either is negligible.
+#[inline]
+fn angle_between(a: &Vector3<f64>, b: &Vector3<f64>) -> f64 {
+    let norms = a.norm() * b.norm();
+    if norms < 1e-12 {
+        return 0.0;
+    }
+    (a.dot(b) / norms).clamp(-1.0, 1.0).acos()
+}
+
+/// Angle between the missile velocity and the collision-course heading.
+fn launch_heading_error(missile: &Missile, target: &Target) -> f64 {
+    let m = &missile.state;
+    let t = &target.state;
+    let aim = match calculate_intercept_time(&m.position, m.speed(), &t.position, &t.velocity) {
+        Some(t_intercept) => t.position + t.velocity * t_intercept - m.position,
+        None => t.position - m.position,
+    };
+    angle_between(&m.velocity, &aim)
+}
+
+impl SimulationObserver for SimulationMetrics {
+    #[inline]
+    fn on_step(&mut self, step: &StepSample) -> ControlFlow<()> {
+        self.record_step(step);
+        ControlFlow::Continue(())
+    }
+
+    fn on_event(&mut self, time: f64, event: &SimulationEvent) {
+        self.record_event(time, event);
+    }
+
+    fn on_finish(&mut self, engine: &SimulationEngine) {
+        if let Some(stages) = &engine.stages {
+            self.summary.divert_delta_v = stages.delta_v_used();
+        }
+        self.finalize(engine.hit_threshold);
+    }
+}
@@ -1,7 +1,17 @@
 mod engine;
+mod format;
 mod metrics;
+mod observer;
+mod recording;
 mod scenario;
+mod snapshot;
 
 pub use engine::SimulationEngine;
-pub use metrics::SimulationMetrics;
+pub use format::{ScenarioFormat, load_scenario_dir};
+pub use metrics::{EngagementSummary, SimulationMetrics};
+pub use observer::{
+    EventRecord, SimulationEvent, SimulationObserver, StepSample, TerminationReason,
+};
+pub use recording::{Decimation, RecordingPolicy};
 pub use scenario::{Scenario, ScenarioBuilder};
+pub use snapshot::EngineSnapshot;
@@ -0,0 +1,147 @@
+use crate::entity::{Launcher, Missile, Target};
+use crate::guidance::GuidanceState;
+use crate::simulation::SimulationEngine;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::ops::ControlFlow;
+
+/// Everything the engine knows about a single step.
+///
+/// Passed to [`SimulationObserver::on_step`] after the entities have been advanced,
+/// so `missile` and `target` hold the state at `time`.
+pub struct StepSample<'a> {
+    /// Simulation time in seconds.
+    pub time: f64,
+    /// Missile state after the step.
+    pub missile: &'a Missile,
+    /// Target state after the step.
+    pub target: &'a Target,
+    /// Acceleration commanded by the guidance law.
+    pub commanded: Vector3<f64>,
+    /// Acceleration actually applied to the missile after limiting.
+    pub applied: Vector3<f64>,
+    /// Line-of-sight rate vector (rad/s).
+    pub los_rate: Vector3<f64>,
+    /// Closing speed (m/s).
+    pub closing_speed: f64,
+    /// Guidance memory after the step, e.g. the predicted intercept point of
+    /// [`crate::guidance::PredictedInterceptPoint`].
+    pub guidance: &'a GuidanceState,
+    /// Target as guidance saw it: the true target while the seeker is locked, otherwise
+    /// the coasting track or the extrapolated [`crate::entity::Datalink`] update.
+    pub perceived: &'a Target,
+    /// Launch platform and tracker after the step, if the run has one.
+    pub launcher: Option<&'a Launcher>,
+}
+
+/// Why a run ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TerminationReason {
+    /// Distance dropped below the hit threshold.
+    Hit,
+    /// `max_time` was reached.
+    TimeLimit,
+    /// Distance grew rapidly, the target is escaping.
+    Diverging,
+    /// An observer asked the engine to stop.
+    Observer,
+    /// The missile hit the terrain.
+    GroundImpact,
+    /// The target hit the terrain.
+    TargetGroundImpact,
+}
+
+/// Discrete events raised by the engine during a run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum SimulationEvent {
+    /// The proximity fuze triggered, the missile came within the hit threshold.
+    Fuze {
+        /// Missile-target distance when the fuze triggered (m).
+        distance: f64,
+    },
+    /// Terrain blocked the line of sight, or the target left the seeker range of a missile
+    /// flying on a [`crate::entity::Datalink`]. The seeker coasts on the last seen target
+    /// track, replaced by uplinked updates when there is a datalink.
+    ///
+    /// Not raised for a missile launched on the datalink outside its seeker range, it never
+    /// had a lock to lose.
+    LockLost,
+    /// The target is visible again, or within seeker range of a missile flying on a
+    /// [`crate::entity::Datalink`], and the seeker tracks it.
+    LockAcquired,
+    /// A rocket motor burned out: the active booster stage of a [`crate::entity::Staging`],
+    /// or stage `0` for the motor of a [`crate::dynamics::SixDof`] airframe.
+    Burnout {
+        /// Index of the stage in [`crate::entity::Staging::stages`].
+        stage: usize,
+    },
+    /// A booster stage separated, the next one ignites or, after the last stage, the
+    /// kill vehicle flies on alone.
+    StageSeparation {
+        /// Index of the dropped stage in [`crate::entity::Staging::stages`].
+        stage: usize,
+    },
+    /// A [`crate::guidance::PhasedGuidance`] handed over to another phase.
+    GuidancePhase {
+        /// Index of the phase now flying.
+        phase: usize,
+    },
+    /// The run ended.
+    Termination {
+        /// Why it ended.
+        reason: TerminationReason,
+    },
+}
+
+/// An event together with the time it was raised.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// Simulation time in seconds.
+    pub time: f64,
+    /// The event.
+    pub event: SimulationEvent,
+}
+
+/// Hooks called by [`SimulationEngine`] while a run progresses.
+///
+/// All methods have empty defaults, implement only what you need. Returning
+/// [`ControlFlow::Break`] from [`SimulationObserver::on_step`] stops the run early.
+/// [`crate::simulation::SimulationMetrics`] is itself an observer.
+///
+/// ```
+/// use missile_sim::prelude::*;
+/// use missile_sim::simulation::{SimulationObserver, StepSample};
+/// use std::ops::ControlFlow;
+///
+/// /// Stops the run once the missile has pulled more than 10 g.
+/// struct GLimit;
+///
+/// impl SimulationObserver for GLimit {
+///     fn on_step(&mut self, step: &StepSample) -> ControlFlow<()> {
+///         if step.applied.norm() > 10.0 * 9.80665 {
+///             ControlFlow::Break(())
+///         } else {
+///             ControlFlow::Continue(())
+///         }
+///     }
+/// }
+/// ```
+pub trait SimulationObserver {
+    /// Called once before the first sample is taken.
+    fn on_start(&mut self, _engine: &SimulationEngine) {}
+
+    /// Called for the launch sample and after every step.
+    fn on_step(&mut self, _step: &StepSample) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Called when the engine raises an event, right after the `on_step` of that step.
+    fn on_event(&mut self, _time: f64, _event: &SimulationEvent) {}
+
+    /// Called once after the run has ended.
+    fn on_finish(&mut self, _engine: &SimulationEngine) {}
+}
+
+/// An observer that does nothing.
+impl SimulationObserver for () {}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// How much per-step data a run keeps in [`crate::simulation::SimulationMetrics`].
+///
+/// Only the time series are affected: `hit`, `miss_distance` and the
+/// [`crate::simulation::EngagementSummary`] are computed from every step at all levels.
+/// Apart from [`RecordingPolicy::None`], the final step is always kept so the recorded
+/// trajectory ends where the run did.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum RecordingPolicy {
+    /// Summary only, no time series. Best for Monte Carlo runs.
+    None,
+    /// Keep a subset of the steps.
+    Decimated(Decimation),
+    /// Keep only the launch step, the steps where an event was raised and the final step.
+    EventOnly,
+    /// Keep every step.
+    #[default]
+    Full,
+}
+
+/// Sampling rule for [`RecordingPolicy::Decimated`].
+///
+/// Serialized as `{ rule = "EveryNthStep", value = 10 }`, a plain map so every scenario
+/// file format can hold it.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "rule", content = "value")]
+pub enum Decimation {
+    /// Keep every Nth step (the first step is always kept).
+    EveryNthStep(usize),
+    /// Keep one step every given number of seconds (the first step is always kept).
+    Interval(f64),
+}
+
+impl RecordingPolicy {
+    /// Number of samples to preallocate for a run of `steps` steps of length `dt`.
+    pub(crate) fn capacity(&self, steps: usize, dt: f64) -> usize {
+        match self {
+            RecordingPolicy::None => 0,
+            RecordingPolicy::Decimated(Decimation::EveryNthStep(n)) => steps / (*n).max(1) + 2,
+            RecordingPolicy::Decimated(Decimation::Interval(interval)) => {
+                let duration = steps as f64 * dt;
+                (duration / interval.max(dt)).ceil() as usize + 2
+            }
+            RecordingPolicy::EventOnly => 2,
+            RecordingPolicy::Full => steps,
+        }
+    }
+}
@@ -1,7 +1,13 @@
-use crate::entity::{Missile, Target};
-use crate::guidance::GuidanceLaw;
+use crate::core::{Axes, LocalFrame, SimRng};
+use crate::dynamics::SixDof;
+use crate::entity::{Airframe, Datalink, Launcher, Maneuver, Missile, Seeker, Staging, Target};
+use crate::environment::{Environment, Terrain, Wind};
+use crate::guidance::{GuidanceLaw, GuidanceSpec};
 use crate::simulation::engine::SimulationEngine;
 use crate::simulation::metrics::SimulationMetrics;
+use crate::simulation::observer::SimulationObserver;
+use crate::simulation::recording::RecordingPolicy;
+use serde::{Deserialize, Serialize};
 
 /// A complete missile-target engagement scenario.
 /// Contains all configuration needed to run a simulation: missile and target
@@ -36,7 +42,10 @@ use crate::simulation::metrics::SimulationMetrics;
 ///  let metrics = scenario.simulate(&PureProportionalNavigation);
 /// }
 /// ```
-#[derive(Clone, Debug)]
+///
+/// Scenarios are serde-serializable, see [`crate::simulation::ScenarioFormat`] for files.
+/// Only `name`, `missile` and `target` are required, the rest default like the builder.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Scenario {
     /// Human-readable name for this scenario.
     pub name: String,
@@ -47,11 +56,82 @@ pub struct Scenario {
     /// Simulation timestep in seconds.
     /// Smaller values give more accuracy at the cost of performance.
     /// Typical values: 0.0001–0.01.
+    #[serde(default = "default_dt")]
     pub dt: f64,
     /// Maximum simulation duration in seconds.
+    #[serde(default = "default_total_time")]
     pub total_time: f64,
     /// Distance threshold below which the engagement is considered a hit.
+    #[serde(default = "default_hit_threshold")]
     pub hit_threshold: f64,
+    /// Record full vector histories (commanded/achieved acceleration, LOS rate and LOS unit
+    /// vectors) in the metrics. Off by default.
+    #[serde(default)]
+    pub record_vectors: bool,
+    /// Which steps are kept in the metrics time series. Summary values are exact at every level.
+    #[serde(default)]
+    pub recording: RecordingPolicy,
+    /// Seed of the run's random generator. Same seed, same run.
+    #[serde(default)]
+    pub seed: u64,
+    /// Guidance law this scenario is meant to be flown with, if any.
+    /// Used by [`Scenario::simulate_default`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guidance: Option<GuidanceSpec>,
+    /// Geodetic frame the coordinates are expressed in. `None` is a flat frame with `z` up
+    /// and no geographic anchor. NED frames (`z` down) are limited to kinematic scenarios,
+    /// without environment, terrain, airframes or stages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frame: Option<LocalFrame>,
+    /// Integrate in the rotating Earth-fixed [`Scenario::frame`], adding Coriolis and
+    /// centrifugal accelerations to missile and target. Ignored without a frame. Off by default.
+    #[serde(default)]
+    pub rotating_earth: bool,
+    /// Ground under the engagement, `None` lets entities fly through `z = 0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terrain: Option<Terrain>,
+    /// Gravity, atmosphere, wind and turbulence. Defaults to no gravity and calm ISA air.
+    #[serde(default)]
+    pub environment: Environment,
+    /// Mass and drag of the missile, `None` for a constant-speed kinematic missile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub airframe: Option<Airframe>,
+    /// Booster stages and kill vehicle of the missile, replacing `airframe` and the
+    /// missile's `max_acceleration`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stages: Option<Staging>,
+    /// Fly the missile as a rigid body with an autopilot instead of a point mass.
+    /// `None` keeps the point-mass model, so the two can be compared on one scenario.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub six_dof: Option<SixDof>,
+    /// Launch platform and tracker, the origin of the beam command guidance laws such as
+    /// [`crate::guidance::CommandToLineOfSight`] fly on. Runs with one record the missile's
+    /// deviation from the beam in the metrics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub launcher: Option<Launcher>,
+    /// Target updates from the launch platform the missile flies on until its seeker
+    /// acquires. `None` gives the seeker the target from launch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datalink: Option<Datalink>,
+    /// Range and field of view of the missile's seeker, `None` sees the target everywhere.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seeker: Option<Seeker>,
+    /// Target maneuver schedule, in order of start time. Empty keeps the target's own
+    /// acceleration for the whole run.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub maneuvers: Vec<Maneuver>,
+}
+
+fn default_dt() -> f64 {
+    0.01
+}
+
+fn default_total_time() -> f64 {
+    60.0
+}
+
+fn default_hit_threshold() -> f64 {
+    5.0
 }
 
 impl Scenario {
@@ -62,16 +142,118 @@ impl Scenario {
     /// `guidance` — Any type implementing [`GuidanceLaw`], such as one of the
     ///  built-in laws (e.g., `PureProportionalNavigation`) or a custom implementation.
     pub fn simulate(&self, guidance: &dyn GuidanceLaw) -> SimulationMetrics {
-        let mut engine = SimulationEngine {
-            missile: self.missile.clone(),
-            target: self.target.clone(),
-            time: 0.0,
-            dt: self.dt,
-            max_time: self.total_time,
-            hit_threshold: self.hit_threshold,
-        };
+        self.engine().run(guidance)
+    }
 
-        engine.run(guidance)
+    /// Simulates the scenario with its own [`Scenario::guidance`].
+    /// Returns an error if the scenario has no guidance law or it can't be built.
+    pub fn simulate_default(&self) -> anyhow::Result<SimulationMetrics> {
+        let spec = self
+            .guidance
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("scenario `{}` has no guidance law", self.name))?;
+        Ok(self.simulate(spec.build()?.as_ref()))
+    }
+
+    /// Same as [`Scenario::simulate`], also reporting steps and events to `observer`.
+    ///
+    /// See [`SimulationObserver`] for the hooks and how to stop a run early.
+    pub fn simulate_with_observer(
+        &self,
+        guidance: &dyn GuidanceLaw,
+        observer: &mut dyn SimulationObserver,
+    ) -> SimulationMetrics {
+        self.engine().run_with_observer(guidance, observer)
+    }
+
+    /// Creates an engine for this scenario.
+    pub(crate) fn engine(&self) -> SimulationEngine {
+        let mut engine = SimulationEngine::new(
+            self.missile.clone(),
+            self.target.clone(),
+            self.dt,
+            self.total_time,
+            self.hit_threshold,
+        );
+        engine.record_vectors = self.record_vectors;
+        engine.recording = self.recording;
+        engine.rng = SimRng::seed_from_u64(self.seed);
+        if self.rotating_earth {
+            engine.earth_rotation = self.frame;
+        }
+        engine.terrain = self.terrain.clone();
+        engine.environment = self.environment.clone();
+        engine.airframe = self.airframe.clone();
+        engine.stages = self.stages.clone();
+        engine.six_dof = self.six_dof.clone();
+        engine.launcher = self.launcher.clone();
+        engine.datalink = self.datalink.clone();
+        engine.seeker = self.seeker.clone();
+        engine.maneuvers = self.maneuvers.clone();
+        engine
+    }
+
+    /// Checks settings that are valid on their own but not together. Returns an error if
+    /// Earth rotation is enabled without a frame, if a [`Axes::Ned`] frame is combined with
+    /// anything that reads altitude off `+z`, if a power-law wind has no positive reference
+    /// altitude, if missile stages are empty or combined
+    /// with a 6-DOF airframe, if the datalink has no update interval or a loss
+    /// probability outside `[0, 1]`, if the seeker range or field of view isn't positive,
+    /// or if the maneuver schedule starts before launch or isn't in order.
+    ///
+    /// [`ScenarioBuilder::build`] and scenario files ([`crate::simulation::ScenarioFormat`])
+    /// run it for you.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.rotating_earth && self.frame.is_none() {
+            anyhow::bail!("rotating_earth requires a frame");
+        }
+        // gravity, air, terrain and staging take `+z` as up
+        if self.frame.is_some_and(|frame| frame.axes == Axes::Ned)
+            && (self.environment != Environment::default()
+                || self.terrain.is_some()
+                || self.airframe.is_some()
+                || self.stages.is_some()
+                || self.six_dof.is_some())
+        {
+            anyhow::bail!("environment, terrain, airframes and stages need a z-up frame, not NED");
+        }
+        if let Wind::PowerLaw {
+            reference_altitude, ..
+        } = self.environment.wind
+            && reference_altitude <= 0.0
+        {
+            anyhow::bail!("power-law wind reference altitude must be positive");
+        }
+        if let Some(stages) = &self.stages {
+            if stages.stages.is_empty() {
+                anyhow::bail!("missile stages must not be empty");
+            }
+            if self.six_dof.is_some() {
+                anyhow::bail!("missile stages cannot fly a 6-DOF airframe");
+            }
+        }
+        if let Some(datalink) = &self.datalink {
+            if datalink.update_interval <= 0.0 {
+                anyhow::bail!("datalink update interval must be positive");
+            }
+            if !(0.0..=1.0).contains(&datalink.packet_loss) {
+                anyhow::bail!("datalink packet loss must be within [0, 1]");
+            }
+        }
+        if let Some(seeker) = &self.seeker {
+            if seeker.range.is_some_and(|range| range <= 0.0) {
+                anyhow::bail!("seeker range must be positive");
+            }
+            if seeker.field_of_view.is_some_and(|fov| fov <= 0.0) {
+                anyhow::bail!("seeker field of view must be positive");
+            }
+        }
+        if self.maneuvers.first().is_some_and(|m| m.start < 0.0)
+            || self.maneuvers.windows(2).any(|w| w[1].start < w[0].start)
+        {
+            anyhow::bail!("maneuvers must start at or after launch, in order");
+        }
+        Ok(())
     }
 
     /// Creates a new [`ScenarioBuilder`] with the given name.
@@ -84,6 +266,22 @@ impl Scenario {
 /// * dt - `0.01`
 /// * total_time - `60.0`
 /// * hit_threshold - `5.0`
+/// * record_vectors - `false`
+/// * recording - [`RecordingPolicy::Full`]
+/// * seed - `0`
+/// * guidance - `None`
+/// * frame - `None`
+/// * rotating_earth - `false`
+/// * terrain - `None`
+/// * environment - [`Environment::default`]
+/// * airframe - `None`
+/// * stages - `None`
+/// * six_dof - `None`
+/// * launcher - `None`
+/// * datalink - `None`
+/// * seeker - `None`
+/// * maneuvers - empty
+#[derive(Clone)]
 pub struct ScenarioBuilder {
     name: String,
     missile: Option<Missile>,
@@ -91,6 +289,21 @@ pub struct ScenarioBuilder {
     dt: f64,
     total_time: f64,
     hit_threshold: f64,
+    record_vectors: bool,
+    recording: RecordingPolicy,
+    seed: u64,
+    guidance: Option<GuidanceSpec>,
+    frame: Option<LocalFrame>,
+    rotating_earth: bool,
+    terrain: Option<Terrain>,
+    environment: Environment,
+    airframe: Option<Airframe>,
+    stages: Option<Staging>,
+    six_dof: Option<SixDof>,
+    launcher: Option<Launcher>,
+    datalink: Option<Datalink>,
+    seeker: Option<Seeker>,
+    maneuvers: Vec<Maneuver>,
 }
 
 impl ScenarioBuilder {
@@ -100,9 +313,24 @@ impl ScenarioBuilder {
             name: name.to_string(),
             missile: None,
             target: None,
-            dt: 0.01,
-            total_time: 60.0,
-            hit_threshold: 5.0,
+            dt: default_dt(),
+            total_time: default_total_time(),
+            hit_threshold: default_hit_threshold(),
+            record_vectors: false,
+            recording: RecordingPolicy::Full,
+            seed: 0,
+            guidance: None,
+            frame: None,
+            rotating_earth: false,
+            terrain: None,
+            environment: Environment::default(),
+            airframe: None,
+            stages: None,
+            six_dof: None,
+            launcher: None,
+            datalink: None,
+            seeker: None,
+            maneuvers: Vec::new(),
         }
     }
 
@@ -136,9 +364,102 @@ impl ScenarioBuilder {
         self
     }
 
-    /// Builds the scenario. Returns an error if missile or target is missing.
+    /// Enables recording of full vector histories in the metrics.
+    pub fn record_vectors(mut self, enabled: bool) -> Self {
+        self.record_vectors = enabled;
+        self
+    }
+
+    /// Sets the recording policy for the metrics time series.
+    pub fn recording(mut self, recording: RecordingPolicy) -> Self {
+        self.recording = recording;
+        self
+    }
+
+    /// Sets the seed of the run's random generator.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the guidance law the scenario is meant to be flown with.
+    pub fn guidance(mut self, guidance: GuidanceSpec) -> Self {
+        self.guidance = Some(guidance);
+        self
+    }
+
+    /// Declares the geodetic frame of the scenario coordinates.
+    pub fn frame(mut self, frame: LocalFrame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Adds Coriolis and centrifugal accelerations of the rotating Earth, see
+    /// [`Scenario::rotating_earth`]. Requires [`ScenarioBuilder::frame`].
+    pub fn rotating_earth(mut self, enabled: bool) -> Self {
+        self.rotating_earth = enabled;
+        self
+    }
+
+    /// Sets the terrain under the engagement.
+    pub fn terrain(mut self, terrain: Terrain) -> Self {
+        self.terrain = Some(terrain);
+        self
+    }
+
+    /// Sets gravity, atmosphere, wind and turbulence.
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Gives the missile mass and drag, see [`Scenario::airframe`].
+    pub fn airframe(mut self, airframe: Airframe) -> Self {
+        self.airframe = Some(airframe);
+        self
+    }
+
+    /// Flies the missile on booster stages, see [`Scenario::stages`].
+    pub fn stages(mut self, stages: Staging) -> Self {
+        self.stages = Some(stages);
+        self
+    }
+
+    /// Flies the missile with a 6-DOF airframe, see [`Scenario::six_dof`].
+    pub fn six_dof(mut self, six_dof: SixDof) -> Self {
+        self.six_dof = Some(six_dof);
+        self
+    }
+
+    /// Sets the launch platform and tracker, see [`Scenario::launcher`].
+    pub fn launcher(mut self, launcher: Launcher) -> Self {
+        self.launcher = Some(launcher);
+        self
+    }
+
+    /// Flies the missile on target updates from the launch platform, see
+    /// [`Scenario::datalink`].
+    pub fn datalink(mut self, datalink: Datalink) -> Self {
+        self.datalink = Some(datalink);
+        self
+    }
+
+    /// Limits what the missile's seeker sees, see [`Scenario::seeker`].
+    pub fn seeker(mut self, seeker: Seeker) -> Self {
+        self.seeker = Some(seeker);
+        self
+    }
+
+    /// Adds a target maneuver to the schedule, see [`Scenario::maneuvers`].
+    pub fn maneuver(mut self, maneuver: Maneuver) -> Self {
+        self.maneuvers.push(maneuver);
+        self
+    }
+
+    /// Builds the scenario. Returns an error if missile or target is missing or the
+    /// scenario fails [`Scenario::validate`].
     pub fn build(self) -> anyhow::Result<Scenario> {
-        Ok(Scenario {
+        let scenario = Scenario {
             name: self.name,
             missile: self
                 .missile
@@ -149,6 +470,23 @@ impl ScenarioBuilder {
             dt: self.dt,
             total_time: self.total_time,
             hit_threshold: self.hit_threshold,
-        })
+            record_vectors: self.record_vectors,
+            recording: self.recording,
+            seed: self.seed,
+            guidance: self.guidance,
+            frame: self.frame,
+            rotating_earth: self.rotating_earth,
+            terrain: self.terrain,
+            environment: self.environment,
+            airframe: self.airframe,
+            stages: self.stages,
+            six_dof: self.six_dof,
+            launcher: self.launcher,
+            datalink: self.datalink,
+            seeker: self.seeker,
+            maneuvers: self.maneuvers,
+        };
+        scenario.validate()?;
+        Ok(scenario)
     }
 }
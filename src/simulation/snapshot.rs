@@ -0,0 +1,86 @@
+use crate::core::{LocalFrame, SimRng};
+use crate::dynamics::{SixDof, SixDofState};
+use crate::entity::{
+    Airframe, Datalink, DatalinkState, Launcher, Maneuver, Missile, Seeker, Staging, Target,
+};
+use crate::environment::{Environment, GustState, Terrain};
+use crate::guidance::GuidanceState;
+use crate::simulation::recording::RecordingPolicy;
+use serde::{Deserialize, Serialize};
+
+/// Serializable copy of a [`crate::simulation::SimulationEngine`] state.
+///
+/// Taken with [`crate::simulation::SimulationEngine::snapshot`]. Useful to checkpoint
+/// long runs, branch "what-if" continuations from a mid-flight state, or rewind.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EngineSnapshot {
+    /// The missile entity.
+    pub missile: Missile,
+    /// The target entity.
+    pub target: Target,
+    /// Simulation time in seconds.
+    pub time: f64,
+    /// Timestep in seconds.
+    pub dt: f64,
+    /// Maximum simulation duration in seconds.
+    pub max_time: f64,
+    /// Distance threshold for hit detection.
+    pub hit_threshold: f64,
+    /// Whether vector histories are recorded.
+    pub record_vectors: bool,
+    /// Recording policy of the metrics.
+    pub recording: RecordingPolicy,
+    /// Memory of the guidance law.
+    pub guidance_state: GuidanceState,
+    /// Random generator state.
+    pub rng: SimRng,
+    /// Earth-fixed frame with rotation, if enabled.
+    #[serde(default)]
+    pub earth_rotation: Option<LocalFrame>,
+    /// Terrain of the run.
+    #[serde(default)]
+    pub terrain: Option<Terrain>,
+    /// Gravity, air and wind.
+    #[serde(default)]
+    pub environment: Environment,
+    /// Mass and drag of the missile, if any.
+    #[serde(default)]
+    pub airframe: Option<Airframe>,
+    /// Booster stages and kill vehicle of the missile, mid-flight.
+    #[serde(default)]
+    pub stages: Option<Staging>,
+    /// Turbulence filter states of the missile and the target.
+    #[serde(default)]
+    pub(crate) gusts: [GustState; 2],
+    /// Rigid-body airframe, if the missile flies 6-DOF.
+    #[serde(default)]
+    pub six_dof: Option<SixDof>,
+    /// Attitude, rates and fins of the 6-DOF airframe.
+    #[serde(default)]
+    pub(crate) six_dof_state: Option<SixDofState>,
+    /// Launch platform and tracker, if any.
+    #[serde(default)]
+    pub launcher: Option<Launcher>,
+    /// Datalink from the launch platform, if any.
+    #[serde(default)]
+    pub datalink: Option<Datalink>,
+    /// Datalink updates in flight.
+    #[serde(default)]
+    pub(crate) uplink: DatalinkState,
+    /// Range and field of view of the seeker, if limited.
+    #[serde(default)]
+    pub seeker: Option<Seeker>,
+    /// Target maneuver schedule.
+    #[serde(default)]
+    pub maneuvers: Vec<Maneuver>,
+    /// Target track the seeker coasts on while masked or on the datalink.
+    #[serde(default)]
+    pub(crate) memory: Option<Target>,
+    /// Whether the missile still waits for its first datalink update.
+    #[serde(default)]
+    pub(crate) awaiting_uplink: bool,
+    /// Termination ring buffer.
+    pub(crate) recent_distances: [f64; 10],
+    /// Number of distances pushed into the ring buffer.
+    pub(crate) distance_count: usize,
+}
@@ -0,0 +1,297 @@
+use missile_sim::analysis::{
+    EnvelopeConfig, ErrorSources, LaunchEnvelope, LinearEngagement, Optimizer, TuningProblem,
+};
+use missile_sim::prelude::*;
+
+fn small_envelope_config() -> EnvelopeConfig {
+    EnvelopeConfig {
+        aspect_angles: vec![0.0, std::f64::consts::PI],
+        target_altitudes: vec![5000.0],
+        min_range: 1000.0,
+        max_range: 20000.0,
+        scan_steps: 6,
+        tolerance: 200.0,
+        total_time: 20.0,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_launch_envelope_ranges_are_ordered() {
+    let config = small_envelope_config();
+    let envelope = LaunchEnvelope::compute(&config, &PureProportionalNavigation);
+
+    assert_eq!(envelope.points.len(), 2);
+    for point in &envelope.points {
+        let r_min = point.r_min.expect("expected a hit region");
+        let r_max = point.r_max.expect("expected a hit region");
+        assert!(r_min <= r_max);
+        assert!(r_min >= config.min_range && r_max <= config.max_range);
+        if let Some(r_ne) = point.r_ne {
+            assert!(r_ne <= r_max + config.tolerance);
+        }
+    }
+
+    // the fuze arming time keeps close-in launches out of the envelope
+    assert!(envelope.points[0].r_min.unwrap() > config.min_range);
+
+    // a hot target closes the gap, so it can be engaged from further out than a fleeing one
+    let hot = envelope.points[0].r_max.unwrap();
+    let cold = envelope.points[1].r_max.unwrap();
+    assert!(hot > cold);
+}
+
+#[test]
+fn test_launch_envelope_grid_shape() {
+    let config = EnvelopeConfig {
+        target_altitudes: vec![3000.0, 6000.0],
+        ..small_envelope_config()
+    };
+    let envelope = LaunchEnvelope::compute(&config, &PureProportionalNavigation);
+
+    let tables = envelope.polar_tables();
+    assert_eq!(tables.len(), 2);
+    assert!(tables.iter().all(|t| t.rows.len() == 2));
+
+    let grid = envelope.grid();
+    assert_eq!(grid.target_altitudes, vec![3000.0, 6000.0]);
+    assert_eq!(grid.r_max.len(), 2);
+    assert_eq!(grid.r_max[0].len(), 2);
+}
+
+#[test]
+fn test_launch_envelope_flies_the_base_scenario() {
+    let config = small_envelope_config();
+    // geometry is set per run, the base scenario brings the airframe and the air
+    let base = Scenario::builder("base")
+        .missile(config.missile.clone())
+        .target(Target {
+            state: State3D::init(0.0, 0.0, 0.0, 0.0, 0.0, 0.0),
+            acceleration: Vector3::zeros(),
+        })
+        .airframe(Airframe {
+            mass: 150.0,
+            reference_area: 0.03,
+            drag_coefficient: 0.3,
+            drag_table: None,
+        });
+    let drag = EnvelopeConfig {
+        base: Some(base.clone().build().unwrap()),
+        ..config.clone()
+    };
+    // gravity and a headwind on top
+    let windy = EnvelopeConfig {
+        base: Some(
+            base.environment(Environment {
+                gravity: 9.81,
+                wind: missile_sim::environment::Wind::Constant {
+                    velocity: Vector3::new(-100.0, 0.0, 0.0),
+                },
+                ..Default::default()
+            })
+            .build()
+            .unwrap(),
+        ),
+        ..config.clone()
+    };
+
+    let still = LaunchEnvelope::compute(&drag, &PureProportionalNavigation);
+    let blown = LaunchEnvelope::compute(&windy, &PureProportionalNavigation);
+    // both the plain and the no-escape runs fly into the wind
+    assert!(blown.points[1].r_max.unwrap() < still.points[1].r_max.unwrap());
+    for (a, b) in still.points.iter().zip(&blown.points) {
+        assert!(b.r_ne.unwrap() < a.r_ne.unwrap());
+    }
+}
+
+fn tuning_scenarios() -> Vec<Scenario> {
+    (0..3)
+        .map(|i| {
+            let offset = 1000.0 + 500.0 * i as f64;
+            Scenario::builder(&format!("tune_{}", i))
+                .missile(Missile {
+                    state: State3D::init(0.0, 0.0, 0.0, 600.0, 0.0, 0.0),
+                    max_acceleration: 200.0,
+                    navigation_constant: 4.0,
+                    max_closing_speed: 2000.0,
+                })
+                .target(Target {
+                    state: State3D::init(6000.0, offset, 0.0, -200.0, 0.0, 0.0),
+                    acceleration: Vector3::new(0.0, 20.0, 0.0),
+                })
+                .dt(0.01)
+                .total_time(20.0)
+                .hit_threshold(5.0)
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_nelder_mead_improves_objective() {
+    let scenarios = tuning_scenarios();
+    let problem = TuningProblem::apn(&scenarios);
+
+    let initial = problem.evaluate(&[0.5]);
+    let result = problem
+        .optimize(&Optimizer::NelderMead {
+            max_iterations: 30,
+            tolerance: 1e-6,
+        })
+        .unwrap();
+
+    let tuned = result.value("time_constant").unwrap();
+    assert!((0.1..=5.0).contains(&tuned));
+    assert!(result.objective <= initial);
+    assert!(result.evaluations > 1);
+
+    // nothing to tune is an error, not a panic
+    let empty = TuningProblem::new(&scenarios, Vec::new(), |_, _| {
+        Box::new(PureProportionalNavigation)
+    });
+    assert!(empty.optimize(&Optimizer::default()).is_err());
+}
+
+#[test]
+fn test_genetic_is_deterministic() {
+    let scenarios = tuning_scenarios();
+    let problem =
+        TuningProblem::navigation_constant(&scenarios, || Box::new(PureProportionalNavigation));
+    let optimizer = Optimizer::Genetic {
+        population: 6,
+        generations: 3,
+        mutation: 0.1,
+        seed: 7,
+    };
+
+    let a = problem.optimize(&optimizer).unwrap();
+    let b = problem.optimize(&optimizer).unwrap();
+
+    assert_eq!(
+        a.value("navigation_constant"),
+        b.value("navigation_constant")
+    );
+    assert_eq!(a.objective, b.objective);
+    // elitism never lets the best objective get worse
+    assert!(a.history.windows(2).all(|w| w[1] <= w[0]));
+}
+
+#[test]
+fn test_adjoint_matches_forward_simulation() {
+    let engagement = LinearEngagement {
+        navigation_ratio: 4.0,
+        closing_speed: 1200.0,
+        missile_speed: 900.0,
+        time_constant: 0.5,
+        flight_time: 6.0,
+        dt: 0.001,
+    };
+    let sources = ErrorSources {
+        target_maneuver: 30.0,
+        heading_error: 0.05,
+        acceleration_noise: 0.0,
+    };
+
+    let curves = engagement.adjoint(&sources);
+    for flight_time in [1.0, 2.5, 4.0, 6.0] {
+        let budget = curves.budget_at(flight_time).unwrap();
+        let forward = engagement.forward(flight_time, &sources);
+        let adjoint = budget.target_maneuver + budget.heading_error;
+        assert!(
+            (forward - adjoint).abs() < 1e-3 * (1.0 + forward.abs()),
+            "tf {}: forward {} vs adjoint {}",
+            flight_time,
+            forward,
+            adjoint
+        );
+    }
+}
+
+#[test]
+fn test_linear_miss_matches_the_simulated_one() {
+    use missile_sim::guidance::GuidanceContext;
+
+    /// TPN flown through a first-order autopilot lag, as the linear model assumes.
+    struct Lagged(f64);
+    impl GuidanceLaw for Lagged {
+        fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64> {
+            TrueProportionalNavigation.calculate_acceleration(missile, target)
+        }
+
+        fn guide(
+            &self,
+            missile: &Missile,
+            target: &Target,
+            ctx: &mut GuidanceContext,
+        ) -> Vector3<f64> {
+            let command = self.calculate_acceleration(missile, target);
+            let achieved = ctx.state.vector("achieved").unwrap_or_default();
+            let achieved = achieved + (command - achieved) * (ctx.dt / self.0);
+            ctx.state.set_vector("achieved", achieved);
+            achieved
+        }
+
+        fn name(&self) -> &str {
+            "lagged"
+        }
+    }
+
+    // head-on, the target pulls a step maneuver across the line of sight
+    let scenario = Scenario::builder("linear")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 0.0, 800.0, 0.0, 0.0),
+            max_acceleration: 1000.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 3000.0,
+        })
+        .target(Target {
+            state: State3D::init(2400.0, 0.0, 0.0, -400.0, 0.0, 0.0),
+            acceleration: Vector3::new(0.0, 30.0, 0.0),
+        })
+        .dt(0.001)
+        .total_time(10.0)
+        .hit_threshold(0.01)
+        .build()
+        .unwrap();
+    let engagement = LinearEngagement::from_scenario(&scenario, 0.5);
+    let linear = engagement.forward(
+        engagement.flight_time,
+        &ErrorSources {
+            target_maneuver: 30.0,
+            ..Default::default()
+        },
+    );
+    let simulated = scenario.simulate(&Lagged(0.5)).miss_distance;
+    // the linear model's sign is the side of the line of sight, the engine's miss a distance
+    assert!((engagement.flight_time - 2.0).abs() < 1e-9);
+    assert!(
+        (simulated - linear.abs()).abs() < 0.15 * simulated,
+        "linear {linear} vs simulated {simulated}"
+    );
+}
+
+#[test]
+fn test_adjoint_miss_decays_with_flight_time() {
+    let engagement = LinearEngagement {
+        navigation_ratio: 4.0,
+        closing_speed: 1200.0,
+        missile_speed: 900.0,
+        time_constant: 0.5,
+        flight_time: 10.0,
+        dt: 0.001,
+    };
+    let curves = engagement.adjoint(&ErrorSources {
+        target_maneuver: 30.0,
+        heading_error: 0.05,
+        acceleration_noise: 1.0,
+    });
+
+    // with flight times much longer than the autopilot lag, PN removes deterministic errors
+    let late = curves.budget_at(10.0).unwrap();
+    let early = curves.budget_at(0.5).unwrap();
+    assert!(late.heading_error.abs() < early.heading_error.abs());
+    assert!(late.target_maneuver.abs() < 0.1);
+    assert!(late.total_rss >= late.acceleration_noise);
+    assert_eq!(curves.flight_time.len(), 10_000);
+}
@@ -0,0 +1,246 @@
+use missile_sim::dynamics::{SixDof, SixDofState};
+use missile_sim::environment::Atmosphere;
+use missile_sim::prelude::*;
+
+#[test]
+fn test_autopilot_tracks_acceleration_command() {
+    let six_dof = SixDof::default();
+    let mut state = State3D::init(0.0, 0.0, 3000.0, 600.0, 0.0, 0.0);
+    let mut body = SixDofState::aligned(&state.velocity);
+    assert_eq!(body.attitude * Vector3::x(), Vector3::x());
+    // body z points down when wings level
+    assert!((body.attitude * Vector3::z() + Vector3::z()).norm() < 1e-12);
+
+    let command = Vector3::new(0.0, 50.0, 100.0);
+    let mut errors = Vec::new();
+    for _ in 0..150 {
+        let along = state.velocity.normalize();
+        let lateral = command - along * along.dot(&command);
+        let achieved = six_dof.step(
+            &mut body,
+            &mut state,
+            lateral,
+            Vector3::zeros(),
+            Vector3::zeros(),
+            &Atmosphere::Isa,
+            0.01,
+        );
+        errors.push((achieved - lateral).norm() / lateral.norm());
+    }
+
+    // tail control first pulls the wrong way, then settles within half a second
+    assert!(errors[0] > 1.0);
+    assert!(errors[60..].iter().all(|e| *e < 0.1), "{errors:?}");
+    // flying at an angle of attack, no roll
+    assert!(body.angle_of_attack > 0.1 && body.sideslip.abs() > 0.01);
+    assert!(body.angular_velocity.x.abs() < 1e-6);
+    assert!(body.mach > 1.5 && body.mach < 2.0);
+}
+
+#[test]
+fn test_six_dof_flies_the_same_scenario_as_the_point_mass() {
+    let builder = Scenario::builder("6dof")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 3000.0, 700.0, 0.0, 0.0),
+            max_acceleration: 200.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 3000.0,
+        })
+        .target(Target {
+            state: State3D::init(8000.0, 1500.0, 3500.0, -100.0, -250.0, 0.0),
+            acceleration: Vector3::new(0.0, 0.0, 30.0),
+        })
+        .total_time(30.0)
+        .hit_threshold(10.0);
+    let six_dof = SixDof {
+        thrust: 2000.0,
+        burn_time: 20.0,
+        ..SixDof::default()
+    };
+
+    let point_mass = builder.clone().build().unwrap();
+    let rigid = builder.six_dof(six_dof).build().unwrap();
+    for law in [
+        &PureProportionalNavigation as &dyn GuidanceLaw,
+        &AugmentedProportionalNavigation::default(),
+    ] {
+        let a = point_mass.simulate(law);
+        let b = rigid.simulate(law);
+        assert!(
+            a.hit && b.hit,
+            "{}: {} / {}",
+            law.name(),
+            a.miss_distance,
+            b.miss_distance
+        );
+        // autopilot lag makes the rigid body a little slower to react
+        assert_ne!(a.missile_trajectory, b.missile_trajectory);
+    }
+
+    // mid-run snapshots carry the airframe state
+    let mut engine = SimulationEngine::new(
+        rigid.missile.clone(),
+        rigid.target.clone(),
+        rigid.dt,
+        rigid.total_time,
+        rigid.hit_threshold,
+    );
+    engine.six_dof = rigid.six_dof.clone();
+    assert!(engine.six_dof_state().is_none());
+    let mut metrics = SimulationMetrics::init(100);
+    for _ in 0..200 {
+        engine.step(&PureProportionalNavigation, &mut metrics);
+    }
+    assert!(engine.six_dof_state().unwrap().angle_of_attack.abs() > 1e-3);
+
+    let snapshot = engine.snapshot();
+    let original = engine.run(&PureProportionalNavigation);
+    let replay = SimulationEngine::from_snapshot(snapshot).run(&PureProportionalNavigation);
+    assert_eq!(original.missile_trajectory, replay.missile_trajectory);
+}
+
+#[test]
+fn test_tabulated_aero_matches_linear_derivatives() {
+    use missile_sim::core::{LookupTable, TableAxis};
+    use missile_sim::dynamics::{Aerodynamics, LinearAero, TabulatedAero};
+
+    let linear = LinearAero::default();
+    let mach = vec![0.0, 1.0, 2.0, 4.0];
+    let angle = vec![0.0, 0.1, 0.2, 0.4];
+    let fin = vec![-0.4, -0.2, 0.0, 0.2, 0.4];
+    let grid = |slope_angle: f64, slope_fin: f64| {
+        let mut values = Vec::new();
+        for _ in &mach {
+            for a in &angle {
+                values.extend(fin.iter().map(|d| slope_angle * a + slope_fin * d));
+            }
+        }
+        let axes = vec![
+            TableAxis::new("mach", mach.clone()),
+            TableAxis::new("angle", angle.clone()),
+            TableAxis::new("fin", fin.clone()),
+        ];
+        LookupTable::new(axes, values).unwrap()
+    };
+    let constant = |value: f64| LookupTable::new_1d("mach", vec![0.0], vec![value]).unwrap();
+    let tabulated = Aerodynamics::Tabulated(Box::new(TabulatedAero {
+        axial: constant(linear.axial),
+        normal: grid(linear.normal_alpha, linear.normal_delta),
+        moment: grid(linear.moment_alpha, linear.moment_delta),
+        moment_damping: constant(linear.moment_damping),
+        roll_delta: linear.roll_delta,
+        roll_damping: linear.roll_damping,
+    }));
+
+    let fins = Vector3::new(0.01, 0.05, -0.1);
+    let rates = Vector3::new(0.001, -0.002, 0.003);
+    let a = Aerodynamics::Linear(linear).coefficients(1.7, [0.15, -0.05], &fins, &rates);
+    let b = tabulated.coefficients(1.7, [0.15, -0.05], &fins, &rates);
+    for (x, y) in [
+        (a.axial, b.axial),
+        (a.normal[0], b.normal[0]),
+        (a.normal[1], b.normal[1]),
+        (a.moment[0], b.moment[0]),
+        (a.moment[1], b.moment[1]),
+        (a.roll, b.roll),
+    ] {
+        assert!((x - y).abs() < 1e-9, "{x} vs {y}");
+    }
+
+    // and flies a scenario through a thrust curve
+    let six_dof = SixDof {
+        aerodynamics: tabulated,
+        thrust_curve: Some(
+            LookupTable::new_1d(
+                "time",
+                vec![0.0, 2.0, 20.0, 20.5],
+                vec![4000.0, 2000.0, 2000.0, 0.0],
+            )
+            .unwrap(),
+        ),
+        ..SixDof::default()
+    };
+    let mut state = State3D::init(0.0, 0.0, 3000.0, 600.0, 0.0, 0.0);
+    let mut body = SixDofState::aligned(&state.velocity);
+    for _ in 0..100 {
+        six_dof.step(
+            &mut body,
+            &mut state,
+            Vector3::new(0.0, 0.0, 50.0),
+            Vector3::zeros(),
+            Vector3::zeros(),
+            &Atmosphere::Isa,
+            0.01,
+        );
+    }
+    assert!(state.velocity.z > 10.0 && body.angle_of_attack > 0.0);
+    // the motor burns out at the end of its thrust curve
+    assert_eq!(six_dof.burnout_time(), Some(20.5));
+    assert_eq!(SixDof::default().burnout_time(), None);
+
+    // table shapes are checked when a model is loaded
+    let json = serde_json::to_string(&six_dof.aerodynamics).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Aerodynamics>(&json).unwrap(),
+        six_dof.aerodynamics
+    );
+    let Aerodynamics::Tabulated(aero) = &six_dof.aerodynamics else {
+        unreachable!()
+    };
+    let mut swapped = aero.as_ref().clone();
+    swapped.axial = swapped.normal.clone();
+    assert!(swapped.validate().is_err());
+    let json = serde_json::to_string(&Aerodynamics::Tabulated(Box::new(swapped))).unwrap();
+    assert!(serde_json::from_str::<Aerodynamics>(&json).is_err());
+}
+
+#[test]
+fn test_divert_thrusters_pulse_and_burn() {
+    use missile_sim::entity::{DivertState, DivertThrusters, divert_axes};
+
+    let thrusters = DivertThrusters {
+        thrust: 1000.0,
+        levels: vec![0.5, 1.0],
+        min_pulse: 0.05,
+        specific_impulse: 250.0,
+        propellant: 2.0,
+    };
+    let velocity = Vector3::new(2000.0, 0.0, 0.0);
+    let [right, up] = divert_axes(&velocity);
+    assert_eq!(right, -Vector3::y());
+    assert_eq!(up, Vector3::z());
+
+    // commands snap to the nearest level, 20 m/s² is half of the 40 m/s² full thrust
+    let mut state = DivertState::default();
+    let applied = thrusters.fire(&mut state, &(up * 22.0), &velocity, 25.0, 0.01);
+    assert_eq!(applied, up * 20.0);
+    // a pulse is held for its minimum width, then stops
+    for _ in 0..4 {
+        let held = thrusters.fire(&mut state, &Vector3::zeros(), &velocity, 25.0, 0.01);
+        assert_eq!(held, up * 20.0);
+    }
+    let off = thrusters.fire(&mut state, &Vector3::zeros(), &velocity, 25.0, 0.01);
+    assert_eq!(off, Vector3::zeros());
+    let burned = 5.0 * 500.0 / (250.0 * 9.80665) * 0.01;
+    assert!((state.propellant_used - burned).abs() < 1e-12);
+
+    // both axes firing until the tanks run dry
+    let mut steps = 0;
+    while thrusters
+        .fire(
+            &mut state,
+            &(right * 40.0 - up * 40.0),
+            &velocity,
+            25.0,
+            0.01,
+        )
+        .norm()
+        > 0.0
+    {
+        steps += 1;
+    }
+    assert_eq!(state.propellant_used, 2.0);
+    let per_step = 2000.0 / (250.0 * 9.80665) * 0.01;
+    assert_eq!(steps, ((2.0 - burned) / per_step).ceil() as usize);
+    assert_eq!(thrusters.delta_v_remaining(&state, 23.0), 0.0);
+}
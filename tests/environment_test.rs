@@ -0,0 +1,193 @@
+use missile_sim::environment::{HeightMap, Terrain};
+use nalgebra::Vector3;
+
+fn ridge() -> Terrain {
+    // 0 m plains with a 400 m ridge along y at x = 3000
+    let row = [
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 400.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    ];
+    Terrain::HeightMap(HeightMap::new([0.0, -10_000.0], 500.0, vec![row.to_vec(); 41]).unwrap())
+}
+
+#[test]
+fn test_height_map_interpolation() {
+    let map = HeightMap::from_csv("0, 10\n20, 30\n", [100.0, 200.0], 50.0).unwrap();
+    assert_eq!(map.columns, 2);
+    assert_eq!(map.rows(), 2);
+
+    assert_eq!(map.elevation(100.0, 200.0), 0.0);
+    assert_eq!(map.elevation(150.0, 250.0), 30.0);
+    assert_eq!(map.elevation(125.0, 225.0), 15.0);
+    assert_eq!(map.elevation(150.0, 225.0), 20.0);
+    // clamped outside the grid
+    assert_eq!(map.elevation(-1e6, 1e6), 20.0);
+
+    assert!(HeightMap::from_csv("0, 1\n2\n", [0.0, 0.0], 1.0).is_err());
+    assert!(HeightMap::from_csv("0, x\n", [0.0, 0.0], 1.0).is_err());
+    assert!(HeightMap::new([0.0, 0.0], 0.0, vec![vec![1.0]]).is_err());
+
+    // scenario files go through the same checks
+    let json = serde_json::to_string(&map).unwrap();
+    assert_eq!(serde_json::from_str::<HeightMap>(&json).unwrap(), map);
+    for bad in [
+        r#"{"origin":[0,0],"spacing":1,"columns":0,"heights":[]}"#,
+        r#"{"origin":[0,0],"spacing":1,"columns":2,"heights":[]}"#,
+        r#"{"origin":[0,0],"spacing":1,"columns":2,"heights":[1,2,3]}"#,
+        r#"{"origin":[0,0],"spacing":0,"columns":1,"heights":[1]}"#,
+    ] {
+        assert!(serde_json::from_str::<HeightMap>(bad).is_err(), "{bad}");
+    }
+}
+
+#[test]
+fn test_terrain_line_of_sight() {
+    let sea = Terrain::flat(0.0);
+    let low = Vector3::new(0.0, 0.0, 10.0);
+    let far = Vector3::new(20_000.0, 0.0, 5.0);
+    assert!(sea.line_of_sight(&low, &far));
+    assert!(!sea.line_of_sight(&low, &Vector3::new(1000.0, 0.0, -1.0)));
+    assert_eq!(sea.height_above(&low), 10.0);
+
+    let ridge = ridge();
+    assert_eq!(ridge.elevation(3000.0, 123.0), 400.0);
+    assert_eq!(ridge.elevation(2750.0, 0.0), 200.0);
+    assert!(!ridge.line_of_sight(
+        &Vector3::new(0.0, 0.0, 100.0),
+        &Vector3::new(6000.0, 0.0, 100.0)
+    ));
+    assert!(ridge.line_of_sight(
+        &Vector3::new(0.0, 0.0, 500.0),
+        &Vector3::new(6000.0, 0.0, 500.0)
+    ));
+}
+
+#[test]
+fn test_standard_atmosphere() {
+    use missile_sim::environment::Atmosphere;
+
+    let isa = Atmosphere::Isa;
+    assert!((isa.density(0.0) - 1.225).abs() < 1e-3);
+    assert!((isa.density(11_000.0) - 0.3639).abs() < 1e-3);
+    assert!((isa.density(20_000.0) - 0.0880).abs() < 1e-3);
+    assert!((isa.speed_of_sound(0.0) - 340.29).abs() < 0.01);
+    assert!((isa.speed_of_sound(15_000.0) - 295.07).abs() < 0.01);
+    // continuous and decaying all the way up
+    let mut previous = isa.density(-1000.0);
+    for altitude in (0..150).map(|k| k as f64 * 1000.0) {
+        let density = isa.density(altitude);
+        assert!(density < previous && density > 0.0);
+        previous = density;
+    }
+
+    assert_eq!(Atmosphere::Vacuum.density(0.0), 0.0);
+    let exponential = Atmosphere::Exponential {
+        sea_level_density: 1.2,
+        scale_height: 8000.0,
+    };
+    assert!((exponential.density(8000.0) - 1.2 / std::f64::consts::E).abs() < 1e-12);
+}
+
+#[test]
+fn test_wind_profiles() {
+    use missile_sim::environment::{Wind, WindLayer};
+
+    let at = |wind: &Wind, z: f64| wind.at(&Vector3::new(0.0, 0.0, z));
+    assert_eq!(at(&Wind::Calm, 100.0), Vector3::zeros());
+
+    let shear = Wind::PowerLaw {
+        reference_velocity: Vector3::new(10.0, 0.0, 0.0),
+        reference_altitude: 10.0,
+        exponent: 0.5,
+    };
+    assert_eq!(at(&shear, 40.0), Vector3::new(20.0, 0.0, 0.0));
+    assert_eq!(at(&shear, -5.0), Vector3::zeros());
+
+    // a power law needs a reference above the ground
+    use missile_sim::prelude::*;
+    let scenario = |reference_altitude: f64| {
+        Scenario::builder("shear")
+            .missile(Missile {
+                state: State3D::init(0.0, 0.0, 100.0, 300.0, 0.0, 0.0),
+                max_acceleration: 100.0,
+                navigation_constant: 4.0,
+                max_closing_speed: 2000.0,
+            })
+            .target(Target {
+                state: State3D::init(3000.0, 0.0, 100.0, 0.0, 0.0, 0.0),
+                acceleration: Vector3::zeros(),
+            })
+            .environment(Environment {
+                wind: Wind::PowerLaw {
+                    reference_velocity: Vector3::new(10.0, 0.0, 0.0),
+                    reference_altitude,
+                    exponent: 0.5,
+                },
+                ..Environment::default()
+            })
+            .build()
+    };
+    assert!(scenario(10.0).is_ok());
+    assert!(scenario(0.0).is_err());
+    assert!(scenario(-10.0).is_err());
+
+    let layers = Wind::Layers {
+        layers: vec![
+            WindLayer {
+                altitude: 0.0,
+                velocity: Vector3::new(5.0, 0.0, 0.0),
+            },
+            WindLayer {
+                altitude: 10_000.0,
+                velocity: Vector3::new(45.0, 10.0, 0.0),
+            },
+        ],
+    };
+    assert_eq!(at(&layers, 2500.0), Vector3::new(15.0, 2.5, 0.0));
+    assert_eq!(at(&layers, -100.0), Vector3::new(5.0, 0.0, 0.0));
+    assert_eq!(at(&layers, 30_000.0), Vector3::new(45.0, 10.0, 0.0));
+}
+
+#[test]
+fn test_turbulence_intensity() {
+    use missile_sim::core::SimRng;
+    use missile_sim::environment::{GustState, Turbulence};
+
+    for turbulence in [Turbulence::dryden(3.0), Turbulence::von_karman(3.0)] {
+        let mut rng = SimRng::seed_from_u64(7);
+        let mut gust = GustState::default();
+        let mut sum_sq = Vector3::<f64>::zeros();
+        let steps = 400_000;
+        for _ in 0..steps {
+            let v = turbulence.step(&mut gust, &Vector3::new(250.0, 0.0, 0.0), 0.01, &mut rng);
+            sum_sq += v.component_mul(&v);
+        }
+        let sigma = (sum_sq / steps as f64).map(f64::sqrt);
+        for axis in 0..3 {
+            assert!(
+                (sigma[axis] - 3.0).abs() < 0.3,
+                "{:?} axis {axis}: {}",
+                turbulence.model,
+                sigma[axis]
+            );
+        }
+
+        // gusts turn with the airspeed, the longitudinal one follows a body flying north
+        let mut east = (GustState::default(), SimRng::seed_from_u64(3));
+        let mut north = (GustState::default(), SimRng::seed_from_u64(3));
+        for _ in 0..100 {
+            let a = turbulence.step(
+                &mut east.0,
+                &Vector3::new(250.0, 0.0, 0.0),
+                0.01,
+                &mut east.1,
+            );
+            let b = turbulence.step(
+                &mut north.0,
+                &Vector3::new(0.0, 250.0, 0.0),
+                0.01,
+                &mut north.1,
+            );
+            assert!((b - Vector3::new(-a.y, a.x, a.z)).norm() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use missile_sim::export::{
+    AcmiExport, ColumnData, GeoOrigin, RunRecord, summary_table, trajectory_table,
+};
+use missile_sim::prelude::*;
+
+fn crossing() -> Scenario {
+    Scenario::builder("crossing, fast")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 0.0, 300.0, 0.0, 0.0),
+            max_acceleration: 100.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 2000.0,
+        })
+        .target(Target {
+            state: State3D::init(3000.0, 1000.0, 0.0, 0.0, 100.0, 0.0),
+            acceleration: Vector3::new(0.0, 0.0, 5.0),
+        })
+        .dt(0.01)
+        .total_time(30.0)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_trajectory_table_flattens_vectors() {
+    let scenario = crossing();
+    let metrics = scenario.simulate(&PureProportionalNavigation);
+    let table = trajectory_table(&metrics);
+
+    assert_eq!(table.rows(), metrics.time_history.len());
+    assert_eq!(table.columns.len(), 1 + 4 * 3 + 4);
+    assert_eq!(
+        table.column("missile_y"),
+        Some(&ColumnData::F64(
+            metrics.missile_trajectory.iter().map(|p| p.y).collect()
+        ))
+    );
+
+    let csv = table.to_csv();
+    let mut lines = csv.lines();
+    assert!(
+        lines
+            .next()
+            .unwrap()
+            .starts_with("time,missile_x,missile_y,missile_z,")
+    );
+    let last: Vec<f64> = csv
+        .lines()
+        .last()
+        .unwrap()
+        .split(',')
+        .map(|v| v.parse().unwrap())
+        .collect();
+    assert_eq!(last[0], *metrics.time_history.last().unwrap());
+    assert_eq!(csv.lines().count(), metrics.time_history.len() + 1);
+
+    // vector histories add their own columns
+    let mut with_vectors = scenario.clone();
+    with_vectors.record_vectors = true;
+    let table = trajectory_table(&with_vectors.simulate(&PureProportionalNavigation));
+    assert_eq!(table.columns.len(), 1 + 8 * 3 + 4);
+    assert!(table.column("commanded_a_x").is_some());
+}
+
+#[test]
+fn test_summary_table_has_one_row_per_run() {
+    let scenario = crossing();
+    let runs: Vec<RunRecord> = [
+        Box::new(PureProportionalNavigation) as Box<dyn GuidanceLaw>,
+        Box::new(AugmentedProportionalNavigation::new(0.8)),
+    ]
+    .iter()
+    .map(|law| RunRecord::new(&scenario, law.as_ref(), &scenario.simulate(law.as_ref())))
+    .collect();
+
+    let table = summary_table(&runs);
+    assert_eq!(table.rows(), 2);
+
+    let csv = table.to_csv();
+    let rows: Vec<&str> = csv.lines().collect();
+    assert!(rows[0].starts_with("scenario,guidance,hit,miss_distance,time_of_flight"));
+    // names with commas are quoted, specs keep their parameters
+    assert!(rows[2].starts_with("\"crossing, fast\",APN{time_constant=0.8},"));
+}
+
+#[test]
+fn test_table_save_rejects_unknown_formats_before_creating_files() {
+    let table = trajectory_table(&crossing().simulate(&PureProportionalNavigation));
+    let dir = std::env::temp_dir().join(format!("missile-sim-save-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let text = dir.join("x.txt");
+    assert!(table.save(&text).is_err());
+    assert!(!text.exists());
+    #[cfg(not(feature = "arrow"))]
+    {
+        let arrow = dir.join("x.arrow");
+        assert!(table.save(&arrow).is_err());
+        assert!(!arrow.exists());
+    }
+
+    let csv = dir.join("x.csv");
+    table.save(&csv).unwrap();
+    assert_eq!(std::fs::read_to_string(&csv).unwrap(), table.to_csv());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn test_arrow_ipc_round_trip() {
+    let metrics = crossing().simulate(&PureProportionalNavigation);
+    let table = trajectory_table(&metrics);
+
+    let mut buffer = Vec::new();
+    table.write_arrow_ipc(&mut buffer).unwrap();
+
+    let reader =
+        arrow_ipc::reader::FileReader::try_new(std::io::Cursor::new(buffer), None).unwrap();
+    let batches: Vec<_> = reader.map(|b| b.unwrap()).collect();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].num_rows(), table.rows());
+    assert_eq!(batches[0].num_columns(), table.columns.len());
+    assert_eq!(batches[0].schema().field(0).name(), "time");
+}
+
+#[test]
+fn test_acmi_export_objects_and_events() {
+    let metrics = crossing().simulate(&PureProportionalNavigation);
+    assert!(metrics.hit);
+
+    let origin = GeoOrigin {
+        latitude: 0.0,
+        longitude: 10.0,
+    };
+    let acmi = AcmiExport::new(origin)
+        .title("crossing")
+        .add_run("PPN", &metrics)
+        .to_acmi();
+    let lines: Vec<&str> = acmi.lines().collect();
+
+    assert_eq!(lines[0], "FileType=text/acmi/tacview");
+    assert_eq!(lines[1], "FileVersion=2.1");
+    assert!(lines.contains(&"0,ReferenceLongitude=10"));
+    assert!(lines.contains(&"#0"));
+
+    // first sample of each object declares its type and color
+    let missile = lines.iter().find(|l| l.starts_with("101,")).unwrap();
+    assert!(missile.contains("Type=Weapon+Missile,Name=PPN missile,Color=Red"));
+    let target = lines.iter().find(|l| l.starts_with("102,")).unwrap();
+    assert!(target.contains("Color=Blue"));
+    // 3000 m east of the origin on the equator
+    assert!(target.starts_with("102,T=0.0269796|0.0089932|0.00|"));
+
+    let samples = lines.iter().filter(|l| l.starts_with("101,T=")).count();
+    assert_eq!(samples, metrics.time_history.len());
+
+    let end = lines.iter().rposition(|l| l.starts_with('#')).unwrap();
+    let tail = &lines[end..];
+    assert!(tail.contains(&"0,Event=Destroyed|102|PPN: hit"));
+    assert!(tail.contains(&"-101"));
+    assert!(tail.contains(&"-102"));
+}
+
+#[test]
+fn test_acmi_export_in_geodetic_frame() {
+    use missile_sim::core::{Geodetic, LocalFrame};
+
+    let frame = LocalFrame::enu(Geodetic::new(0.0, 10.0, 0.0));
+    let metrics = crossing().simulate(&PureProportionalNavigation);
+    let acmi = AcmiExport::with_frame(frame)
+        .add_run("PPN", &metrics)
+        .to_acmi();
+
+    assert!(acmi.contains("0,ReferenceLongitude=10\n"));
+    // exact WGS-84 conversion: equatorial radius and ~0.8 m of curvature 3.2 km out
+    let target = acmi.lines().find(|l| l.starts_with("102,")).unwrap();
+    assert!(
+        target.starts_with("102,T=0.0269495|0.0090437|0.78|"),
+        "{target}"
+    );
+}
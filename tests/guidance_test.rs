@@ -170,3 +170,224 @@ fn test_lead_pursuit_guidance() {
     assert!(acceleration.norm() > 0.0);
     assert!(acceleration.norm() <= missile.max_acceleration);
 }
+
+#[test]
+fn test_registry_builds_builtin_laws_from_strings() {
+    let registry = GuidanceRegistry::builtin();
+
+    let apn = registry.build_str("apn{time_constant=1.2}").unwrap();
+    assert_eq!(apn.name(), "APN");
+    assert_eq!(apn.parameters()["time_constant"], 1.2);
+
+    // defaults are reported back too
+    let lp = registry.build_str("LP").unwrap();
+    assert_eq!(lp.spec().to_string(), "LP{lead_time=1}");
+
+    // a law's spec builds the same law again
+    let spec: GuidanceSpec = apn.spec().to_string().parse().unwrap();
+    assert_eq!(registry.build(&spec).unwrap().spec(), apn.spec());
+
+    assert!(registry.build_str("PPN{lead_time=1}").is_err());
+    assert!(registry.build_str("XYZ").is_err());
+    assert!(registry.build_str("APN{time_constant=fast}").is_err());
+    assert!(registry.build_str("APN{time_constant=1").is_err());
+}
+
+#[test]
+fn test_spec_strings_nest_phases_and_wrappers() {
+    use missile_sim::guidance::{PhaseTrigger, PulseWidthModulation, WrapperSpec};
+
+    let registry = GuidanceRegistry::builtin();
+    let law = registry
+        .build_str("PIP{heading_gain=3}>[range=3000,blend_time=0.5](PPN+PWM{period=0.05})>[seeker_lock]APN+MINALT{min_altitude=20}")
+        .unwrap();
+    assert_eq!(law.name(), "PIP>PPN+PWM>APN+MinAlt");
+
+    let spec = law.spec();
+    assert_eq!(spec.law, "PIP");
+    assert_eq!(spec.blend_time, Some(0.5));
+    assert_eq!(
+        spec.phases[0].trigger,
+        PhaseTrigger::Range { range: 3000.0 }
+    );
+    assert_eq!(
+        spec.phases[0].guidance.wrap,
+        [WrapperSpec::Pwm {
+            period: 0.05,
+            min_pulse: 0.0,
+        }]
+    );
+    assert_eq!(spec.phases[1].trigger, PhaseTrigger::SeekerLock);
+
+    // the string and the structured form give the law back
+    let parsed: GuidanceSpec = spec.to_string().parse().unwrap();
+    assert_eq!(parsed, spec);
+    let json = serde_json::to_string(&spec).unwrap();
+    assert_eq!(serde_json::from_str::<GuidanceSpec>(&json).unwrap(), spec);
+    assert_eq!(registry.build(&spec).unwrap().spec(), spec);
+
+    // wrappers built in code describe themselves the same way
+    let pwm = PulseWidthModulation::new(Box::new(PureProportionalNavigation), 0.1);
+    assert_eq!(pwm.spec().to_string(), "PPN+PWM{period=0.1,min_pulse=0}");
+
+    assert!(registry.build_str("PPN>PP").is_err());
+    assert!(registry.build_str("PPN>[range]PP").is_err());
+    assert!(registry.build_str("PPN+PWM").is_err());
+    assert!(registry.build_str("PPN+XYZ{period=1}").is_err());
+    assert!(registry.build_str("PPN+PWM{period=1,gain=2}").is_err());
+}
+
+#[test]
+fn test_registry_accepts_custom_laws() {
+    struct Scaled(f64);
+
+    impl GuidanceLaw for Scaled {
+        fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64> {
+            PureProportionalNavigation.calculate_acceleration(missile, target) * self.0
+        }
+
+        fn name(&self) -> &str {
+            "Scaled"
+        }
+
+        fn parameters(&self) -> std::collections::BTreeMap<String, f64> {
+            [("gain".to_string(), self.0)].into()
+        }
+    }
+
+    let mut registry = GuidanceRegistry::builtin();
+    registry.register("Scaled", &[("gain", 1.0)], |p| {
+        anyhow::ensure!(p["gain"] > 0.0, "gain must be positive");
+        Ok(Box::new(Scaled(p["gain"])))
+    });
+
+    assert!(registry.names().any(|n| n == "Scaled"));
+    let law = registry.build_str("scaled{gain=0.5}").unwrap();
+    assert_eq!(
+        law.spec(),
+        GuidanceSpec::new("Scaled").with_param("gain", 0.5)
+    );
+    assert!(registry.build_str("Scaled{gain=-1}").is_err());
+}
+
+#[test]
+fn test_predicted_intercept_point() {
+    use missile_sim::core::calculate_intercept_time;
+
+    let mut missile = Missile {
+        state: State3D::init(0.0, 0.0, 10_000.0, 900.0, 0.0, 0.0),
+        max_acceleration: 100.0,
+        navigation_constant: 4.0,
+        max_closing_speed: 3000.0,
+    };
+    let mut target = Target {
+        state: State3D::init(60_000.0, 20_000.0, 12_000.0, -250.0, -100.0, 0.0),
+        acceleration: Vector3::zeros(),
+    };
+    let pip = PredictedInterceptPoint::default();
+
+    // a constant-velocity target and no drag is the closed-form collision course
+    let (point, time) = pip.predict(&missile, &target, None).unwrap();
+    let expected = calculate_intercept_time(
+        &missile.state.position,
+        missile.state.speed(),
+        &target.state.position,
+        &target.state.velocity,
+    )
+    .unwrap();
+    assert!((time - expected).abs() < 1e-4);
+    assert!((point - (target.state.position + target.state.velocity * time)).norm() < 0.1);
+
+    // target acceleration and missile drag both push the point out
+    target.acceleration = Vector3::new(0.0, 0.0, 5.0);
+    let (_, accelerating) = pip.predict(&missile, &target, None).unwrap();
+    let airframe = Airframe {
+        mass: 150.0,
+        reference_area: 0.03,
+        drag_coefficient: 0.3,
+        drag_table: None,
+    };
+    let environment = Environment::default();
+    let drag = Some((&airframe, &environment));
+    let (_, dragged) = pip.predict(&missile, &target, drag).unwrap();
+    assert!(accelerating != time && dragged > accelerating);
+
+    // the command turns the velocity toward the point, perpendicular to it
+    let command = pip.calculate_acceleration(&missile, &target);
+    assert!(command.dot(&missile.state.velocity).abs() < 1e-9);
+    assert!(command.y > 0.0 && command.z > 0.0);
+
+    // the solution is kept in the run's memory
+    use missile_sim::core::SimRng;
+    let mut state = GuidanceState::default();
+    let mut rng = SimRng::seed_from_u64(0);
+    let mut guide = |missile: &Missile, state: &mut GuidanceState| {
+        let mut ctx = GuidanceContext {
+            time: 0.0,
+            dt: 0.01,
+            state,
+            rng: &mut rng,
+            terrain: None,
+            environment: &environment,
+            launcher: None,
+            airframe: Some(&airframe),
+            stages: None,
+            seeker_locked: true,
+        };
+        pip.guide(missile, &target, &mut ctx);
+    };
+    guide(&missile, &mut state);
+    assert!(state.vector("pip").is_some() && state.scalar("pip_time").is_some());
+
+    // out of reach, it falls back to aiming at the target and drops the stale point
+    missile.state.velocity = Vector3::new(100.0, 0.0, 0.0);
+    assert!(pip.predict(&missile, &target, None).is_none());
+    guide(&missile, &mut state);
+    assert!(state.vector("pip").is_none() && state.scalar("pip_time").is_none());
+
+    let law = GuidanceRegistry::builtin()
+        .build_str("PIP{heading_gain=3}")
+        .unwrap();
+    assert_eq!(law.parameters()["heading_gain"], 3.0);
+}
+
+#[test]
+fn test_command_guidance_on_the_beam() {
+    // target crossing 4 km out, the beam from the origin turns at 0.05 rad/s
+    let target = Target {
+        state: State3D::init(4000.0, 0.0, 0.0, 0.0, 200.0, 0.0),
+        acceleration: Vector3::zeros(),
+    };
+    // missile 1 km down the beam, flying out at 500 m/s and turning with it
+    let mut missile = Missile {
+        state: State3D::init(1000.0, 0.0, 0.0, 500.0, 50.0, 0.0),
+        max_acceleration: 300.0,
+        navigation_constant: 4.0,
+        max_closing_speed: 2000.0,
+    };
+
+    // on the beam it takes `2 Ṙ_M σ̇` across it to stay there, which only a beam rider
+    // doesn't know, tilted along the beam to be lateral
+    let expected = Vector3::new(-5.0, 50.0, 0.0);
+    let three_point = ThreePoint.calculate_acceleration(&missile, &target);
+    let clos = CommandToLineOfSight::default().calculate_acceleration(&missile, &target);
+    let rider = BeamRider::default().calculate_acceleration(&missile, &target);
+    assert!((three_point - expected).norm() < 1e-9, "{three_point}");
+    assert!((clos - expected).norm() < 1e-9, "{clos}");
+    assert!(clos.dot(&missile.state.velocity).abs() < 1e-9);
+    assert!(rider.norm() < 1e-9, "{rider}");
+
+    // 10 m off the beam, CLOS and the beam rider pull back, three-point doesn't notice
+    missile.state.position.z += 10.0;
+    let clos = CommandToLineOfSight::default().calculate_acceleration(&missile, &target);
+    let rider = BeamRider::default().calculate_acceleration(&missile, &target);
+    assert!((clos.z + 40.0).abs() < 1e-9 && (rider.z + 40.0).abs() < 1e-9);
+    assert_eq!(ThreePoint.calculate_acceleration(&missile, &target).z, 0.0);
+
+    let registry = GuidanceRegistry::builtin();
+    let clos = registry.build_str("CLOS{bandwidth=3}").unwrap();
+    assert_eq!(clos.parameters()["bandwidth"], 3.0);
+    assert_eq!(clos.parameters()["damping"], 0.7);
+    assert_eq!(registry.build_str("br").unwrap().name(), "BR");
+    assert_eq!(registry.build_str("3PT").unwrap().name(), "3PT");
+}
@@ -1,4 +1,5 @@
-use missile_sim::prelude::{calculate_closing_speed, calculate_los_rate};
+use missile_sim::core::calculate_intercept_time;
+use missile_sim::prelude::{SimRng, calculate_closing_speed, calculate_los_rate};
 use nalgebra::Vector3;
 
 #[test]
@@ -50,3 +51,162 @@ fn test_calculate_los_rate() {
 
     assert!((los_rate - expected_los_rate).norm() < 1e-6);
 }
+
+#[test]
+fn test_sim_rng_is_deterministic() {
+    let mut a = SimRng::seed_from_u64(42);
+    let mut b = SimRng::seed_from_u64(42);
+
+    for _ in 0..100 {
+        let x = a.uniform();
+        assert_eq!(x, b.uniform());
+        assert!((0.0..1.0).contains(&x));
+    }
+
+    let mut c = SimRng::seed_from_u64(43);
+    assert_ne!(a.next_u64(), c.next_u64());
+}
+
+#[test]
+fn test_calculate_intercept_time() {
+    let missile_pos = Vector3::new(0.0, 0.0, 0.0);
+    let target_pos = Vector3::new(1000.0, 0.0, 0.0);
+
+    // head-on: 1000 m closed at 100 + 100 m/s
+    let t = calculate_intercept_time(
+        &missile_pos,
+        100.0,
+        &target_pos,
+        &Vector3::new(-100.0, 0.0, 0.0),
+    );
+    assert!((t.unwrap() - 5.0).abs() < 1e-9);
+
+    // a faster target running away can't be caught
+    let t = calculate_intercept_time(
+        &missile_pos,
+        100.0,
+        &target_pos,
+        &Vector3::new(150.0, 0.0, 0.0),
+    );
+    assert!(t.is_none());
+}
+
+#[test]
+fn test_geodetic_ecef_round_trip() {
+    use missile_sim::core::{Geodetic, WGS84_A};
+
+    // equator / prime meridian lies on the x axis
+    let ecef = Geodetic::new(0.0, 0.0, 0.0).to_ecef();
+    assert!((ecef - Vector3::new(WGS84_A, 0.0, 0.0)).norm() < 1e-6);
+
+    for g in [
+        Geodetic::new(36.2, -115.1, 1500.0),
+        Geodetic::new(-33.9, 151.2, 12_000.0),
+        Geodetic::new(89.9999, 45.0, 300_000.0),
+    ] {
+        let back = Geodetic::from_ecef(&g.to_ecef());
+        assert!((back.latitude - g.latitude).abs() < 1e-9);
+        assert!((back.longitude - g.longitude).abs() < 1e-9);
+        assert!((back.altitude - g.altitude).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_local_frames() {
+    use missile_sim::core::{Geodetic, LocalFrame};
+
+    let origin = Geodetic::new(45.0, 10.0, 0.0);
+    let enu = LocalFrame::enu(origin);
+    let ned = LocalFrame::ned(origin);
+
+    let p = Vector3::new(1200.0, -3400.0, 5000.0);
+    assert!((enu.from_ecef(&enu.to_ecef(&p)) - p).norm() < 1e-6);
+    assert!((ned.from_geodetic(&enu.to_geodetic(&p)) - ned.from_enu(&p)).norm() < 1e-6);
+
+    // straight up is up in both frames
+    let above = Geodetic::new(45.0, 10.0, 1000.0);
+    assert!((enu.from_geodetic(&above) - Vector3::new(0.0, 0.0, 1000.0)).norm() < 1e-6);
+    assert!((ned.from_geodetic(&above) - Vector3::new(0.0, 0.0, -1000.0)).norm() < 1e-6);
+
+    // Earth curvature: 100 km east on the tangent plane is ~785 m above the ellipsoid
+    let far = enu.to_geodetic(&Vector3::new(100_000.0, 0.0, 0.0));
+    assert!((far.altitude - 785.0).abs() < 5.0);
+    assert!(far.longitude > 10.0);
+}
+
+#[test]
+fn test_rotating_frame_acceleration() {
+    use missile_sim::core::{EARTH_ROTATION_RATE, Geodetic, LocalFrame, WGS84_A};
+
+    // at the equator Earth's spin axis points north
+    let enu = LocalFrame::enu(Geodetic::new(0.0, 0.0, 0.0));
+    assert!((enu.earth_rotation() - Vector3::new(0.0, EARTH_ROTATION_RATE, 0.0)).norm() < 1e-15);
+
+    // at rest: centrifugal only, straight up
+    let at_rest = enu.rotating_acceleration(&Vector3::zeros(), &Vector3::zeros());
+    let centrifugal = EARTH_ROTATION_RATE.powi(2) * WGS84_A;
+    assert!((at_rest - Vector3::new(0.0, 0.0, centrifugal)).norm() < 1e-9);
+
+    // moving east adds the Eötvös lift 2 ω v
+    let east = enu.rotating_acceleration(&Vector3::zeros(), &Vector3::new(1000.0, 0.0, 0.0));
+    let coriolis = 2.0 * EARTH_ROTATION_RATE * 1000.0;
+    assert!((east - at_rest - Vector3::new(0.0, 0.0, coriolis)).norm() < 1e-9);
+
+    // the same physics in NED
+    let ned = LocalFrame::ned(Geodetic::new(0.0, 0.0, 0.0));
+    let east_ned = ned.rotating_acceleration(&Vector3::zeros(), &Vector3::new(0.0, 1000.0, 0.0));
+    assert!((east_ned - ned.from_enu(&east)).norm() < 1e-9);
+}
+
+#[test]
+fn test_table_interpolation() {
+    use missile_sim::core::{Extrapolation, Interpolation, LookupTable};
+
+    let table = LookupTable::new_1d("mach", vec![0.0, 1.0, 3.0], vec![0.0, 2.0, 4.0]).unwrap();
+    assert_eq!(table.lookup1(0.5), 1.0);
+    assert_eq!(table.lookup1(2.0), 3.0);
+    // clamped by default, then extended along the edge interval
+    assert_eq!(table.lookup1(-1.0), 0.0);
+    assert_eq!(table.lookup1(5.0), 4.0);
+    let extended = table.clone().with_extrapolation(Extrapolation::Linear);
+    assert_eq!(extended.lookup1(-1.0), -2.0);
+    assert_eq!(extended.lookup1(5.0), 6.0);
+
+    // cubic passes through the breakpoints and reproduces a parabola inside the grid
+    let x: Vec<f64> = (0..9).map(|i| i as f64 * 0.5).collect();
+    let cubic = LookupTable::new_1d("x", x.clone(), x.iter().map(|x| x * x).collect())
+        .unwrap()
+        .with_interpolation(Interpolation::Cubic);
+    assert_eq!(cubic.lookup1(1.5), 2.25);
+    assert!((cubic.lookup1(2.2) - 4.84).abs() < 1e-12);
+
+    // the cached bracket follows the query wherever it jumps
+    for q in [3.9, 0.1, 2.6, 2.4, 4.0, 0.0] {
+        let linear = LookupTable::new_1d("x", x.clone(), x.clone()).unwrap();
+        assert!((cubic.lookup1(q) - q * q).abs() < 0.05, "{q}");
+        assert!((linear.lookup1(q) - q).abs() < 1e-12);
+    }
+
+    // rows in any order, the cell center is the mean of its corners
+    let csv = "mach, alpha, cn\n1,0.2,5.2\n0,0,0\n0,0.2,0.4\n1,0,3\n2,0,6\n2,0.2,6.4\n";
+    let grid = LookupTable::from_csv(csv).unwrap();
+    assert_eq!(grid.dimensions(), 2);
+    assert_eq!(grid.axes[1].name, "alpha");
+    assert!((grid.lookup(&[0.5, 0.1]) - 2.15).abs() < 1e-12);
+    assert!(LookupTable::from_csv("mach,alpha,cn\n0,0,0\n1,0.2,1\n").is_err());
+    assert!(LookupTable::new_1d("x", vec![1.0, 0.0], vec![0.0, 1.0]).is_err());
+
+    let json = serde_json::to_string(&grid).unwrap();
+    assert_eq!(serde_json::from_str::<LookupTable>(&json).unwrap(), grid);
+    #[cfg(feature = "json")]
+    assert_eq!(LookupTable::from_json(&json).unwrap(), grid);
+
+    // deserialized tables are checked like constructed ones
+    for bad in [
+        r#"{"axes":[{"name":"x","breakpoints":[0,1,2]}],"values":[1]}"#,
+        r#"{"axes":[{"name":"x","breakpoints":[]}],"values":[]}"#,
+        r#"{"axes":[],"values":[1]}"#,
+    ] {
+        assert!(serde_json::from_str::<LookupTable>(bad).is_err(), "{bad}");
+    }
+}
@@ -88,3 +88,1431 @@ fn test_maneuvering_target() {
     assert!(!metrics.time_history.is_empty());
     assert!(metrics.hit);
 }
+
+#[test]
+fn test_engagement_summary_head_on() {
+    let missile = Missile {
+        state: State3D::init(0.0, 0.0, 0.0, 100.0, 0.0, 0.0),
+        max_acceleration: 30.0,
+        navigation_constant: 3.0,
+        max_closing_speed: 1000.0,
+    };
+    let target = Target {
+        state: State3D::init(1000.0, 0.0, 0.0, -50.0, 0.0, 0.0),
+        acceleration: Vector3::zeros(),
+    };
+
+    let mut engine = SimulationEngine::new(missile.clone(), target.clone(), 0.01, 20.0, 2.0);
+    let metrics = engine.run(&PureProportionalNavigation);
+    let summary = &metrics.summary;
+
+    assert!(metrics.hit);
+    assert!((summary.time_of_flight - 1000.0 / 150.0).abs() < 0.02);
+    assert!(summary.launch_heading_error.abs() < 1e-9);
+    assert!((summary.final_closing_speed - 150.0).abs() < 1e-6);
+    assert!((summary.terminal_speed - 100.0).abs() < 1e-6);
+    assert!((summary.impact_angle - std::f64::consts::PI).abs() < 1e-6);
+    assert!(summary.final_aspect_angle.abs() < 1e-6);
+    assert!(summary.control_effort.abs() < 1e-9);
+    assert_eq!(summary.saturation_time, 0.0);
+
+    // a missile that can't maneuver is never saturated
+    let mut ballistic = SimulationEngine::new(
+        Missile {
+            max_acceleration: 0.0,
+            ..missile
+        },
+        target,
+        0.01,
+        20.0,
+        2.0,
+    );
+    assert_eq!(
+        ballistic
+            .run(&PureProportionalNavigation)
+            .summary
+            .saturation_time,
+        0.0
+    );
+}
+
+#[test]
+fn test_engagement_summary_crossing_target() {
+    let missile = Missile {
+        state: State3D::init(0.0, 0.0, 0.0, 300.0, 0.0, 0.0),
+        max_acceleration: 20.0,
+        navigation_constant: 4.0,
+        max_closing_speed: 2000.0,
+    };
+    let target = Target {
+        state: State3D::init(3000.0, 1000.0, 0.0, 0.0, 100.0, 0.0),
+        acceleration: Vector3::zeros(),
+    };
+
+    let mut engine = SimulationEngine::new(missile, target, 0.01, 30.0, 5.0);
+    let metrics = engine.run(&PureProportionalNavigation);
+    let summary = &metrics.summary;
+
+    assert!(summary.launch_heading_error > 0.1);
+    assert!(summary.peak_acceleration_g > 0.0);
+    assert!(summary.peak_acceleration_g <= 20.0 / 9.80665 + 1e-9);
+    assert!(summary.rms_acceleration_g <= summary.peak_acceleration_g);
+    assert!(summary.saturation_time > 0.0);
+    assert!(summary.control_effort > 0.0);
+    assert!(summary.peak_los_rate > 0.0);
+    assert!(summary.time_of_flight <= *metrics.time_history.last().unwrap());
+}
+
+#[test]
+fn test_vector_histories() {
+    let builder = Scenario::builder("crossing")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 0.0, 300.0, 0.0, 0.0),
+            max_acceleration: 100.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 2000.0,
+        })
+        .target(Target {
+            state: State3D::init(3000.0, 1000.0, 0.0, 0.0, 100.0, 0.0),
+            acceleration: Vector3::zeros(),
+        })
+        .dt(0.01)
+        .total_time(30.0);
+
+    // lean by default
+    let lean = builder
+        .clone()
+        .build()
+        .unwrap()
+        .simulate(&PureProportionalNavigation);
+    assert!(lean.commanded_acceleration.is_empty());
+    assert!(lean.los_unit_vectors.is_empty());
+
+    let full = builder
+        .record_vectors(true)
+        .build()
+        .unwrap()
+        .simulate(&PureProportionalNavigation);
+    let n = full.time_history.len();
+    assert_eq!(full.commanded_acceleration.len(), n);
+    assert_eq!(full.achieved_acceleration.len(), n);
+    assert_eq!(full.los_rate_vectors.len(), n);
+    assert_eq!(full.los_unit_vectors.len(), n);
+
+    for i in 1..n {
+        // PN commands are perpendicular to the LOS rate, achieved ones to the velocity
+        let cmd = full.commanded_acceleration[i];
+        assert!(cmd.dot(&full.los_rate_vectors[i]).abs() < 1e-6);
+        let achieved = full.achieved_acceleration[i];
+        assert!((achieved.norm() - full.acceleration_records[i].min(100.0)).abs() < 1e-6);
+        assert!((full.los_unit_vectors[i].norm() - 1.0).abs() < 1e-9);
+    }
+}
+
+fn crossing_builder() -> ScenarioBuilder {
+    Scenario::builder("crossing")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 0.0, 300.0, 0.0, 0.0),
+            max_acceleration: 100.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 2000.0,
+        })
+        .target(Target {
+            state: State3D::init(3000.0, 1000.0, 0.0, 0.0, 100.0, 0.0),
+            acceleration: Vector3::new(0.0, 0.0, 5.0),
+        })
+        .dt(0.01)
+        .total_time(30.0)
+}
+
+#[test]
+fn test_recording_policies_keep_summary_exact() {
+    let full = crossing_builder()
+        .build()
+        .unwrap()
+        .simulate(&PureProportionalNavigation);
+
+    let policies = [
+        RecordingPolicy::None,
+        RecordingPolicy::EventOnly,
+        RecordingPolicy::Decimated(Decimation::EveryNthStep(10)),
+        RecordingPolicy::Decimated(Decimation::Interval(0.5)),
+    ];
+
+    for policy in policies {
+        let metrics = crossing_builder()
+            .recording(policy)
+            .build()
+            .unwrap()
+            .simulate(&PureProportionalNavigation);
+
+        assert_eq!(metrics.miss_distance, full.miss_distance);
+        assert_eq!(metrics.hit, full.hit);
+        assert_eq!(metrics.summary.time_of_flight, full.summary.time_of_flight);
+        assert_eq!(metrics.summary.control_effort, full.summary.control_effort);
+        assert_eq!(metrics.summary.duration, full.summary.duration);
+    }
+}
+
+#[test]
+fn test_recording_policy_sample_counts() {
+    let full = crossing_builder()
+        .build()
+        .unwrap()
+        .simulate(&PureProportionalNavigation);
+    let n = full.time_history.len();
+    let last = *full.time_history.last().unwrap();
+
+    let none = crossing_builder()
+        .recording(RecordingPolicy::None)
+        .build()
+        .unwrap()
+        .simulate(&PureProportionalNavigation);
+    assert!(none.time_history.is_empty());
+
+    let events = crossing_builder()
+        .recording(RecordingPolicy::EventOnly)
+        .build()
+        .unwrap()
+        .simulate(&PureProportionalNavigation);
+    assert_eq!(events.time_history, vec![0.0, last]);
+
+    let decimated = crossing_builder()
+        .recording(RecordingPolicy::Decimated(Decimation::EveryNthStep(10)))
+        .build()
+        .unwrap()
+        .simulate(&PureProportionalNavigation);
+    assert_eq!(
+        decimated.time_history.len(),
+        n.div_ceil(10) + usize::from(!(n - 1).is_multiple_of(10))
+    );
+    assert_eq!(*decimated.time_history.last().unwrap(), last);
+    assert_eq!(
+        decimated.missile_trajectory.len(),
+        decimated.time_history.len()
+    );
+}
+
+/// Counts callbacks and stops the run after `stop_after` steps.
+#[derive(Default)]
+struct CountingObserver {
+    started: bool,
+    steps: usize,
+    events: Vec<SimulationEvent>,
+    finished: bool,
+    stop_after: Option<usize>,
+}
+
+impl SimulationObserver for CountingObserver {
+    fn on_start(&mut self, _engine: &SimulationEngine) {
+        self.started = true;
+    }
+
+    fn on_step(&mut self, _step: &StepSample) -> std::ops::ControlFlow<()> {
+        self.steps += 1;
+        match self.stop_after {
+            Some(n) if self.steps >= n => std::ops::ControlFlow::Break(()),
+            _ => std::ops::ControlFlow::Continue(()),
+        }
+    }
+
+    fn on_event(&mut self, _time: f64, event: &SimulationEvent) {
+        self.events.push(event.clone());
+    }
+
+    fn on_finish(&mut self, _engine: &SimulationEngine) {
+        self.finished = true;
+    }
+}
+
+#[test]
+fn test_observer_sees_every_step_and_events() {
+    let scenario = crossing_builder().hit_threshold(5.0).build().unwrap();
+    let mut observer = CountingObserver::default();
+    let metrics = scenario.simulate_with_observer(&PureProportionalNavigation, &mut observer);
+
+    assert!(metrics.hit);
+    assert!(observer.started && observer.finished);
+    assert_eq!(observer.steps, metrics.time_history.len());
+    assert!(matches!(
+        observer.events.as_slice(),
+        [
+            SimulationEvent::Fuze { .. },
+            SimulationEvent::Termination {
+                reason: TerminationReason::Hit
+            }
+        ]
+    ));
+    assert_eq!(metrics.events.len(), 2);
+    assert_eq!(
+        metrics.events[1].time,
+        *metrics.time_history.last().unwrap()
+    );
+}
+
+#[test]
+fn test_observer_can_stop_run() {
+    let scenario = crossing_builder().build().unwrap();
+    let mut observer = CountingObserver {
+        stop_after: Some(50),
+        ..Default::default()
+    };
+    let metrics = scenario.simulate_with_observer(&PureProportionalNavigation, &mut observer);
+
+    assert_eq!(observer.steps, 50);
+    assert_eq!(metrics.time_history.len(), 50);
+    assert!(!metrics.hit);
+    assert_eq!(
+        metrics.events.last().map(|e| &e.event),
+        Some(&SimulationEvent::Termination {
+            reason: TerminationReason::Observer
+        })
+    );
+}
+
+/// PPN with a noisy, low-pass filtered command, to exercise guidance memory and the RNG.
+struct NoisyFilteredPn;
+
+impl GuidanceLaw for NoisyFilteredPn {
+    fn calculate_acceleration(&self, missile: &Missile, target: &Target) -> Vector3<f64> {
+        PureProportionalNavigation.calculate_acceleration(missile, target)
+    }
+
+    fn guide(&self, missile: &Missile, target: &Target, ctx: &mut GuidanceContext) -> Vector3<f64> {
+        let raw = self.calculate_acceleration(missile, target)
+            + Vector3::new(ctx.rng.normal(), ctx.rng.normal(), ctx.rng.normal()) * 2.0;
+        let previous = ctx.state.vector("filtered").unwrap_or(raw);
+        let filtered = previous + (raw - previous) * 0.2;
+        ctx.state.set_vector("filtered", filtered);
+        filtered
+    }
+
+    fn name(&self) -> &str {
+        "NoisyFilteredPn"
+    }
+}
+
+#[test]
+fn test_snapshot_restore_is_deterministic() {
+    let scenario = crossing_builder().seed(7).build().unwrap();
+    let mut engine = SimulationEngine::new(
+        scenario.missile.clone(),
+        scenario.target.clone(),
+        scenario.dt,
+        scenario.total_time,
+        scenario.hit_threshold,
+    );
+    engine.rng = SimRng::seed_from_u64(scenario.seed);
+
+    let mut metrics = SimulationMetrics::default();
+    for _ in 0..400 {
+        engine.step(&NoisyFilteredPn, &mut metrics);
+    }
+
+    let snapshot = engine.snapshot();
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let restored: EngineSnapshot = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, snapshot);
+
+    let original = engine.run(&NoisyFilteredPn);
+    let replay = SimulationEngine::from_snapshot(restored).run(&NoisyFilteredPn);
+    assert_eq!(original.missile_trajectory, replay.missile_trajectory);
+    assert_eq!(original.miss_distance, replay.miss_distance);
+
+    // rewind the original engine and branch a different continuation
+    engine.restore(&snapshot);
+    assert_eq!(engine.time, snapshot.time);
+    engine.target.acceleration = Vector3::new(0.0, -30.0, 0.0);
+    let branch = engine.run(&NoisyFilteredPn);
+    assert_ne!(branch.missile_trajectory, original.missile_trajectory);
+}
+
+#[test]
+fn test_restored_run_matches_uninterrupted_run() {
+    // a receding target, 60 m further each step, just enough to trip the divergence check
+    let scenario = Scenario::builder("receding")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 0.0, 300.0, 0.0, 0.0),
+            max_acceleration: 100.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 2000.0,
+        })
+        .target(Target {
+            state: State3D::init(1000.0, 0.0, 0.0, 900.0, 0.0, 0.0),
+            acceleration: Vector3::zeros(),
+        })
+        .dt(0.1)
+        .build()
+        .unwrap();
+    let full = scenario.simulate(&PureProportionalNavigation);
+    let diverging = SimulationEvent::Termination {
+        reason: TerminationReason::Diverging,
+    };
+    assert_eq!(full.events.last().map(|e| &e.event), Some(&diverging));
+
+    struct StopAfter(usize, Option<EngineSnapshot>);
+    impl SimulationObserver for StopAfter {
+        fn on_step(&mut self, _step: &StepSample) -> std::ops::ControlFlow<()> {
+            self.0 = self.0.saturating_sub(1);
+            if self.0 == 0 {
+                std::ops::ControlFlow::Break(())
+            } else {
+                std::ops::ControlFlow::Continue(())
+            }
+        }
+
+        fn on_finish(&mut self, engine: &SimulationEngine) {
+            self.1 = Some(engine.snapshot());
+        }
+    }
+    // the launch sample and five steps, then resume from there
+    let mut stop = StopAfter(6, None);
+    scenario.simulate_with_observer(&PureProportionalNavigation, &mut stop);
+    let resumed = SimulationEngine::from_snapshot(stop.1.unwrap()).run(&PureProportionalNavigation);
+
+    assert_eq!(resumed.events.last().map(|e| &e.event), Some(&diverging));
+    assert_eq!(resumed.missile_trajectory, full.missile_trajectory[5..]);
+    assert_eq!(resumed.time_history.last(), full.time_history.last());
+    // the summary covers what the resumed run flew, from 0.5 s on
+    let flown = full.summary.duration - 0.5;
+    assert!((resumed.summary.duration - flown).abs() < 1e-9);
+}
+
+#[test]
+fn test_scenario_seed_controls_noise() {
+    let run = |seed| {
+        crossing_builder()
+            .seed(seed)
+            .build()
+            .unwrap()
+            .simulate(&NoisyFilteredPn)
+            .miss_distance
+    };
+
+    assert_eq!(run(1), run(1));
+    assert_ne!(run(1), run(2));
+}
+
+#[test]
+#[cfg(all(feature = "json", feature = "toml", feature = "yaml"))]
+fn test_scenario_round_trips_through_every_format() {
+    use missile_sim::simulation::ScenarioFormat;
+
+    let scenario = crossing_builder()
+        .seed(3)
+        .recording(RecordingPolicy::Decimated(Decimation::EveryNthStep(5)))
+        .guidance(GuidanceSpec::new("APN").with_param("time_constant", 0.8))
+        .frame(missile_sim::core::LocalFrame::enu(
+            missile_sim::core::Geodetic::new(36.2, -115.1, 1500.0),
+        ))
+        .terrain(Terrain::HeightMap(
+            missile_sim::environment::HeightMap::new(
+                [-500.0, -500.0],
+                250.0,
+                vec![vec![-10.0, -5.0], vec![-8.0, -1.0]],
+            )
+            .unwrap(),
+        ))
+        .environment(Environment {
+            gravity: 9.81,
+            wind: missile_sim::environment::Wind::Constant {
+                velocity: Vector3::new(-15.0, 4.0, 0.0),
+            },
+            turbulence: Some(missile_sim::environment::Turbulence::von_karman(2.0)),
+            ..Default::default()
+        })
+        .six_dof(missile_sim::dynamics::SixDof::default())
+        .build()
+        .unwrap();
+
+    for format in [
+        ScenarioFormat::Json,
+        ScenarioFormat::Toml,
+        ScenarioFormat::Yaml,
+    ] {
+        let text = format.write(&scenario).unwrap();
+        let parsed = format.parse(&text).unwrap();
+
+        assert_eq!(parsed.name, scenario.name);
+        assert_eq!(parsed.missile, scenario.missile);
+        assert_eq!(parsed.target, scenario.target);
+        assert_eq!(parsed.dt, scenario.dt);
+        assert_eq!(parsed.recording, scenario.recording);
+        assert_eq!(parsed.seed, scenario.seed);
+        assert_eq!(parsed.guidance, scenario.guidance);
+        assert_eq!(parsed.frame, scenario.frame);
+        assert_eq!(parsed.terrain, scenario.terrain);
+        assert_eq!(parsed.environment, scenario.environment);
+        assert_eq!(parsed.six_dof, scenario.six_dof);
+    }
+
+    // altitude-dependent models read `+z` as up, which NED frames point down
+    let mut ned = scenario.clone();
+    ned.frame = Some(missile_sim::core::LocalFrame::ned(
+        missile_sim::core::Geodetic::new(36.2, -115.1, 1500.0),
+    ));
+    assert!(ned.validate().is_err());
+    let kinematic = crossing_builder().frame(ned.frame.unwrap()).build();
+    assert!(kinematic.is_ok());
+}
+
+#[test]
+#[cfg(feature = "toml")]
+fn test_scenario_file_defaults_and_guidance() {
+    use missile_sim::simulation::ScenarioFormat;
+
+    let text = r#"
+            name = "minimal"
+
+            [guidance]
+            law = "lp"
+            lead_time = 1.2
+
+            [missile]
+            max_acceleration = 100.0
+            navigation_constant = 4.0
+            max_closing_speed = 2000.0
+            state = { position = [0.0, 0.0, 0.0], velocity = [300.0, 0.0, 0.0] }
+
+            [target]
+            acceleration = [0.0, 0.0, 0.0]
+            state = { position = [3000.0, 500.0, 0.0], velocity = [-50.0, 0.0, 0.0] }
+            "#;
+    let scenario = ScenarioFormat::Toml.parse(text).unwrap();
+
+    assert_eq!(scenario.dt, 0.01);
+    assert_eq!(scenario.total_time, 60.0);
+    assert_eq!(scenario.hit_threshold, 5.0);
+    assert_eq!(scenario.recording, RecordingPolicy::Full);
+    assert!(scenario.simulate_default().unwrap().hit);
+
+    let bad = GuidanceSpec::new("PPN").with_param("lead_time", 1.0);
+    assert!(bad.build().is_err());
+    assert!(GuidanceSpec::new("XYZ").build().is_err());
+
+    // files are validated like built scenarios
+    let format = ScenarioFormat::Toml;
+    assert!(
+        format
+            .parse(&format!("rotating_earth = true\n{text}"))
+            .is_err()
+    );
+    let lossy = "[datalink]\nupdate_interval = 1.0\npacket_loss = 2.0\n";
+    let error = format.parse(&format!("{text}\n{lossy}"));
+    assert!(error.unwrap_err().to_string().contains("packet loss"));
+
+    let mut staged = scenario.clone();
+    staged.stages = Some(missile_sim::entity::Staging::new(Vec::new()));
+    assert!(staged.validate().is_err());
+}
+
+#[test]
+#[cfg(feature = "toml")]
+fn test_load_scenario_dir() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("scenarios");
+    let scenes = missile_sim::simulation::load_scenario_dir(dir).unwrap();
+
+    assert_eq!(scenes.len(), 6);
+    assert_eq!(scenes[0].name, "BVR Fighter Intercept");
+    assert!(scenes.iter().all(|s| s.guidance.is_some()));
+}
+
+#[test]
+#[cfg(feature = "toml")]
+fn test_scenario_file_maneuvers_seeker_and_phased_guidance() {
+    use missile_sim::entity::{Maneuver, Seeker};
+    use missile_sim::guidance::{PhaseTrigger, WrapperSpec};
+    use missile_sim::simulation::ScenarioFormat;
+
+    let text = r#"
+            name = "weaving target"
+            dt = 0.001
+            total_time = 30.0
+
+            [missile]
+            max_acceleration = 300.0
+            navigation_constant = 4.0
+            max_closing_speed = 2000.0
+            state = { position = [0.0, 0.0, 500.0], velocity = [600.0, 0.0, 0.0] }
+
+            [target]
+            acceleration = [0.0, 0.0, 0.0]
+            state = { position = [8000.0, 1000.0, 500.0], velocity = [-200.0, 0.0, 0.0] }
+
+            [seeker]
+            range = 6000.0
+            field_of_view = 1.0
+
+            [[maneuvers]]
+            start = 2.0
+            acceleration = [0.0, 40.0, 0.0]
+
+            [[maneuvers]]
+            start = 5.0
+            acceleration = [0.0, -40.0, 0.0]
+
+            [guidance]
+            law = "PIP"
+            heading_gain = 2.5
+            blend_time = 0.5
+            wrap = [{ kind = "min_altitude", min_altitude = 50.0 }]
+
+            [[guidance.phases]]
+            trigger = { kind = "time_to_go", time = 4.0 }
+            law = "APN"
+            time_constant = 0.3
+            "#;
+    let scenario = ScenarioFormat::Toml.parse(text).unwrap();
+
+    assert_eq!(
+        scenario.seeker,
+        Some(Seeker {
+            range: Some(6000.0),
+            field_of_view: Some(1.0),
+        })
+    );
+    assert_eq!(
+        scenario.maneuvers[1],
+        Maneuver::new(5.0, Vector3::new(0.0, -40.0, 0.0))
+    );
+    let spec = scenario.guidance.as_ref().unwrap();
+    assert_eq!(spec.phases[0].trigger, PhaseTrigger::TimeToGo { time: 4.0 });
+    assert_eq!(spec.phases[0].guidance.params["time_constant"], 0.3);
+    assert_eq!(
+        spec.wrap,
+        [WrapperSpec::MinAltitude {
+            min_altitude: 50.0,
+            lookahead: 2.0,
+        }]
+    );
+
+    // the law built from the file describes itself with the same spec
+    let law = spec.build().unwrap();
+    assert_eq!(law.name(), "PIP>APN+MinAlt");
+    assert_eq!(law.spec(), *spec);
+    let metrics = scenario.simulate(law.as_ref());
+    assert!(metrics.hit);
+    let seen: Vec<_> = metrics.events.iter().map(|e| &e.event).collect();
+    assert!(seen.contains(&&SimulationEvent::LockAcquired));
+    assert!(seen.contains(&&SimulationEvent::GuidancePhase { phase: 1 }));
+
+    // back through the file format
+    let again = ScenarioFormat::Toml
+        .parse(&ScenarioFormat::Toml.write(&scenario).unwrap())
+        .unwrap();
+    assert_eq!(again.guidance, scenario.guidance);
+    assert_eq!(again.maneuvers, scenario.maneuvers);
+
+    let out_of_order = text.replace("start = 5.0", "start = 1.0");
+    assert!(ScenarioFormat::Toml.parse(&out_of_order).is_err());
+}
+
+#[test]
+fn test_maneuver_schedule_switches_target_acceleration() {
+    use missile_sim::entity::Maneuver;
+
+    let build = |maneuvers: &[Maneuver]| {
+        let mut builder = crossing_builder().total_time(4.0).hit_threshold(0.0);
+        for maneuver in maneuvers {
+            builder = builder.maneuver(maneuver.clone());
+        }
+        builder.build().unwrap()
+    };
+    let jink = [
+        Maneuver::new(1.0, Vector3::new(0.0, 30.0, 0.0)),
+        Maneuver::new(2.0, Vector3::zeros()),
+    ];
+    struct Final(Option<Target>);
+    impl SimulationObserver for Final {
+        fn on_finish(&mut self, engine: &SimulationEngine) {
+            self.0 = Some(engine.target.clone());
+        }
+    }
+    let mut last = Final(None);
+    build(&jink).simulate_with_observer(&PurePursuit, &mut last);
+
+    // one second at 30 m/s² on top of the 5 m/s² climb, then coasting on the velocity gained
+    let target = last.0.unwrap();
+    assert!((target.state.velocity.y - 130.0).abs() < 0.5);
+    assert_eq!(target.acceleration, Vector3::zeros());
+
+    let plain = build(&[]).simulate(&PurePursuit);
+    let jinked = build(&jink).simulate(&PurePursuit);
+    assert_ne!(plain.miss_distance, jinked.miss_distance);
+}
+
+#[test]
+fn test_seeker_field_of_view_gates_lock() {
+    use missile_sim::entity::Seeker;
+
+    // the target sits 60° off the missile's nose at launch
+    let builder = Scenario::builder("off boresight")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 0.0, 300.0, 0.0, 0.0),
+            max_acceleration: 100.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 2000.0,
+        })
+        .target(Target {
+            state: State3D::init(2000.0, 3464.0, 0.0, 0.0, 0.0, 0.0),
+            acceleration: Vector3::zeros(),
+        })
+        .total_time(30.0);
+
+    let wide = builder
+        .clone()
+        .seeker(Seeker {
+            range: None,
+            field_of_view: Some(1.2),
+        })
+        .build()
+        .unwrap();
+    let metrics = wide.simulate(&PureProportionalNavigation);
+    assert!(metrics.hit);
+    assert!(
+        !metrics
+            .events
+            .iter()
+            .any(|e| e.event == SimulationEvent::LockLost)
+    );
+
+    // a narrow gimbal flies out on the launch track and acquires once turned toward it
+    let narrow = builder
+        .seeker(Seeker {
+            range: None,
+            field_of_view: Some(0.5),
+        })
+        .build()
+        .unwrap();
+    let metrics = narrow.simulate(&PureProportionalNavigation);
+    assert!(metrics.hit);
+    let events: Vec<_> = metrics.events.iter().map(|e| &e.event).collect();
+    assert!(!events.contains(&&SimulationEvent::LockLost));
+    assert!(events.contains(&&SimulationEvent::LockAcquired));
+    assert!(metrics.events[0].time > 0.0);
+}
+
+#[test]
+fn test_rotating_earth_deflects_long_range_intercept() {
+    use missile_sim::core::{Geodetic, LocalFrame};
+
+    let frame = LocalFrame::enu(Geodetic::new(30.0, 45.0, 0.0));
+    let builder = Scenario::builder("ballistic terminal")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 0.0, 0.0, 1200.0, 600.0),
+            max_acceleration: 300.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 6000.0,
+        })
+        .target(Target {
+            state: State3D::init(5_000.0, 150_000.0, 60_000.0, 0.0, -2500.0, -800.0),
+            acceleration: Vector3::zeros(),
+        })
+        .frame(frame)
+        .dt(0.01)
+        .total_time(120.0)
+        // ~40 m of closure per step
+        .hit_threshold(30.0);
+
+    let inertial = builder.clone().build().unwrap();
+    let rotating = builder.rotating_earth(true).build().unwrap();
+    assert!(!inertial.rotating_earth);
+
+    let flat = inertial.simulate(&PureProportionalNavigation);
+    let spun = rotating.simulate(&PureProportionalNavigation);
+
+    // guidance closes on the deflected target, at a visibly different intercept point
+    assert!(flat.hit && spun.hit);
+    let shift =
+        (flat.missile_trajectory.last().unwrap() - spun.missile_trajectory.last().unwrap()).norm();
+    assert!(shift > 50.0, "shift {shift}");
+
+    // the rotation needs somewhere on Earth to rotate about
+    assert!(
+        Scenario::builder("no frame")
+            .missile(rotating.missile.clone())
+            .target(rotating.target.clone())
+            .rotating_earth(true)
+            .build()
+            .is_err()
+    );
+}
+
+fn sea_skimmer_builder() -> ScenarioBuilder {
+    Scenario::builder("sea skimmer")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 15.0, 800.0, 0.0, -40.0),
+            max_acceleration: 300.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 2000.0,
+        })
+        .target(Target {
+            state: State3D::init(6000.0, 100.0, 6.0, -270.0, 0.0, 0.0),
+            acceleration: Vector3::zeros(),
+        })
+        .terrain(Terrain::flat(0.0))
+        .dt(0.001)
+        .total_time(20.0)
+}
+
+#[test]
+fn test_terrain_ends_run_on_ground_impact() {
+    let scenario = sea_skimmer_builder().build().unwrap();
+    let metrics = scenario.simulate(&PureProportionalNavigation);
+    assert!(!metrics.hit);
+    assert_eq!(
+        metrics.events.last().map(|e| &e.event),
+        Some(&SimulationEvent::Termination {
+            reason: TerminationReason::GroundImpact
+        })
+    );
+
+    // the guard holds the missile above the sea all the way to the target
+    let guarded = MinimumAltitude::new(Box::new(PureProportionalNavigation), 3.0);
+    assert_eq!(guarded.name(), "PPN+MinAlt");
+    let metrics = scenario.simulate(&guarded);
+    assert!(metrics.hit, "miss {}", metrics.miss_distance);
+    assert!(metrics.missile_trajectory.iter().all(|p| p.z > 0.0));
+}
+
+#[test]
+fn test_terrain_masking_breaks_seeker_lock() {
+    use missile_sim::environment::HeightMap;
+
+    let row = [
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 400.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    ];
+    let ridge = HeightMap::new([0.0, -10_000.0], 500.0, vec![row.to_vec(); 41]).unwrap();
+    let scenario = Scenario::builder("pop-up")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 100.0, 300.0, 0.0, 100.0),
+            max_acceleration: 200.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 2000.0,
+        })
+        .target(Target {
+            state: State3D::init(6000.0, 0.0, 50.0, -50.0, 0.0, 100.0),
+            acceleration: Vector3::zeros(),
+        })
+        .terrain(Terrain::HeightMap(ridge))
+        .total_time(30.0)
+        .build()
+        .unwrap();
+
+    let metrics = scenario.simulate(&PureProportionalNavigation);
+    let events: Vec<_> = metrics.events.iter().map(|e| &e.event).collect();
+    assert_eq!(events[0], &SimulationEvent::LockLost);
+    assert_eq!(metrics.events[0].time, 0.0);
+    assert_eq!(events[1], &SimulationEvent::LockAcquired);
+    assert!(metrics.events[1].time > 0.0);
+    assert!(metrics.hit, "miss {}", metrics.miss_distance);
+
+    // the masked run is reproducible from a snapshot
+    let mut engine = SimulationEngine::new(
+        scenario.missile.clone(),
+        scenario.target.clone(),
+        scenario.dt,
+        scenario.total_time,
+        scenario.hit_threshold,
+    );
+    engine.terrain = scenario.terrain.clone();
+    let mut partial = SimulationMetrics::init(10);
+    engine.step(&PureProportionalNavigation, &mut partial);
+    assert!(!engine.seeker_locked());
+    let restored = SimulationEngine::from_snapshot(engine.snapshot());
+    assert!(!restored.seeker_locked());
+}
+
+#[test]
+fn test_environment_drag_wind_and_gusts() {
+    use missile_sim::environment::{Turbulence, Wind};
+
+    let airframe = Airframe {
+        mass: 150.0,
+        reference_area: 0.03,
+        drag_coefficient: 0.4,
+        drag_table: None,
+    };
+    let with = |environment: Environment| {
+        crossing_builder()
+            .environment(environment)
+            .airframe(airframe.clone())
+            .build()
+            .unwrap()
+            .simulate(&PureProportionalNavigation)
+    };
+    let final_speed = |metrics: &SimulationMetrics| metrics.missile_velocity.last().unwrap().norm();
+
+    let kinematic = crossing_builder()
+        .build()
+        .unwrap()
+        .simulate(&PureProportionalNavigation);
+    let still = with(Environment::default());
+    assert!(final_speed(&still) < final_speed(&kinematic) - 1.0);
+
+    // a headwind along the missile's path adds airspeed and so drag
+    let headwind = with(Environment {
+        wind: Wind::Constant {
+            velocity: Vector3::new(-30.0, 0.0, 0.0),
+        },
+        ..Default::default()
+    });
+    assert!(final_speed(&headwind) < final_speed(&still));
+
+    // vacuum has no drag at all
+    let vacuum = with(Environment {
+        atmosphere: missile_sim::environment::Atmosphere::Vacuum,
+        ..Default::default()
+    });
+    assert_eq!(vacuum.missile_trajectory, kinematic.missile_trajectory);
+
+    // gusts perturb the target, reproducibly for a given seed
+    let gusty = |seed| {
+        crossing_builder()
+            .seed(seed)
+            .environment(Environment {
+                turbulence: Some(Turbulence::dryden(5.0)),
+                ..Default::default()
+            })
+            .build()
+            .unwrap()
+            .simulate(&PureProportionalNavigation)
+    };
+    let (a, b, c) = (gusty(1), gusty(1), gusty(2));
+    assert_eq!(a.target_trajectory, b.target_trajectory);
+    assert_ne!(a.target_trajectory, c.target_trajectory);
+    assert_ne!(a.target_trajectory, kinematic.target_trajectory);
+}
+
+#[test]
+fn test_multi_stage_interceptor() {
+    use missile_sim::entity::{KillVehicle, Stage, Staging, StagingTrigger};
+    use missile_sim::simulation::{EventRecord, SimulationObserver};
+
+    let booster = Stage {
+        name: "booster".into(),
+        mass: 1200.0,
+        propellant: 900.0,
+        thrust: 250_000.0,
+        burn_time: 8.0,
+        max_acceleration: 150.0,
+        reference_area: 0.2,
+        drag_coefficient: 0.3,
+        drag_table: None,
+        trigger: StagingTrigger::Burnout,
+    };
+    let sustainer = Stage {
+        name: "sustainer".into(),
+        mass: 400.0,
+        propellant: 300.0,
+        thrust: 70_000.0,
+        burn_time: 10.0,
+        max_acceleration: 100.0,
+        reference_area: 0.1,
+        trigger: StagingTrigger::Altitude { altitude: 40_000.0 },
+        ..booster.clone()
+    };
+    let staging = Staging::new(vec![booster, sustainer]).with_kill_vehicle(KillVehicle {
+        mass: 40.0,
+        max_acceleration: 60.0,
+        delta_v: 400.0,
+        thrusters: None,
+    });
+
+    let scenario = Scenario::builder("exo intercept")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 0.0, 0.0, 60.0, 100.0),
+            max_acceleration: 0.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 8000.0,
+        })
+        .stages(staging)
+        .target(Target {
+            state: State3D::init(2000.0, 90_000.0, 110_000.0, 0.0, -1500.0, -1000.0),
+            acceleration: Vector3::new(0.0, 0.0, -9.81),
+        })
+        .environment(Environment {
+            gravity: 9.81,
+            ..Default::default()
+        })
+        .dt(0.005)
+        .total_time(60.0)
+        .hit_threshold(15.0)
+        .build()
+        .unwrap();
+
+    struct Final(Option<Staging>);
+    impl SimulationObserver for Final {
+        fn on_finish(&mut self, engine: &SimulationEngine) {
+            self.0 = engine.stages.clone();
+        }
+    }
+    let mut last = Final(None);
+    let metrics = scenario.simulate_with_observer(&PureProportionalNavigation, &mut last);
+    assert!(metrics.hit, "{}", metrics.miss_distance);
+
+    let separations: Vec<&EventRecord> = metrics
+        .events
+        .iter()
+        .filter(|e| matches!(e.event, SimulationEvent::StageSeparation { .. }))
+        .collect();
+    assert_eq!(separations.len(), 2);
+    assert_eq!(
+        separations[0].event,
+        SimulationEvent::StageSeparation { stage: 0 }
+    );
+    assert!((separations[0].time - 8.0).abs() < 0.01);
+    // the sustainer drops at 40 km, after burnout
+    assert_eq!(
+        separations[1].event,
+        SimulationEvent::StageSeparation { stage: 1 }
+    );
+    assert!(separations[1].time > 18.0 && separations[1].time < metrics.summary.time_of_flight);
+
+    // both motors burn out, the booster in the step it drops
+    let burnouts: Vec<&EventRecord> = metrics
+        .events
+        .iter()
+        .filter(|e| matches!(e.event, SimulationEvent::Burnout { .. }))
+        .collect();
+    assert_eq!(burnouts.len(), 2);
+    assert_eq!(burnouts[0].event, SimulationEvent::Burnout { stage: 0 });
+    assert_eq!(burnouts[0].time, separations[0].time);
+    assert_eq!(burnouts[1].event, SimulationEvent::Burnout { stage: 1 });
+    assert!((burnouts[1].time - 18.0).abs() < 0.01);
+
+    let staging = last.0.unwrap();
+    assert!(staging.kill_vehicle_released());
+    assert_eq!(staging.mass(), 40.0);
+    assert!(staging.delta_v_used() > 0.0 && staging.delta_v_used() <= 400.0);
+}
+
+#[test]
+fn test_kill_vehicle_divert_thrusters_with_pwm() {
+    use missile_sim::entity::{DivertThrusters, KillVehicle, Stage, Staging, StagingTrigger};
+
+    // a spent stage dropped at launch leaves the kill vehicle coasting exo-atmospheric
+    let carrier = Stage {
+        name: "carrier".into(),
+        mass: 100.0,
+        propellant: 0.0,
+        thrust: 0.0,
+        burn_time: 0.0,
+        max_acceleration: 0.0,
+        reference_area: 0.0,
+        drag_coefficient: 0.0,
+        drag_table: None,
+        trigger: StagingTrigger::Time { after: 0.0 },
+    };
+    let kill_vehicle = KillVehicle {
+        mass: 60.0,
+        max_acceleration: 0.0,
+        delta_v: 0.0,
+        thrusters: Some(DivertThrusters {
+            thrust: 3000.0,
+            levels: vec![1.0],
+            min_pulse: 0.02,
+            specific_impulse: 280.0,
+            propellant: 15.0,
+        }),
+    };
+    let scenario = Scenario::builder("exo kill vehicle")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 100_000.0, 3000.0, 0.0, 0.0),
+            max_acceleration: 0.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 8000.0,
+        })
+        .stages(Staging::new(vec![carrier]).with_kill_vehicle(kill_vehicle))
+        .target(Target {
+            state: State3D::init(60_000.0, 2000.0, 101_000.0, -3000.0, 0.0, 0.0),
+            acceleration: Vector3::zeros(),
+        })
+        .dt(0.0005)
+        .total_time(15.0)
+        .hit_threshold(5.0)
+        .build()
+        .unwrap();
+
+    let pwm =
+        PulseWidthModulation::new(Box::new(PureProportionalNavigation), 0.1).with_min_pulse(0.02);
+    assert_eq!(pwm.name(), "PPN+PWM");
+    let metrics = scenario.simulate(&pwm);
+    assert!(metrics.hit, "{}", metrics.miss_distance);
+
+    // thrusters are on or off, each axis at 50 m/s² rising as propellant burns
+    let lateral: Vec<f64> = metrics
+        .acceleration_records
+        .iter()
+        .copied()
+        .filter(|a| *a > 0.0)
+        .collect();
+    assert!(
+        lateral
+            .iter()
+            .all(|a| *a > 49.9 && *a < 1.42 * 3000.0 / 45.0)
+    );
+    let divert = metrics.summary.divert_delta_v;
+    assert!(
+        divert > 100.0 && divert < 2800.0 * (60.0_f64 / 45.0).ln(),
+        "{divert}"
+    );
+    // every bit of applied acceleration came out of the thrusters
+    assert!((divert - metrics.summary.control_effort).abs() < 1e-6);
+}
+
+#[test]
+fn test_phased_guidance_hands_over_and_blends() {
+    use missile_sim::guidance::{PhaseTrigger, PhasedGuidance};
+
+    let scenario = crossing_builder().record_vectors(true).build().unwrap();
+    let phased = |blend_time| {
+        PhasedGuidance::new(Box::new(PurePursuit))
+            .then(
+                PhaseTrigger::Range { range: 1500.0 },
+                Box::new(PureProportionalNavigation),
+            )
+            .with_blend_time(blend_time)
+    };
+    // after the launch sample, which has no command yet
+    let largest_jump = |metrics: &SimulationMetrics| {
+        metrics.commanded_acceleration[1..]
+            .windows(2)
+            .map(|w| (w[1] - w[0]).norm())
+            .fold(0.0, f64::max)
+    };
+
+    let sharp = scenario.simulate(&phased(0.0));
+    let blended = scenario.simulate(&phased(0.5));
+    assert!(sharp.hit && blended.hit);
+    assert_eq!(phased(0.5).name(), "PP>PPN");
+
+    let (time, phase) = blended
+        .events
+        .iter()
+        .find_map(|record| match record.event {
+            SimulationEvent::GuidancePhase { phase } => Some((record.time, phase)),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(phase, 1);
+    let step = blended
+        .time_history
+        .iter()
+        .position(|t| (t - time).abs() < 1e-9)
+        .unwrap();
+    assert!(blended.distance_records[step] < 1500.0);
+    assert!(blended.distance_records[step - 1] >= 1500.0);
+    assert_eq!(blended.phase_at(time - 0.1), 0);
+    assert_eq!(blended.phase_at(time), 1);
+
+    // blending removes the step in commanded acceleration at the handover
+    assert!(largest_jump(&blended) < 0.2 * largest_jump(&sharp));
+
+    // a trigger that holds at launch skips straight to the later phase
+    let locked = PhasedGuidance::new(Box::new(PurePursuit)).then(
+        PhaseTrigger::SeekerLock,
+        Box::new(PureProportionalNavigation),
+    );
+    let metrics = scenario.simulate(&locked);
+    assert_eq!(metrics.phase_at(0.0), 1);
+    assert_eq!(
+        metrics.missile_trajectory,
+        scenario
+            .simulate(&PureProportionalNavigation)
+            .missile_trajectory
+    );
+
+    // phases keep their own memory, a nested phased law doesn't touch the outer phase
+    struct Memory(GuidanceState);
+    impl SimulationObserver for Memory {
+        fn on_finish(&mut self, engine: &SimulationEngine) {
+            self.0 = engine.guidance_state.clone();
+        }
+    }
+    let nested = PhasedGuidance::new(Box::new(
+        PhasedGuidance::new(Box::new(PredictedInterceptPoint::default())).then(
+            PhaseTrigger::Range { range: 3000.0 },
+            Box::new(PredictedInterceptPoint::default()),
+        ),
+    ))
+    .then(
+        PhaseTrigger::Range { range: 1500.0 },
+        Box::new(PureProportionalNavigation),
+    )
+    .with_blend_time(0.5);
+    let mut memory = Memory(GuidanceState::default());
+    let metrics = scenario.simulate_with_observer(&nested, &mut memory);
+    assert!(metrics.hit);
+    let state = memory.0;
+    assert_eq!(state.phase, 1);
+    assert!(state.vector("pip").is_none());
+    let inner = &state.phases[0];
+    assert_eq!(inner.phase, 1);
+    assert!(inner.vector("pip").is_none() && inner.scalar("phase_switch_time").is_some());
+    assert!(inner.phases[0].vector("pip").is_some() && inner.phases[1].vector("pip").is_some());
+    assert_ne!(inner.phases[0].vector("pip"), inner.phases[1].vector("pip"));
+}
+
+#[test]
+fn test_pip_midcourse_with_pn_terminal() {
+    use missile_sim::guidance::{PhaseTrigger, PhasedGuidance};
+    use missile_sim::simulation::{SimulationObserver, StepSample};
+    use std::ops::ControlFlow;
+
+    let scenario = Scenario::builder("long range")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 10_000.0, 900.0, 0.0, 0.0),
+            max_acceleration: 150.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 3000.0,
+        })
+        .target(Target {
+            state: State3D::init(60_000.0, 20_000.0, 12_000.0, -250.0, -100.0, 0.0),
+            acceleration: Vector3::new(0.0, 3.0, 0.0),
+        })
+        .dt(0.01)
+        .total_time(120.0)
+        .hit_threshold(10.0)
+        .build()
+        .unwrap();
+
+    /// Predicted intercept points as guidance sees them.
+    #[derive(Default)]
+    struct Points(Vec<Vector3<f64>>);
+    impl SimulationObserver for Points {
+        fn on_step(&mut self, step: &StepSample) -> ControlFlow<()> {
+            self.0.extend(step.guidance.vector("pip"));
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut points = Points::default();
+    let pip = PredictedInterceptPoint::default();
+    let metrics = scenario.simulate_with_observer(&pip, &mut points);
+    assert!(metrics.hit, "{}", metrics.miss_distance);
+
+    // the point settles where the engagement ends
+    let end = metrics.target_trajectory.last().unwrap();
+    assert!((points.0[points.0.len() / 2] - end).norm() < 500.0);
+
+    // handed over to PN for the endgame, it flies a straighter path than lead pursuit
+    let phased = PhasedGuidance::new(Box::new(pip))
+        .then(
+            PhaseTrigger::TimeToGo { time: 5.0 },
+            Box::new(PureProportionalNavigation),
+        )
+        .with_blend_time(0.5);
+    let handover = scenario.simulate(&phased);
+    let lead = scenario.simulate(&LeadPursuit::default());
+    assert!(handover.hit);
+    assert!(handover.summary.control_effort < lead.summary.control_effort);
+}
+
+#[test]
+fn test_datalink_stale_tracks_until_seeker_acquires() {
+    use missile_sim::entity::Datalink;
+    use missile_sim::simulation::{SimulationObserver, StepSample};
+    use std::ops::ControlFlow;
+
+    let datalink = Datalink {
+        update_interval: 1.0,
+        latency: 0.3,
+        packet_loss: 0.0,
+        position_error: 0.0,
+        velocity_error: 0.0,
+        seeker_range: Some(4000.0),
+    };
+    let builder = Scenario::builder("midcourse")
+        .missile(Missile {
+            state: State3D::init(0.0, 0.0, 5000.0, 900.0, 0.0, 0.0),
+            max_acceleration: 250.0,
+            navigation_constant: 4.0,
+            max_closing_speed: 3000.0,
+        })
+        .target(Target {
+            state: State3D::init(25_000.0, 3000.0, 6000.0, -250.0, 0.0, 0.0),
+            acceleration: Vector3::new(0.0, 10.0, 0.0),
+        })
+        .total_time(60.0)
+        .hit_threshold(10.0)
+        .datalink(datalink.clone());
+
+    /// Largest gap between the perceived and the true target, on the datalink and after.
+    #[derive(Default)]
+    struct Staleness([f64; 2]);
+    impl SimulationObserver for Staleness {
+        fn on_step(&mut self, step: &StepSample) -> ControlFlow<()> {
+            let error = (step.perceived.state.position - step.target.state.position).norm();
+            let range = (step.target.state.position - step.missile.state.position).norm();
+            let i = usize::from(range < 3900.0);
+            self.0[i] = self.0[i].max(error);
+            ControlFlow::Continue(())
+        }
+    }
+
+    let scenario = builder.clone().build().unwrap();
+    let mut staleness = Staleness::default();
+    let metrics = scenario.simulate_with_observer(&PureProportionalNavigation, &mut staleness);
+    assert!(metrics.hit, "miss {}", metrics.miss_distance);
+
+    // a 10 m/s² target drifts off a track up to 1.3 s old by ½ a t²
+    assert!(
+        staleness.0[0] > 7.0 && staleness.0[0] < 8.5,
+        "{:?}",
+        staleness.0
+    );
+    assert_eq!(staleness.0[1], 0.0);
+
+    // no track, so no command, until the first update lands 0.3 s after launch
+    #[derive(Default)]
+    struct Commands(Vec<(f64, f64)>);
+    impl SimulationObserver for Commands {
+        fn on_step(&mut self, step: &StepSample) -> ControlFlow<()> {
+            self.0.push((step.time, step.commanded.norm()));
+            ControlFlow::Continue(())
+        }
+    }
+    let mut commands = Commands::default();
+    scenario.simulate_with_observer(&PureProportionalNavigation, &mut commands);
+    let (held, guided): (Vec<_>, Vec<_>) = commands.0.iter().partition(|(t, _)| *t < 0.3 + 1e-9);
+    assert!(held.iter().all(|(_, a)| *a == 0.0));
+    assert!(guided[0].1 > 0.0);
+
+    // launched blind, acquired once within seeker range
+    let locks: Vec<_> = metrics
+        .events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e.event,
+                SimulationEvent::LockLost | SimulationEvent::LockAcquired
+            )
+        })
+        .collect();
+    assert_eq!(locks.len(), 1);
+    assert_eq!(locks[0].event, SimulationEvent::LockAcquired);
+    let at = metrics
+        .time_history
+        .iter()
+        .position(|&t| t == locks[0].time)
+        .unwrap();
+    let range = (metrics.target_trajectory[at] - metrics.missile_trajectory[at]).norm();
+    assert!(range < 4000.0 && range > 3500.0);
+
+    // noisy, lossy links are reproducible from the seed
+    let noisy = Datalink {
+        packet_loss: 0.3,
+        position_error: 50.0,
+        velocity_error: 5.0,
+        ..datalink
+    };
+    let run = |seed| {
+        builder
+            .clone()
+            .datalink(noisy.clone())
+            .seed(seed)
+            .build()
+            .unwrap()
+            .simulate(&PureProportionalNavigation)
+    };
+    let (first, second, other) = (run(1), run(1), run(2));
+    assert_eq!(first.missile_trajectory, second.missile_trajectory);
+    assert_ne!(first.missile_trajectory, other.missile_trajectory);
+
+    // and the losses show up in the engine
+    let mut engine = SimulationEngine::new(
+        scenario.missile.clone(),
+        scenario.target.clone(),
+        scenario.dt,
+        scenario.total_time,
+        scenario.hit_threshold,
+    );
+    engine.datalink = Some(noisy);
+    let mut partial = SimulationMetrics::init(2000);
+    for _ in 0..1500 {
+        engine.step(&PureProportionalNavigation, &mut partial);
+    }
+    let link = engine.datalink_state();
+    assert!(!engine.seeker_locked());
+    assert_eq!(link.received + link.lost, 15);
+    assert!(link.lost > 0 && link.received > link.lost);
+
+    let bad = Datalink {
+        packet_loss: 1.5,
+        ..Datalink::clone(scenario.datalink.as_ref().unwrap())
+    };
+    assert!(builder.datalink(bad).build().is_err());
+}
+
+#[test]
+fn test_saclos_engagement_records_beam_deviation() {
+    let scenario = |launcher: Launcher| {
+        // fired down the beam, with the launcher's own velocity
+        let origin = launcher.state.position;
+        let aim = (Vector3::new(4000.0, 0.0, 500.0) - origin).normalize() * 300.0
+            + launcher.state.velocity;
+        Scenario::builder("saclos")
+            .missile(Missile {
+                state: State3D {
+                    position: origin,
+                    velocity: aim,
+                },
+                max_acceleration: 300.0,
+                navigation_constant: 4.0,
+                max_closing_speed: 2000.0,
+            })
+            .target(Target {
+                state: State3D::init(4000.0, 0.0, 500.0, -50.0, 150.0, 0.0),
+                acceleration: Vector3::zeros(),
+            })
+            .launcher(launcher)
+            .environment(Environment {
+                gravity: 9.81,
+                ..Default::default()
+            })
+            .dt(0.001)
+            .total_time(30.0)
+            .build()
+            .unwrap()
+    };
+    let fixed = scenario(Launcher::fixed(Vector3::zeros()));
+
+    let clos = fixed.simulate(&CommandToLineOfSight::default());
+    let rider = fixed.simulate(&BeamRider::default());
+    let three_point = fixed.simulate(&ThreePoint);
+    for metrics in [&clos, &rider, &three_point] {
+        assert!(metrics.hit, "miss {}", metrics.miss_distance);
+        assert_eq!(
+            metrics.beam_deviation_records.len(),
+            metrics.time_history.len()
+        );
+        let peak = metrics
+            .beam_deviation_records
+            .iter()
+            .fold(0.0_f64, |a, &b| a.max(b));
+        assert_eq!(metrics.summary.peak_beam_deviation, peak);
+    }
+    // the rider lags the turning beam, CLOS and three-point ride it
+    assert!(clos.summary.peak_beam_deviation < 1.0);
+    assert!(three_point.summary.peak_beam_deviation < 1.0);
+    assert!(rider.summary.peak_beam_deviation > 10.0 * clos.summary.peak_beam_deviation);
+
+    // from a ship under way
+    let ship = Launcher {
+        state: State3D::init(0.0, -500.0, 10.0, 0.0, 15.0, 0.0),
+        acceleration: Vector3::zeros(),
+    };
+    let moving = scenario(ship).simulate(&CommandToLineOfSight::default());
+    assert!(moving.hit, "miss {}", moving.miss_distance);
+    assert!(moving.summary.peak_beam_deviation < 1.0);
+
+    // other laws record the deviation too, PN doesn't ride the beam
+    let pn = fixed.simulate(&PureProportionalNavigation);
+    assert!(pn.summary.peak_beam_deviation > 100.0);
+
+    let mut plain = fixed.clone();
+    plain.launcher = None;
+    let metrics = plain.simulate(&CommandToLineOfSight::default());
+    assert!(metrics.beam_deviation_records.is_empty());
+}
@@ -1,3 +1,7 @@
+mod analysis_test;
+mod dynamics_test;
+mod environment_test;
+mod export_test;
 mod guidance_test;
 mod kinematics_test;
 mod simulation_test;